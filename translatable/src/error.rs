@@ -6,7 +6,8 @@
 //! method invocations.
 
 use thiserror::Error;
-use translatable_shared::misc::language::Language;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::misc::templating::ArgumentError;
 use translatable_shared::translations::node::TranslationNodeError;
 
 /// Macro runtime error handling.
@@ -57,7 +58,7 @@ pub enum RuntimeError {
     ///
     /// **Parameters**
     /// * `0` - The path that could not be found
-    /// appended with it's separator.
+    ///   appended with it's separator.
     ///
     /// [`LanguageNotAvailable`]: crate::Error::LanguageNotAvailable
     #[error("The path '{0}' could not be found")]
@@ -69,16 +70,44 @@ pub enum RuntimeError {
     /// is not available for a specific translation.
     ///
     /// Language parsing is delegated to the user,
-    /// the language parameter must be a [`Language`],
+    /// the language parameter must convert into a [`Locale`],
     /// if it's a &[`str`] the validation is made in compile
     /// time. In that case we don't reach runtime.
     ///
+    /// The error is only returned once every less
+    /// specific locale was also found unavailable.
+    ///
+    /// This variant used to hold a [`Language`], the
+    /// requested one is available through [`Locale::language`].
+    ///
     /// **Parameters**
-    /// * `0` - The language that is not available.
+    /// * `0` - The locale that is not available.
     /// * `1` - The path for which the language is not available
-    /// appended with it's separator.
-    #[error("The language '{0:?}' ('{0:#}') is not available for the path '{1}'")]
-    LanguageNotAvailable(Language, String),
+    ///   appended with it's separator.
+    ///
+    /// [`Language`]: crate::Language
+    #[error("The language '{0}' is not available for the path '{1}'")]
+    LanguageNotAvailable(Locale, String),
+
+    /// Dynamic argument validation error.
+    ///
+    /// Unknown and missing arguments are only returned when
+    /// `runtime_validation` is configured as `Strict`, as
+    /// otherwise unknown arguments are ignored and missing
+    /// placeholders kept as written. Arguments that don't
+    /// implement the formatting trait of their placeholder
+    /// specifier are always returned.
+    ///
+    /// Static paths are validated in compile time
+    /// instead, thus they never return this error.
+    ///
+    /// **Parameters**
+    /// * `0` - The first mismatch found.
+    /// * `1` - The requested locale.
+    /// * `2` - The path of the translation appended with
+    ///   it's separator.
+    #[error("{0:#} in the '{1}' translation for the path '{2}'")]
+    InvalidArgument(ArgumentError, Locale, String),
 }
 
 impl RuntimeError {
@@ -8,6 +8,7 @@
 #![warn(missing_docs)]
 
 mod error;
+mod negotiation;
 
 /// Runtime error re-export.
 ///
@@ -27,9 +28,41 @@ pub use error::RuntimeError as Error;
 #[rustfmt::skip]
 pub use translatable_proc::translation;
 
+#[rustfmt::skip]
+pub use translatable_proc::translation_display;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_str;
+
+#[rustfmt::skip]
+pub use translatable_proc::init;
+
 #[rustfmt::skip]
 pub use translatable_proc::translation_context;
 
+#[rustfmt::skip]
+pub use translatable_proc::available_languages;
+
+#[rustfmt::skip]
+pub use translatable_proc::gettext_catalog;
+
+#[rustfmt::skip]
+pub use translatable_proc::xliff_document;
+
+/// Language negotiation re-exports.
+///
+/// This `use` statement exports the
+/// `Accept-Language` header parsing and
+/// negotiation helpers.
+#[rustfmt::skip]
+pub use negotiation::{
+    LanguageRange,
+    Negotiation,
+    NegotiationReason,
+    negotiate_language,
+    parse_accept_language,
+};
+
 /// Language enum re-export.
 ///
 /// This `use` statement re-exports
@@ -38,6 +71,15 @@ pub use translatable_proc::translation_context;
 #[rustfmt::skip]
 pub use shared::misc::language::Language;
 
+/// Locale struct re-export.
+///
+/// This `use` statement re-exports
+/// from the hidden shared re-export
+/// for user convenience on parsing
+/// regional BCP 47 tags.
+#[rustfmt::skip]
+pub use shared::misc::locale::Locale;
+
 #[doc(hidden)]
 #[rustfmt::skip]
 pub use translatable_shared as shared;
@@ -0,0 +1,277 @@
+//! Language negotiation module.
+//!
+//! This module contains the helpers to parse
+//! `Accept-Language` HTTP headers and match the
+//! requested language ranges against the languages
+//! available in the translation files.
+
+use translatable_shared::misc::locale::Locale;
+
+/// A weighted language range.
+///
+/// Represents one of the comma separated entries
+/// of an `Accept-Language` header, such as
+/// `es-ES;q=0.9`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    /// The requested locale.
+    ///
+    /// `None` represents the `*` wildcard.
+    locale: Option<Locale>,
+
+    /// The range weight between `0.0` and `1.0`.
+    quality: f32,
+}
+
+/// The reason a language was chosen.
+///
+/// Every variant carries the quality of the
+/// range that matched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NegotiationReason {
+    /// The requested range is exactly an available locale.
+    ///
+    /// **Parameters**
+    /// * `0` - The quality of the matching range.
+    Exact(f32),
+
+    /// The requested range is more specific than the available
+    /// locale, such as `pt-BR` matching `pt`.
+    ///
+    /// **Parameters**
+    /// * `0` - The requested locale.
+    /// * `1` - The quality of the matching range.
+    LessSpecific(Locale, f32),
+
+    /// The requested range is less specific than the available
+    /// locale, such as `pt` matching `pt-BR`.
+    ///
+    /// **Parameters**
+    /// * `0` - The requested locale.
+    /// * `1` - The quality of the matching range.
+    MoreSpecific(Locale, f32),
+
+    /// The `*` wildcard matched an available locale that
+    /// wasn't explicitly rejected with `q=0`.
+    ///
+    /// **Parameters**
+    /// * `0` - The quality of the wildcard range.
+    Wildcard(f32),
+}
+
+/// Language negotiation result.
+///
+/// Contains the chosen locale along with
+/// the reason it was chosen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Negotiation {
+    /// The chosen locale, always one of the available ones.
+    locale: Locale,
+
+    /// Why the locale was chosen.
+    reason: NegotiationReason,
+}
+
+impl LanguageRange {
+    /// Locale getter.
+    ///
+    /// **Returns**
+    /// The requested locale or `None` if the range is the `*` wildcard.
+    pub fn locale(&self) -> Option<&Locale> {
+        self.locale
+            .as_ref()
+    }
+
+    /// Quality getter.
+    ///
+    /// **Returns**
+    /// The range weight between `0.0` and `1.0`.
+    pub fn quality(&self) -> f32 {
+        self.quality
+    }
+}
+
+impl Negotiation {
+    /// Chosen locale getter.
+    ///
+    /// **Returns**
+    /// A reference to the chosen locale.
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Reason getter.
+    ///
+    /// **Returns**
+    /// A reference to the reason the locale was chosen.
+    pub fn reason(&self) -> &NegotiationReason {
+        &self.reason
+    }
+
+    /// Chosen locale conversion.
+    ///
+    /// **Returns**
+    /// The chosen locale, consuming the negotiation.
+    pub fn into_locale(self) -> Locale {
+        self.locale
+    }
+}
+
+/// `Accept-Language` header parsing.
+///
+/// Parses the comma separated language ranges with
+/// their optional `q` weight, which defaults to `1.0`.
+///
+/// Entries that are not valid BCP 47 tags or that have an
+/// invalid weight are skipped, the same way HTTP servers
+/// ignore unknown ranges.
+///
+/// **Arguments**
+/// * `header` - The header value, such as `es-ES,es;q=0.9,en;q=0.8`.
+///
+/// **Returns**
+/// The parsed ranges sorted by descending quality, ranges with the same
+/// quality keep the header order.
+pub fn parse_accept_language(header: &str) -> Vec<LanguageRange> {
+    let mut ranges = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parameters = entry.split(';');
+
+            let tag = parameters
+                .next()?
+                .trim();
+
+            let quality = parameters
+                .filter_map(|parameter| {
+                    parameter
+                        .trim()
+                        .strip_prefix("q=")
+                })
+                .map(|quality| {
+                    quality
+                        .trim()
+                        .parse::<f32>()
+                        .ok()
+                        .filter(|quality| (0.0..=1.0).contains(quality))
+                })
+                .next()
+                .unwrap_or(Some(1.0))?;
+
+            let locale = match tag {
+                "*" => None,
+                tag => Some(
+                    tag.parse::<Locale>()
+                        .ok()?,
+                ),
+            };
+
+            Some(LanguageRange { locale, quality })
+        })
+        .collect::<Vec<_>>();
+
+    ranges.sort_by(|a, b| {
+        b.quality
+            .total_cmp(&a.quality)
+    });
+    ranges
+}
+
+/// `Accept-Language` negotiation.
+///
+/// Parses the header and finds the best available locale
+/// for it. Ranges are tried by descending quality, and for
+/// each range an exact match is preferred, then a less specific
+/// available locale and then a more specific one.
+///
+/// Ranges with `q=0` reject their locale and every more specific
+/// one, so `es;q=0` excludes both `es` and `es-ES` from any match,
+/// including the less specific, more specific and wildcard ones.
+///
+/// The available locales may be obtained with [`available_languages!()`]
+/// to match against the languages in the translation files.
+///
+/// **Arguments**
+/// * `header` - The header value, such as `es-ES,es;q=0.9,en;q=0.8`.
+/// * `available` - The locales that can be chosen, in order of preference for
+///   ties.
+///
+/// **Returns**
+/// The chosen locale and the reason, or `None` if no range matched.
+///
+/// [`available_languages!()`]: crate::available_languages
+pub fn negotiate_language(header: &str, available: &[Locale]) -> Option<Negotiation> {
+    let ranges = parse_accept_language(header);
+
+    let rejected = ranges
+        .iter()
+        .filter(|range| range.quality <= 0.0)
+        .filter_map(|range| range.locale())
+        .collect::<Vec<_>>();
+
+    let available = available
+        .iter()
+        .filter(|locale| {
+            !locale
+                .fallbacks()
+                .any(|fallback| rejected.contains(&&fallback))
+        })
+        .collect::<Vec<_>>();
+
+    ranges
+        .iter()
+        .filter(|range| range.quality > 0.0)
+        .find_map(|range| {
+            let quality = range.quality;
+
+            let Some(requested) = range.locale() else {
+                return available
+                    .first()
+                    .copied()
+                    .map(|locale| Negotiation {
+                        locale: locale.clone(),
+                        reason: NegotiationReason::Wildcard(quality),
+                    });
+            };
+
+            if let Some(locale) = available
+                .iter()
+                .copied()
+                .find(|locale| *locale == requested)
+            {
+                return Some(Negotiation {
+                    locale: locale.clone(),
+                    reason: NegotiationReason::Exact(quality),
+                });
+            }
+
+            if let Some(locale) = requested
+                .fallbacks()
+                .skip(1)
+                .find_map(|fallback| {
+                    available
+                        .iter()
+                        .copied()
+                        .find(|locale| **locale == fallback)
+                })
+            {
+                return Some(Negotiation {
+                    locale: locale.clone(),
+                    reason: NegotiationReason::LessSpecific(requested.clone(), quality),
+                });
+            }
+
+            available
+                .iter()
+                .copied()
+                .find(|locale| {
+                    locale
+                        .fallbacks()
+                        .any(|fallback| fallback == *requested)
+                })
+                .map(|locale| Negotiation {
+                    locale: locale.clone(),
+                    reason: NegotiationReason::MoreSpecific(requested.clone(), quality),
+                })
+        })
+}
@@ -0,0 +1,20 @@
+### test fluent resources along with TOML files.
+
+-brand = Translatable
+
+## test terms, selectors and plurals mapped to templates.
+fluent =
+    .welcome = Welcome to { -brand }, { $user }!
+    .files = { $count ->
+        [one] You have one file.
+       *[other] You have { $count } files.
+    }
+    .invited = { $gender ->
+        [female] She
+        [male] He
+       *[other] They
+    } invited { $guest }.
+    .braces = Write { "{" }user{ "}" } to mention someone.
+    .multiline =
+        First line,
+        second line.
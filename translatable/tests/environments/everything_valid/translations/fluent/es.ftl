@@ -0,0 +1,17 @@
+### test fluent resources along with TOML files.
+
+-brand = Translatable
+
+## test terms, selectors and plurals mapped to templates.
+fluent =
+    .welcome = Bienvenido a { -brand }, { $user }!
+    .files = Tienes { NUMBER($count) ->
+        [one] un archivo
+        [many] { $count } de archivos
+       *[other] { $count } archivos
+    }.
+    .invited = { $gender ->
+        [female] Ella invitó
+        [male] Él invitó
+       *[other] Elle invitó
+    } a { $guest }.
@@ -0,0 +1,2 @@
+# test variables that are not valid identifiers.
+greeting = Hola { $user-name }
@@ -0,0 +1,6 @@
+use translatable::translation;
+
+fn main() {
+    // the path and the fallback come from the workspace configuration.
+    assert_eq!(translation!("fr", static greetings::hello), "Hola desde el espacio de trabajo.");
+}
@@ -0,0 +1,6 @@
+use translatable::translation;
+
+fn main() {
+    // the path comes from the crate configuration, the fallback from the workspace.
+    assert_eq!(translation!("fr", static greetings::hello), "Hola desde el crate.");
+}
@@ -0,0 +1,9 @@
+#[allow(unused_imports)]
+use translatable::{Language, translation};
+
+fn main() {
+    let name = "John";
+
+    translation!("es", static greetings::informal, usr = name);
+    let _ = translation!(Language::ES, static auditory::actions::delete_user, author = name);
+}
@@ -0,0 +1,8 @@
+#![deny(deprecated)]
+
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("es", static greetings::informal, usr = "John");
+}
@@ -0,0 +1,9 @@
+// the configured fallback chain ends in 'en'
+// which is not available for this path.
+
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("pt", static greetings::farewell);
+}
@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    translation!("en", static orders::summary, id = 1, price = 2.5);
+}
@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    translation!("en", static auditory::actions::delete_user, author = "John", target = "Ana");
+}
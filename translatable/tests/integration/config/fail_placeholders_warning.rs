@@ -0,0 +1,11 @@
+#![deny(deprecated)]
+
+#[allow(unused_imports)]
+use translatable::translation;
+
+translatable::init!();
+
+fn main() {
+    let _ = translation!("en", static auditory::actions::delete_user, author = "John", target = "Ana");
+    let _ = translation!("es", static auditory::actions::delete_user, author = "John");
+}
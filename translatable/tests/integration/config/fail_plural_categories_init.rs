@@ -0,0 +1,3 @@
+translatable::init!();
+
+fn main() {}
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use translatable::{Language, translation, translation_context};
+
+translatable::init!();
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: String,
+}
+
+fn main() {
+    assert_eq!(translation!("ca", static greetings::formal), "Nice to meet you.");
+
+    assert_eq!(
+        translation!(Language::CA, vec!["greetings", "formal"]).unwrap(),
+        "Nice to meet you."
+    );
+
+    assert_eq!(
+        translation!(["ca", "es"], static greetings::formal),
+        "Bueno conocerte."
+    );
+
+    assert_eq!(
+        Context::load_translations(Language::CA, &HashMap::<String, String>::new())
+            .unwrap()
+            .formal,
+        "Nice to meet you."
+    );
+}
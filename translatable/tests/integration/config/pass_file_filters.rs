@@ -0,0 +1,12 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("es", static title), "Ajustes");
+
+    // excluded, hidden and not included files aren't loaded.
+    assert!(translation!(Language::EN, vec!["draft"]).is_err());
+    assert!(translation!(Language::EN, vec!["cached"]).is_err());
+    assert!(translation!(Language::EN, vec!["json_only"]).is_err());
+}
@@ -0,0 +1,14 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("en", static app::title), "Dashboard");
+    assert_eq!(translation!("es", static admin::users::title), "Usuarios");
+    assert_eq!(translation!("en", static admin::users::actions::ban, user = "John"), "Ban John");
+
+    assert_eq!(
+        translation!(Language::ES, vec!["admin", "users", "title"]).unwrap(),
+        "Usuarios"
+    );
+}
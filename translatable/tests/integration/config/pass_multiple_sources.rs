@@ -0,0 +1,15 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("es", static vendor::ui::save), "Guardar cambios");
+    assert_eq!(translation!("en", static vendor::ui::save), "Save");
+    assert_eq!(translation!("es", static vendor::ui::cancel), "Cancelar");
+    assert_eq!(translation!("en", static title), "Settings");
+
+    assert_eq!(
+        translation!(Language::ES, vec!["vendor", "ui", "save"]).unwrap(),
+        "Guardar cambios"
+    );
+}
@@ -0,0 +1,14 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("es", static greetings::formal), "Bueno conocerte.");
+    assert_eq!(translation!("fr", static greetings::formal), "Enchanté.");
+    assert_eq!(translation!("en", static greetings::informal), "What's up?");
+
+    assert_eq!(
+        translation!(Language::FR, vec!["greetings", "formal"]).unwrap(),
+        "Enchanté."
+    );
+}
@@ -0,0 +1,13 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("es", static greetings::formal), "Encantado de conocerte.");
+    assert_eq!(translation!("en", static greetings::formal), "Nice to meet you.");
+
+    assert_eq!(
+        translation!(Language::ES, vec!["greetings", "formal"]).unwrap(),
+        "Encantado de conocerte."
+    );
+}
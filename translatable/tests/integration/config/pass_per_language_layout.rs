@@ -0,0 +1,23 @@
+use translatable::{Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(translation!("en", static welcome, user = "John"), "Welcome John!");
+    assert_eq!(translation!("es", static welcome, user = "John"), "¡Bienvenido John!");
+
+    assert_eq!(translation!("es", static menu::close), "Cerrar");
+    assert_eq!(translation!("en", static files::count, count = 1), "1 file");
+    assert_eq!(translation!("es", static files::count, count = 3), "3 archivos");
+
+    assert_eq!(translation!("en", static it::support), "Contact the IT department.");
+    assert_eq!(
+        translation!("es", static it::support),
+        "Contacta con el departamento de informática."
+    );
+
+    assert_eq!(
+        translation!(Language::ES, vec!["errors", "not_found"], page = "/home").unwrap(),
+        "La página /home no existe."
+    );
+}
@@ -0,0 +1,23 @@
+use translatable::{Error, Language, translation};
+
+translatable::init!();
+
+fn main() {
+    assert_eq!(
+        translation!(Language::ES, vec!["greetings", "informal"], user = "John").unwrap(),
+        "Hey John, todo bien?"
+    );
+
+    assert!(matches!(
+        translation!(Language::ES, vec!["greetings", "informal"], usr = "John"),
+        Err(Error::InvalidArgument(..))
+    ));
+
+    assert_eq!(
+        translation!(Language::ES, vec!["greetings", "informal"])
+            .unwrap_err()
+            .cause(),
+        "The placeholder 'user' has no matching argument in the 'es' translation for the path \
+         'greetings::informal'"
+    );
+}
@@ -1,3 +1,4 @@
 pub mod pass_fallback_catch;
+pub mod pass_fallback_chain;
 pub mod pass_invalid_runtime_language;
 pub mod pass_without_params;
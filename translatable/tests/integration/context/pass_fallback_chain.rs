@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use ::{std::collections::HashMap, translatable::translation_context};
+
+#[translation_context(base_path = greetings, fallback_language = ["fr", "es", "en"])]
+struct Context {
+    formal: String,
+    informal: String,
+}
+
+#[test]
+fn pass_fallback_chain() {
+    let translations =
+        Context::load_translations(translatable::Language::AA, &HashMap::from([("user", "John")]));
+
+    assert_eq!(translations.formal, "Bueno conocerte.");
+    assert_eq!(translations.informal, "Hey John, todo bien?");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::available_languages;
+
+fn main() {
+    available_languages!(non::existing::path);
+}
@@ -0,0 +1,2 @@
+pub mod pass_all_languages;
+pub mod pass_path_languages;
@@ -0,0 +1,16 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Locale, available_languages};
+
+#[cfg(test)]
+#[test]
+pub fn pass_all_languages() {
+    let languages = available_languages!()
+        .iter()
+        .map(Locale::to_string)
+        .collect::<Vec<_>>();
+
+    assert_eq!(languages, ["en", "es", "pt", "pt-PT", "ru", "zh", "zh-Hant"]);
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,22 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Locale, available_languages, negotiate_language};
+
+#[cfg(test)]
+#[test]
+pub fn pass_path_languages() {
+    const LANGUAGES: &[Locale] = available_languages!(greetings::farewell);
+
+    let negotiation =
+        negotiate_language("pt-BR,en;q=0.5", LANGUAGES).expect("A language to be negotiated.");
+
+    assert_eq!(LANGUAGES.len(), 4);
+    assert_eq!(
+        negotiation
+            .locale()
+            .to_string(),
+        "pt"
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1 @@
+pub mod pass_display_lazy;
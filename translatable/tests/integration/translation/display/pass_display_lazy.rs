@@ -0,0 +1,35 @@
+#[allow(unused_imports)] // trybuild
+use std::fmt::Write;
+
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_display};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_display_lazy() {
+    let name = String::from("John");
+
+    let greeting = translation_display!("es", static common::greeting, name = &name, surname = 42);
+    assert_eq!(greeting.to_string(), "¡Hola John 42! Mi nombre es John 42 {{hola}}");
+
+    let mut output = String::new();
+    write!(
+        output,
+        "[{}]",
+        translation_display!(Language::EN, static greetings::informal, user = &name)
+            .expect("Expected translation generation to be OK")
+    )
+    .expect("Expected writing to a string to be OK");
+    assert_eq!(output, "[What's good John?]");
+
+    let files = translation_display!(Language::RU, vec!["files", "count"], count = 21)
+        .expect("Expected translation generation to be OK");
+    assert_eq!(format!("{files}"), "21 файл");
+
+    assert!(translation_display!(Language::ES, vec!["non", "existing"]).is_err());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
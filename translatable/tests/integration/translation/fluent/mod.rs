@@ -0,0 +1,2 @@
+pub mod pass_fluent_dynamic;
+pub mod pass_fluent_static;
@@ -0,0 +1,27 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_fluent_dynamic() {
+    assert_eq!(
+        translation!(Language::ES, vec!["fluent", "files"], count = 7).unwrap(),
+        "Tienes 7 archivos."
+    );
+
+    assert_eq!(
+        translation!(Language::EN, static fluent::invited, gender = "male", guest = "Eve").unwrap(),
+        "He invited Eve."
+    );
+
+    // translations from TOML files are still available.
+    assert_eq!(
+        translation!(Language::EN, vec!["greetings", "formal"]).unwrap(),
+        "Nice to meet you."
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
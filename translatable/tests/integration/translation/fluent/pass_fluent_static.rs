@@ -0,0 +1,31 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_fluent_static() {
+    let user = "Ana";
+
+    assert_eq!(translation!("en", static fluent::welcome, user), "Welcome to Translatable, Ana!");
+    assert_eq!(translation!("es", static fluent::welcome, user), "Bienvenido a Translatable, Ana!");
+
+    assert_eq!(translation!("en", static fluent::files, count = 1), "You have one file.");
+    assert_eq!(translation!("en", static fluent::files, count = 4), "You have 4 files.");
+    assert_eq!(translation!("es", static fluent::files, count = 1), "Tienes un archivo.");
+    assert_eq!(translation!("es", static fluent::files, count = 2), "Tienes 2 archivos.");
+
+    assert_eq!(
+        translation!("en", static fluent::invited, gender = "female", guest = "Bob"),
+        "She invited Bob."
+    );
+    assert_eq!(
+        translation!("es", static fluent::invited, gender = "x", guest = "Bob"),
+        "Elle invitó a Bob."
+    );
+
+    assert_eq!(translation!("en", static fluent::braces), "Write {user} to mention someone.");
+    assert_eq!(translation!("en", static fluent::multiline), "First line,\nsecond line.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
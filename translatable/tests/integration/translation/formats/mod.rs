@@ -0,0 +1,2 @@
+pub mod pass_formats_dynamic;
+pub mod pass_formats_static;
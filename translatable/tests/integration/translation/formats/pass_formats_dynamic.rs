@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_formats_dynamic() {
+    assert_eq!(
+        translation!(Language::ES, vec!["json", "files"], count = 7).unwrap(),
+        "7 archivos JSON"
+    );
+
+    assert_eq!(
+        translation!(Language::EN, vec!["yaml", "welcome"], user = "Eve").unwrap(),
+        "Welcome Eve, this came from YAML."
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
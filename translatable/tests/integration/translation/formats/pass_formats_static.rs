@@ -0,0 +1,27 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_formats_static() {
+    let user = "Ana";
+
+    assert_eq!(translation!("en", static json::welcome, user), "Welcome Ana, this came from JSON.");
+    assert_eq!(
+        translation!("es", static yaml::welcome, user),
+        "Bienvenido Ana, esto vino de YAML."
+    );
+
+    assert_eq!(translation!("en", static json::files, count = 1), "1 JSON file");
+    assert_eq!(translation!("es", static json::files, count = 3), "3 archivos JSON");
+    assert_eq!(translation!("en", static yaml::files, count = 2), "2 YAML files");
+    assert_eq!(translation!("es", static yaml::files, count = 1), "1 archivo YAML");
+
+    assert_eq!(translation!("en", static json::escaped), "Tab\tand é \"quoted\" {{literal}}");
+
+    assert_eq!(translation!("en", static yaml::answer), "yes");
+    assert_eq!(translation!("es", static yaml::repeated), "sí");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,3 @@
+pub mod pass_gettext_dynamic;
+pub mod pass_gettext_export;
+pub mod pass_gettext_static;
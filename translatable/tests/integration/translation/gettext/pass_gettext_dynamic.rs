@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_gettext_dynamic() {
+    assert_eq!(
+        translation!(Language::ES, vec!["gettext", "files"], count = 1000000).unwrap(),
+        "1000000 de archivos gettext"
+    );
+
+    // fuzzy messages are skipped.
+    assert!(translation!(Language::ES, vec!["gettext", "fuzzy"]).is_err());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
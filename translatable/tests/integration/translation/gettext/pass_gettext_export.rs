@@ -0,0 +1,46 @@
+#[allow(unused_imports)] // trybuild
+use translatable::gettext_catalog;
+
+#[cfg(test)]
+#[test]
+pub fn pass_gettext_export() {
+    let template = gettext_catalog!("en");
+
+    assert!(template.contains("Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\\n"));
+    assert!(template.contains(
+        "msgctxt \"gettext.welcome\"\nmsgid \"Welcome {user}, this came from gettext.\"\nmsgstr \
+         \"\"\n"
+    ));
+    assert!(template.contains("\n#. Greets the signed in user.\nmsgctxt \"gettext.welcome\""));
+    assert!(!template.contains("# Shown on the landing page."));
+
+    let catalog = gettext_catalog!("en", "es");
+
+    assert!(catalog.contains("Language: es\\n"));
+    assert!(catalog.contains(
+        "msgctxt \"gettext.files\"\nmsgid \"{count} gettext file\"\nmsgid_plural \"{count} \
+         gettext files\"\nmsgstr[0] \"{count} archivo gettext\"\nmsgstr[1] \"{count} de archivos \
+         gettext\"\nmsgstr[2] \"{count} archivos gettext\"\n"
+    ));
+    assert!(catalog.contains(
+        "\n# Informal register.\n#. The user name is shown as is.\nmsgctxt \"gettext.welcome\""
+    ));
+    assert!(catalog.contains("msgstr \"\"\n\"Primera línea\\n\"\n\"Segunda \\\"línea\\\"\"\n"));
+
+    let directory = std::env::temp_dir().join("translatable_gettext_export");
+    let _ = std::fs::remove_dir_all(&directory);
+
+    gettext_catalog!("en", path = directory.join("po/messages.pot"))
+        .expect("Expected the template to be written");
+    gettext_catalog!("en", "es", path = directory.join("po/es.po"))
+        .expect("Expected the catalog to be written");
+
+    assert_eq!(
+        std::fs::read_to_string(directory.join("po/messages.pot")).ok(),
+        Some(template.into())
+    );
+    assert_eq!(std::fs::read_to_string(directory.join("po/es.po")).ok(), Some(catalog.into()));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_gettext_static() {
+    let user = "Ana";
+
+    assert_eq!(
+        translation!("en", static gettext::welcome, user),
+        "Welcome Ana, this came from gettext."
+    );
+    assert_eq!(
+        translation!("es", static gettext::welcome, user),
+        "Bienvenido Ana, esto vino de gettext."
+    );
+
+    assert_eq!(translation!("en", static gettext::files, count = 1), "1 gettext file");
+    assert_eq!(translation!("es", static gettext::files, count = 1), "1 archivo gettext");
+    assert_eq!(translation!("es", static gettext::files, count = 7), "7 archivos gettext");
+
+    assert_eq!(translation!("es", static gettext::multiline), "Primera línea\nSegunda \"línea\"");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
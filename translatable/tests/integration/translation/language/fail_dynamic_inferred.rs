@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    let _ = translation!("pt-PT".parse().unwrap(), static greetings::formal);
+}
@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::{Language, translation};
+
+fn main() {
+    let _ = translation!(["es", Language::EN], static greetings::formal);
+}
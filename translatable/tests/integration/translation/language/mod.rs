@@ -1,5 +1,8 @@
 pub mod pass_dynamic_enum;
 pub mod pass_dynamic_expr;
 pub mod pass_dynamic_invalid_runtime;
+pub mod pass_dynamic_locale;
+pub mod pass_fallback_chain;
 pub mod pass_static_lowercase;
+pub mod pass_static_regional;
 pub mod pass_static_uppercase;
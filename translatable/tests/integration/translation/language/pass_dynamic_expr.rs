@@ -1,11 +1,11 @@
 #[allow(unused_imports)] // trybuild
-use translatable::translation;
+use translatable::{Language, translation};
 
 #[cfg(test)]
 #[test]
 pub fn pass_dynamic_expr() {
     let translation = translation!(
-        "es".parse().expect("Expected language parsing to be OK"),
+        "es".parse::<Language>().expect("Expected language parsing to be OK"),
         static greetings::formal
     )
     .expect("Expected translation generation to be OK");
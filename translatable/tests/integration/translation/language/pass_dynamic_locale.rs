@@ -0,0 +1,29 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Locale, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_locale() {
+    let locale = "pt-BR"
+        .parse::<Locale>()
+        .expect("Expected locale parsing to be OK");
+
+    let translation = translation!(locale, static greetings::farewell)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Tchau.");
+
+    let locale = "pt-PT"
+        .parse::<Locale>()
+        .expect("Expected locale parsing to be OK");
+
+    let translation = translation!(locale, vec!["greetings", "farewell"])
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Adeus.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
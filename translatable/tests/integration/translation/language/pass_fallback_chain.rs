@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_fallback_chain() {
+    let translation = translation!(["ca", "fr", "en"], static greetings::formal);
+    assert_eq!(translation, "Nice to meet you.");
+
+    let translation = translation!([Language::CA, "es", "en"], static greetings::formal)
+        .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!([Language::CA, "en"], vec!["greetings", "formal"])
+        .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Nice to meet you.");
+
+    let translation = translation!([Language::CA, "fr"], vec!["greetings", "formal"]);
+    assert!(translation.is_err());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,14 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_regional() {
+    assert_eq!(translation!("pt-PT", static greetings::farewell), "Adeus.");
+    assert_eq!(translation!("pt-BR", static greetings::farewell), "Tchau.");
+    assert_eq!(translation!("zh-Hant-TW", static greetings::farewell), "再見。");
+    assert_eq!(translation!("zh_hans", static greetings::farewell), "再见。");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
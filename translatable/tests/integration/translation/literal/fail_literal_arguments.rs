@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation_str;
+
+fn main() {
+    let _ = translation_str!("es", static greetings::formal, user = "Eve");
+}
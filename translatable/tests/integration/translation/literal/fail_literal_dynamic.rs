@@ -0,0 +1,7 @@
+#[allow(unused_imports)]
+use translatable::{Language, translation_str};
+
+fn main() {
+    let _ = translation_str!(Language::ES, static greetings::formal);
+    let _ = translation_str!("es", vec!["greetings", "formal"]);
+}
@@ -0,0 +1 @@
+pub mod pass_literal_const;
@@ -0,0 +1,28 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_str};
+
+#[allow(dead_code)]
+const FORMAL: &str = translation_str!("es", static greetings::formal);
+
+#[allow(dead_code)]
+static FAREWELLS: &[(Language, &str)] = &[
+    (Language::PT, translation_str!("pt-BR", static greetings::farewell)),
+    (Language::ZH, translation_str!("zh", static greetings::farewell)),
+];
+
+#[cfg(test)]
+#[test]
+pub fn pass_literal_const() {
+    assert_eq!(FORMAL, "Bueno conocerte.");
+    assert_eq!(FAREWELLS, &[(Language::PT, "Tchau."), (Language::ZH, "再见。")]);
+
+    let greeting = match Language::EN {
+        Language::ES => translation_str!("es", static greetings::formal),
+        _ => translation_str!(["ca", "en"], static greetings::formal),
+    };
+
+    assert_eq!(greeting, "Nice to meet you.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
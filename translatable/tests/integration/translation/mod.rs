@@ -1,3 +1,10 @@
+pub mod display;
+pub mod fluent;
+pub mod formats;
+pub mod gettext;
 pub mod language;
+pub mod literal;
 pub mod path;
+pub mod plural;
 pub mod templates;
+pub mod xliff;
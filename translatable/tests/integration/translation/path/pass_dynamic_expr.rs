@@ -1,6 +1,8 @@
 #[allow(unused_imports)] // trybuild
 use translatable::translation;
 
+translatable::init!();
+
 #[cfg(test)]
 #[test]
 pub fn pass_dynamic_expr() {
@@ -0,0 +1,2 @@
+pub mod pass_dynamic_plural;
+pub mod pass_static_plural;
@@ -0,0 +1,23 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_plural() {
+    let count = 1;
+
+    let result = translation!(Language::EN, static files::count, count)
+        .expect("Translation to be available.");
+
+    assert_eq!(result, "1 file");
+
+    let result = translation!("ru", vec!["files", "count"], count = 5)
+        .expect("Translation to be available.");
+
+    assert_eq!(result, "5 файлов");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_plural() {
+    assert_eq!(translation!("en", static files::count, count = 1), "1 file");
+    assert_eq!(translation!("en", static files::count, count = 3), "3 files");
+    assert_eq!(translation!("es", static files::count, count = 0), "0 archivos");
+    assert_eq!(translation!("es", static files::count, count = 1000000), "1000000 de archivos");
+
+    assert_eq!(translation!("ru", static files::count, count = 21), "21 файл");
+    assert_eq!(translation!("ru", static files::count, count = 3), "3 файла");
+    assert_eq!(translation!("ru", static files::count, count = 11), "11 файлов");
+    assert_eq!(translation!("ru", static files::count, count = 1.5), "1.5 файла");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
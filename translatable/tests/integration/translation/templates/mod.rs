@@ -1,4 +1,8 @@
+pub mod pass_format_spec_dynamic;
+pub mod pass_format_spec_static;
 pub mod pass_ident_ref;
 pub mod pass_multiple_templates;
+pub mod pass_select_dynamic;
+pub mod pass_select_static;
 pub mod pass_trailing_comma;
 pub mod pass_trailing_comma_no_args;
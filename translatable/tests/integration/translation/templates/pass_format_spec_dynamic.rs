@@ -0,0 +1,44 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_display};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_format_spec_dynamic() {
+    let result =
+        translation!(Language::EN, vec!["orders", "summary"], id = 255, count = 3, price = 9.5)
+            .expect("Translation to be available.");
+
+    assert_eq!(result, "Order 0xff:     3 items for 9.50€.");
+
+    let result = translation_display!(
+        Language::ES,
+        vec!["orders", "summary"],
+        id = 4096u32,
+        count = "12",
+        price = 0.125
+    )
+    .expect("Translation to be available.");
+
+    assert_eq!(result.to_string(), "Pedido 0x1000:    12 artículos por 0.12€.");
+
+    // only the resolved translation decides the traits an argument needs.
+    let result = translation!(Language::EN, vec!["files", "count"], count = 2, id = "abc")
+        .expect("Translation to be available.");
+
+    assert_eq!(result, "2 files");
+
+    let error =
+        translation!(Language::EN, vec!["orders", "summary"], id = "abc", count = 3, price = 9.5)
+            .expect_err("The id to not implement LowerHex.");
+
+    assert_eq!(
+        error.cause(),
+        "The argument 'id' doesn't implement the LowerHex trait its placeholder is formatted with \
+         in the 'en' translation for the path 'orders::summary'"
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
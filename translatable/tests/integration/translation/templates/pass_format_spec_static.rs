@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_format_spec_static() {
+    let result = translation!("en", static orders::summary, id = 255, count = 3, price = 9.5);
+
+    assert_eq!(result, "Order 0xff:     3 items for 9.50€.");
+
+    let language = Language::ES;
+    let result =
+        translation!(language, static orders::summary, id = 4096u32, count = 12, price = 0.125)
+            .expect("Translation to be available.");
+
+    assert_eq!(result, "Pedido 0x1000:    12 artículos por 0.12€.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
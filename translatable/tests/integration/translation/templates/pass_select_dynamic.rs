@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+translatable::init!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_select_dynamic() {
+    let gender = "male";
+
+    let result =
+        translation!(Language::EN, vec!["users", "invited"], gender, guest = "Ana", place = "home")
+            .expect("Translation to be available.");
+
+    assert_eq!(result, "He invited Ana to the house.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_select_static() {
+    let guest = "Ana";
+
+    assert_eq!(
+        translation!("en", static users::invited, gender = "female", guest, place = "home"),
+        "She invited Ana to the house."
+    );
+
+    assert_eq!(
+        translation!("es", static users::invited, gender = "x", guest, place = "Madrid"),
+        "Elle invitó a Ana a Madrid."
+    );
+
+    // missing arguments are reported as warnings by default.
+    #[allow(deprecated)]
+    let translation = translation!("en", static users::invited, guest);
+    assert_eq!(translation, "They invited Ana to {place}.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
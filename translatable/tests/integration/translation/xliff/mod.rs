@@ -0,0 +1,2 @@
+pub mod pass_xliff_export;
+pub mod pass_xliff_static;
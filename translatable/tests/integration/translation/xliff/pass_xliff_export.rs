@@ -0,0 +1,31 @@
+#[allow(unused_imports)] // trybuild
+use translatable::xliff_document;
+
+#[cfg(test)]
+#[test]
+pub fn pass_xliff_export() {
+    let document = xliff_document!("en", "es");
+
+    assert!(document.contains(r#"version="2.0" srcLang="en" trgLang="es""#));
+    assert!(document.contains(
+        r#"    <unit id="gettext.welcome">
+      <segment state="translated">
+        <source>Welcome <ph id="1" equiv="{user}" disp="{user}"/>, this came from gettext.</source>
+        <target>Bienvenido <ph id="1" equiv="{user}" disp="{user}"/>, esto vino de gettext.</target>
+      </segment>
+    </unit>"#
+    ));
+    assert!(document.contains(r#"<group id="gettext.files" type="translatable:plural">"#));
+    assert!(document.contains(r#"<unit id="gettext.files.other" name="other">"#));
+
+    let directory = std::env::temp_dir().join("translatable_xliff_export");
+    let _ = std::fs::remove_dir_all(&directory);
+
+    xliff_document!("en", "es", path = directory.join("xliff/es.xlf"))
+        .expect("Expected the document to be written");
+
+    assert_eq!(std::fs::read_to_string(directory.join("xliff/es.xlf")).ok(), Some(document.into()));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
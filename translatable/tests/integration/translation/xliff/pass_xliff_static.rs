@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_xliff_static() {
+    let user = "Ana";
+
+    assert_eq!(
+        translation!("es", static xliff::welcome, user),
+        "Bienvenido Ana, esto vino de XLIFF."
+    );
+
+    assert_eq!(translation!("es", static xliff::files, count = 1), "1 archivo XLIFF");
+    assert_eq!(translation!("es", static xliff::files, count = 3), "3 archivos XLIFF");
+
+    // text braces are escaped, so they can't introduce placeholders.
+    assert_eq!(
+        translation!("es", static xliff::left, gender = "male", user),
+        "Ana, He & {{braces} <3"
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
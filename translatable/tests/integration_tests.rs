@@ -1,13 +1,25 @@
-use std::env::{remove_var, set_var};
-use std::fs::canonicalize;
+use std::env::{remove_var, set_var, vars};
+use std::fs::{canonicalize, copy, create_dir_all, read_dir, write};
+use std::path::Path;
+use std::process::Command;
 use std::sync::Mutex;
 
 use trybuild::TestCases;
 
 mod integration;
 
+translatable::init!();
+
 const PATH_ENV: &str = "TRANSLATABLE_LOCALES_PATH";
 const OVERLAP_ENV: &str = "TRANSLATABLE_OVERLAP";
+const FALLBACK_ENV: &str = "TRANSLATABLE_FALLBACK";
+const ARGUMENT_VALIDATION_ENV: &str = "TRANSLATABLE_ARGUMENT_VALIDATION";
+const RUNTIME_VALIDATION_ENV: &str = "TRANSLATABLE_RUNTIME_VALIDATION";
+const PLACEHOLDER_CONSISTENCY_ENV: &str = "TRANSLATABLE_PLACEHOLDER_CONSISTENCY";
+const LAYOUT_ENV: &str = "TRANSLATABLE_LAYOUT";
+const NAMESPACE_ENV: &str = "TRANSLATABLE_NAMESPACE";
+const OVERLAP_REPORT_ENV: &str = "TRANSLATABLE_OVERLAP_REPORT";
+const CONFIG_ENV: &str = "TRANSLATABLE_CONFIG";
 
 static ENV_MUTEX: Mutex<()> = Mutex::new(());
 
@@ -22,6 +34,25 @@ unsafe fn set_default_env() {
     unsafe {
         set_locales_env("everything_valid");
         remove_var(OVERLAP_ENV);
+        remove_var(FALLBACK_ENV);
+        remove_var(ARGUMENT_VALIDATION_ENV);
+        remove_var(RUNTIME_VALIDATION_ENV);
+        remove_var(PLACEHOLDER_CONSISTENCY_ENV);
+        remove_var(LAYOUT_ENV);
+        remove_var(NAMESPACE_ENV);
+        remove_var(OVERLAP_REPORT_ENV);
+        remove_var(CONFIG_ENV);
+    }
+}
+
+#[inline]
+unsafe fn set_config_env(env: &str) {
+    unsafe {
+        remove_var(PATH_ENV);
+        set_var(
+            CONFIG_ENV,
+            canonicalize(format!("./tests/environments/{env}/translatable.toml")).unwrap(),
+        );
     }
 }
 
@@ -35,6 +66,26 @@ unsafe fn set_locales_env(env: &str) {
     }
 }
 
+/// Copies an environment directory recursively.
+fn copy_environment(from: &Path, to: &Path) {
+    create_dir_all(to).unwrap();
+
+    for entry in read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+
+        if entry
+            .file_type()
+            .unwrap()
+            .is_dir()
+        {
+            copy_environment(&entry.path(), &target);
+        } else {
+            copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
 #[test]
 fn valid_environment() {
     unsafe {
@@ -51,11 +102,27 @@ fn valid_environment() {
         t.pass("./tests/integration/translation/path/pass*.rs");
         t.compile_fail("./tests/integration/translation/path/fail*.rs");
 
+        t.pass("./tests/integration/translation/display/pass*.rs");
+
+        t.pass("./tests/integration/translation/literal/pass*.rs");
+        t.compile_fail("./tests/integration/translation/literal/fail*.rs");
+
         t.pass("./tests/integration/translation/templates/pass*.rs");
         t.compile_fail("./tests/integration/translation/templates/fail*.rs");
 
+        t.pass("./tests/integration/translation/fluent/pass*.rs");
+        t.pass("./tests/integration/translation/formats/pass*.rs");
+        t.pass("./tests/integration/translation/gettext/pass*.rs");
+        t.pass("./tests/integration/translation/xliff/pass*.rs");
+
+        t.pass("./tests/integration/translation/plural/pass*.rs");
+        t.compile_fail("./tests/integration/translation/plural/fail*.rs");
+
         t.pass("./tests/integration/context/pass*.rs");
         t.compile_fail("./tests/integration/context/fail*.rs");
+
+        t.pass("./tests/integration/languages/pass*.rs");
+        t.compile_fail("./tests/integration/languages/fail*.rs");
     }
 }
 
@@ -104,3 +171,467 @@ fn translations_malformed() {
         t.compile_fail("./tests/integration/config/fail_translations_malformed.rs");
     }
 }
+
+#[test]
+fn language_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("language_malformed");
+
+        // invalid language key located in the file.
+        t.compile_fail("./tests/integration/config/fail_language_malformed.rs");
+    }
+}
+
+#[test]
+fn locale_duplicated() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("locale_duplicated");
+
+        // two spellings of the same locale located in the file.
+        t.compile_fail("./tests/integration/config/fail_locale_duplicated.rs");
+    }
+}
+
+#[test]
+fn merge_conflict() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("merge_conflict");
+
+        // translation in a file and nesting in another.
+        t.compile_fail("./tests/integration/config/fail_merge_conflict.rs");
+    }
+}
+
+#[test]
+fn templates_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("templates_malformed");
+
+        // template syntax broken.
+        t.compile_fail("./tests/integration/config/fail_templates_malformed.rs");
+    }
+}
+
+#[test]
+fn format_spec_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("format_spec_malformed");
+
+        // placeholder format specifier with an unknown type.
+        t.compile_fail("./tests/integration/config/fail_format_spec_malformed.rs");
+    }
+}
+
+#[test]
+fn fluent_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("fluent_malformed");
+
+        // fluent resource that can't be mapped to templates.
+        t.compile_fail("./tests/integration/config/fail_fluent_malformed.rs");
+    }
+}
+
+#[test]
+fn formats_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("formats_malformed");
+
+        // yaml sequence where a translation is expected.
+        t.compile_fail("./tests/integration/config/fail_formats_malformed.rs");
+    }
+}
+
+#[test]
+fn gettext_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("gettext_malformed");
+
+        // gettext message without a msgctxt.
+        t.compile_fail("./tests/integration/config/fail_gettext_malformed.rs");
+    }
+}
+
+#[test]
+fn xliff_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("xliff_malformed");
+
+        // xliff target placeholder that isn't in the source.
+        t.compile_fail("./tests/integration/config/fail_xliff_malformed.rs");
+    }
+}
+
+#[test]
+fn xliff_plural_incomplete() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("xliff_plural_incomplete");
+
+        // xliff plural group without an `other` unit.
+        t.compile_fail("./tests/integration/config/fail_xliff_plural_incomplete.rs");
+    }
+}
+
+#[test]
+fn plurals_incomplete() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("plurals_incomplete");
+
+        // plural translation missing required categories.
+        t.compile_fail("./tests/integration/config/fail_plural_categories.rs");
+
+        // every translation checked once by the shared table.
+        t.compile_fail("./tests/integration/config/fail_plural_categories_init.rs");
+    }
+}
+
+#[test]
+fn fallback_config() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(FALLBACK_ENV, "en");
+
+        // configured fallback chain.
+        t.pass("./tests/integration/config/pass_fallback_config.rs");
+        t.compile_fail("./tests/integration/config/fail_fallback_config_unavailable.rs");
+    }
+}
+
+#[test]
+fn argument_validation() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(ARGUMENT_VALIDATION_ENV, "Error");
+        set_var(RUNTIME_VALIDATION_ENV, "Strict");
+
+        // mismatched arguments as errors in compile time and runtime.
+        t.compile_fail("./tests/integration/config/fail_argument_validation.rs");
+        t.pass("./tests/integration/config/pass_runtime_validation.rs");
+    }
+}
+
+#[test]
+fn argument_warning() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+
+        // mismatched arguments as warnings by default, denied here.
+        t.compile_fail("./tests/integration/config/fail_argument_warning.rs");
+    }
+}
+
+#[test]
+fn placeholders_inconsistent() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("placeholders_inconsistent");
+        set_var(PLACEHOLDER_CONSISTENCY_ENV, "Error");
+
+        // languages disagreeing on their placeholders.
+        t.compile_fail("./tests/integration/config/fail_placeholders_inconsistent.rs");
+    }
+}
+
+#[test]
+fn placeholders_warning() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("placeholders_inconsistent");
+
+        // load warnings emitted once by init!() rather than by every call site.
+        t.compile_fail("./tests/integration/config/fail_placeholders_warning.rs");
+    }
+}
+
+#[test]
+fn per_language_layout() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("per_language");
+        set_var(LAYOUT_ENV, "PerLanguage");
+
+        // single language files merged by their path.
+        t.pass("./tests/integration/config/pass_per_language_layout.rs");
+    }
+}
+
+#[test]
+fn file_namespace() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("namespaced");
+        set_var(NAMESPACE_ENV, "FilePath");
+
+        // translation paths prefixed with the file path.
+        t.pass("./tests/integration/config/pass_file_namespace.rs");
+    }
+}
+
+#[test]
+fn namespace_collision() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("namespace_collision");
+        set_var(NAMESPACE_ENV, "FilePath");
+
+        // two files defining the same namespaced path.
+        t.compile_fail("./tests/integration/config/fail_namespace_collision.rs");
+    }
+}
+
+#[test]
+fn overlap_ignore() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+
+        // first file found defining each language kept.
+        t.pass("./tests/integration/config/pass_overlap_ignore.rs");
+    }
+}
+
+#[test]
+fn overlap_overwrite() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_ENV, "Overwrite");
+
+        // last file found defining each language kept.
+        t.pass("./tests/integration/config/pass_overlap_overwrite.rs");
+    }
+}
+
+#[test]
+fn overlap_report() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_REPORT_ENV, "Error");
+
+        // language of a translation defined by two files.
+        t.compile_fail("./tests/integration/config/fail_overlap_report.rs");
+    }
+}
+
+#[test]
+fn multiple_sources() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_config_env("multiple_sources");
+
+        // prefixed vendor source shadowed by a higher priority source.
+        t.pass("./tests/integration/config/pass_multiple_sources.rs");
+    }
+}
+
+#[test]
+fn file_filters() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_config_env("filtered");
+
+        // include, exclude and hidden files.
+        t.pass("./tests/integration/config/pass_file_filters.rs");
+    }
+}
+
+#[test]
+fn file_filters_malformed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_config_env("filters_malformed");
+
+        // a filter entry that isn't a string.
+        t.compile_fail("./tests/integration/config/fail_config_filters_malformed.rs");
+    }
+}
+
+#[test]
+fn file_unfiltered() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("filtered");
+
+        // the readme is rejected without the filters.
+        t.compile_fail("./tests/integration/config/fail_file_unfiltered.rs");
+    }
+}
+
+#[test]
+fn workspace_config() {
+    lock_env!();
+
+    // the environment is copied to build it as a standalone workspace.
+    let workspace = Path::new(env!("CARGO_TARGET_TMPDIR")).join("workspace");
+    copy_environment(Path::new("./tests/environments/workspace"), &workspace);
+
+    let translatable = canonicalize(env!("CARGO_MANIFEST_DIR")).unwrap();
+    copy(translatable.join("../Cargo.lock"), workspace.join("Cargo.lock")).unwrap();
+
+    write(
+        workspace.join("Cargo.toml"),
+        "[workspace]\nresolver = \"2\"\nmembers = [\"inherited\", \"overridden\"]\n",
+    )
+    .unwrap();
+
+    for member in ["inherited", "overridden"] {
+        write(
+            workspace.join(format!("{member}/Cargo.toml")),
+            format!(
+                "[package]\nname = \"{member}\"\nedition = \"2024\"\n\n[dependencies]\n\
+                 translatable = {{ path = {:?} }}\n",
+                translatable.display()
+            ),
+        )
+        .unwrap();
+    }
+
+    for member in ["inherited", "overridden"] {
+        // ran from the workspace root, so the paths must not depend on the current directory.
+        let mut command = Command::new(env!("CARGO"));
+        command
+            .args(["run", "--offline", "--quiet", "--package", member])
+            .current_dir(&workspace)
+            .env_remove("CARGO_TARGET_DIR");
+
+        for (key, _) in vars().filter(|(key, _)| key.starts_with("TRANSLATABLE_")) {
+            command.env_remove(key);
+        }
+
+        let output = command
+            .output()
+            .unwrap();
+
+        assert!(
+            output
+                .status
+                .success(),
+            "{member} failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // each member reports to the shared workspace target directory.
+        assert!(
+            workspace
+                .join(format!("target/translatable/overlaps-{member}.json"))
+                .is_file(),
+            "{member} didn't write its overlap report"
+        );
+    }
+}
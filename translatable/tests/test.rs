@@ -1,5 +1,7 @@
 use translatable::{Language, translation};
 
+translatable::init!();
+
 const NAME: &str = "John";
 const SURNAME: &str = "Doe";
 const RESULT: &str = "¡Hola John Doe! Mi nombre es John Doe {{hola}}";
@@ -0,0 +1,115 @@
+use translatable::Language;
+use translatable_shared::misc::gettext::{PluralForms, PluralFormsError};
+use translatable_shared::misc::plural::{PluralOperands, PluralRule};
+
+#[test]
+pub fn evaluates_plural_forms() {
+    let forms = "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n != 0 ? 1 : 2);"
+        .parse::<PluralForms>()
+        .expect("Plural forms to be valid.");
+
+    assert_eq!(forms.count(), 3);
+    assert_eq!(forms.index(1), 0);
+    assert_eq!(forms.index(11), 1);
+    assert_eq!(forms.index(21), 0);
+    assert_eq!(forms.index(0), 2);
+
+    let forms = " plural = !(n <= 1) ; nplurals = 2 "
+        .parse::<PluralForms>()
+        .expect("Plural forms to be valid.");
+
+    assert_eq!(forms.index(1), 0);
+    assert_eq!(forms.index(5), 1);
+}
+
+#[test]
+pub fn rejects_invalid_plural_forms() {
+    assert_eq!(
+        "plural=n != 1;".parse::<PluralForms>(),
+        Err(PluralFormsError::MissingField("nplurals"))
+    );
+    assert_eq!(
+        "nplurals=0; plural=0;".parse::<PluralForms>(),
+        Err(PluralFormsError::InvalidCount("0".into()))
+    );
+    assert_eq!(
+        "nplurals=2; plural=(n != 1;".parse::<PluralForms>(),
+        Err(PluralFormsError::InvalidExpression(7))
+    );
+}
+
+#[test]
+pub fn plural_forms_match_cldr_rules() {
+    let samples = (0..=2_000)
+        .chain((1..=200).map(|n| n * 1_000))
+        .chain((1..=20).map(|n| n * 100_000))
+        .chain([1_000_000, 2_000_000, 10_000_000]);
+
+    let samples = samples.collect::<Vec<u64>>();
+
+    // a language for each rule.
+    let languages = [
+        Language::ZH,
+        Language::EL,
+        Language::EN,
+        Language::ES,
+        Language::IT,
+        Language::HI,
+        Language::AK,
+        Language::FF,
+        Language::FR,
+        Language::SE,
+        Language::DA,
+        Language::IS,
+        Language::MK,
+        Language::TL,
+        Language::SI,
+        Language::LV,
+        Language::LT,
+        Language::RU,
+        Language::PL,
+        Language::CS,
+        Language::HR,
+        Language::SL,
+        Language::RO,
+        Language::HE,
+        Language::AR,
+        Language::GA,
+        Language::GD,
+        Language::CY,
+        Language::MT,
+        Language::BR,
+        Language::GV,
+        Language::KW,
+    ];
+
+    for language in languages {
+        let rule = PluralRule::from(&language);
+        let forms = rule
+            .plural_forms()
+            .parse::<PluralForms>()
+            .expect("Plural forms to be valid.");
+
+        assert_eq!(
+            forms.count(),
+            rule.categories()
+                .len(),
+            "{language:?}"
+        );
+
+        for n in &samples {
+            let category = rule.select(
+                &n.to_string()
+                    .parse::<PluralOperands>()
+                    .expect("Operands to be valid."),
+            );
+
+            assert_eq!(
+                rule.categories()
+                    .get(forms.index(*n)),
+                Some(&category),
+                "{language:?} with n = {n}"
+            );
+        }
+    }
+}
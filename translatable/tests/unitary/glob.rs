@@ -0,0 +1,39 @@
+use translatable_shared::misc::glob::{GlobError, GlobPattern};
+
+#[test]
+pub fn matches_glob_patterns() {
+    let pattern = "*.md"
+        .parse::<GlobPattern>()
+        .expect("Pattern to be valid.");
+
+    assert!(pattern.matches("README.md"));
+    assert!(pattern.matches("docs/guide.md"));
+    assert!(!pattern.matches("app.toml"));
+
+    let pattern = "./locales/**/?s.toml"
+        .parse::<GlobPattern>()
+        .expect("Pattern to be valid.");
+
+    assert!(pattern.matches("locales/es.toml"));
+    assert!(pattern.matches("locales/app/errors/es.toml"));
+    assert!(!pattern.matches("app/locales/es.toml"));
+    assert!(!pattern.matches("locales/es-AR.toml"));
+
+    let pattern = "drafts/"
+        .parse::<GlobPattern>()
+        .expect("Pattern to be valid.");
+
+    assert!(pattern.matches("drafts"));
+    assert!(pattern.matches("nested/drafts"));
+    assert!(!pattern.matches("drafts/app.toml"));
+}
+
+#[test]
+pub fn rejects_invalid_glob_patterns() {
+    assert_eq!("".parse::<GlobPattern>(), Err(GlobError::EmptySegment));
+    assert_eq!("locales//*.toml".parse::<GlobPattern>(), Err(GlobError::EmptySegment));
+    assert_eq!(
+        "locales/**.toml".parse::<GlobPattern>(),
+        Err(GlobError::MisplacedRecursive("**.toml".into()))
+    );
+}
@@ -0,0 +1,119 @@
+use translatable_shared::misc::json::{JsonError, JsonParser, MAX_DEPTH, json_object, json_string};
+
+fn parse_string(literal: &str) -> Result<String, JsonError> {
+    let source = format!("{{ \"key\": {literal} }}");
+    let table = JsonParser::new(&source).parse_document()?;
+
+    Ok(table
+        .get("key")
+        .and_then(|item| item.as_str())
+        .expect("The value to be a string.")
+        .to_string())
+}
+
+fn parse_value(literal: &str) -> Result<(), JsonError> {
+    let source = format!("{{ \"key\": {literal} }}");
+    JsonParser::new(&source)
+        .parse_document()
+        .map(|_| ())
+}
+
+#[test]
+pub fn parses_string_escapes() {
+    assert_eq!(
+        parse_string(r#""\" \\ \/ \b \f \n \r \t""#),
+        Ok("\" \\ / \u{8} \u{c} \n \r \t".into())
+    );
+    assert_eq!(parse_string(r#""\u00e9\u4e2d""#), Ok("é中".into()));
+
+    assert_eq!(parse_string(r#""\x""#), Err(JsonError::InvalidEscape(10)));
+    assert_eq!(parse_string(r#""\u00g0""#), Err(JsonError::InvalidEscape(10)));
+    assert!(parse_string("\"line\nbreak\"").is_err());
+}
+
+#[test]
+pub fn parses_surrogate_pairs() {
+    assert_eq!(parse_string(r#""\ud83d\ude00""#), Ok("😀".into()));
+    assert_eq!(parse_string(r#""\uD834\uDD1E""#), Ok("𝄞".into()));
+
+    assert!(parse_string(r#""\ud83d""#).is_err());
+    assert!(parse_string(r#""\ud83dA""#).is_err());
+    assert!(parse_string(r#""\ude00""#).is_err());
+}
+
+#[test]
+pub fn follows_number_grammar() {
+    for valid in ["0", "-0", "12", "1.5", "-0.25", "1e3", "1E+3", "1.5e-3", "1e400"] {
+        assert_eq!(parse_value(valid), Ok(()), "{valid} should be valid");
+    }
+
+    for invalid in ["01", "+1", "-", "1.", ".5", "1e", "1e+", "-.5", "0x10"] {
+        assert!(parse_value(invalid).is_err(), "{invalid} should be invalid");
+    }
+}
+
+#[test]
+pub fn rejects_null_values() {
+    assert_eq!(parse_value("null"), Err(JsonError::NullValue(9)));
+    assert_eq!(parse_value("[1, null]"), Err(JsonError::NullValue(13)));
+    assert!(parse_value("nul").is_err());
+}
+
+#[test]
+pub fn limits_nesting_depth() {
+    let nested = |depth: usize| {
+        let mut source = "{ \"key\": 1 }".to_string();
+        for _ in 1..depth {
+            source = format!("{{ \"key\": {source} }}");
+        }
+        source
+    };
+
+    assert!(
+        JsonParser::new(&nested(MAX_DEPTH))
+            .parse_document()
+            .is_ok()
+    );
+    assert!(matches!(
+        JsonParser::new(&nested(MAX_DEPTH + 1)).parse_document(),
+        Err(JsonError::TooDeep(_))
+    ));
+
+    let arrays = format!("{{ \"key\": {}{} }}", "[".repeat(100_000), "]".repeat(100_000));
+    assert!(matches!(JsonParser::new(&arrays).parse_document(), Err(JsonError::TooDeep(_))));
+}
+
+#[test]
+pub fn records_key_locations() {
+    let mut parser = JsonParser::new("{\n  \"a\": {\n    \"b\": \"c\"\n  }\n}");
+    parser
+        .parse_document()
+        .expect("The document to be valid.");
+
+    assert_eq!(
+        parser
+            .locations()
+            .get(&vec!["a".to_string(), "b".to_string()]),
+        Some(&(3, 5))
+    );
+
+    let mut parser = JsonParser::new(r#"{ "a": { "b": "c", "b": "d" } }"#);
+    assert_eq!(
+        parser
+            .parse_document()
+            .err(),
+        Some(JsonError::DuplicatedKey("b".into(), 19))
+    );
+    assert_eq!(parser.keys(), ["a"]);
+}
+
+#[test]
+pub fn writes_json_literals() {
+    let text = "quote \" backslash \\ line\nbell \u{7}";
+    let literal = json_string(text);
+
+    assert_eq!(literal, r#""quote \" backslash \\ line\nbell \u0007""#);
+    assert_eq!(parse_string(&literal), Ok(text.into()));
+
+    assert_eq!(json_object(&[("a", "1"), ("b", "\"")]), r#"{ "a": "1", "b": "\"" }"#);
+}
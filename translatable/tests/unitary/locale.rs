@@ -0,0 +1,105 @@
+use quote::ToTokens;
+use translatable::{Language, Locale};
+
+#[test]
+pub fn locale_parsing_normalizes_subtags() {
+    let locale = "ZH_hant-tw"
+        .parse::<Locale>()
+        .expect("Locale to be valid.");
+
+    assert_eq!(locale.language(), &Language::ZH);
+    assert_eq!(locale.to_string(), "zh-Hant-TW");
+}
+
+#[test]
+pub fn locale_parsing_numeric_region() {
+    let locale = "es-419"
+        .parse::<Locale>()
+        .expect("Locale to be valid.");
+
+    assert_eq!(
+        locale
+            .region()
+            .expect("Region to be parsed.")
+            .as_str(),
+        "419"
+    );
+}
+
+#[test]
+pub fn locale_parsing_fails_invalid() {
+    assert!(
+        "".parse::<Locale>()
+            .is_err()
+    );
+    assert!(
+        "xx-BR"
+            .parse::<Locale>()
+            .is_err()
+    );
+    assert!(
+        "pt-BRA1"
+            .parse::<Locale>()
+            .is_err()
+    );
+    assert!(
+        "pt-BR-Latn"
+            .parse::<Locale>()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn locale_fallbacks_truncate() {
+    let fallbacks = "zh-Hant-TW"
+        .parse::<Locale>()
+        .expect("Locale to be valid.")
+        .fallbacks()
+        .map(|locale| locale.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(fallbacks, vec!["zh-Hant-TW", "zh-Hant", "zh"]);
+}
+
+#[test]
+pub fn locale_from_language() {
+    assert_eq!(
+        Locale::from(Language::ES),
+        "es".parse::<Locale>()
+            .expect("Locale to be valid.")
+    );
+}
+
+#[test]
+pub fn locale_to_tokens() {
+    let tokens = "pt-BR"
+        .parse::<Locale>()
+        .expect("Locale to be valid.")
+        .into_token_stream()
+        .to_string()
+        .replace(" ", ""); // normalize
+
+    assert!(tokens.contains("translatable::shared::misc::locale::Locale::new"));
+    assert!(tokens.contains("translatable::shared::misc::language::Language::PT"));
+    assert!(tokens.contains("translatable::shared::misc::locale::Region::from_bytes"));
+}
+
+#[test]
+pub fn locale_lookup_follows_fallbacks() {
+    let available = ["es", "pt", "zh-Hant"].map(|tag| {
+        tag.parse::<Locale>()
+            .expect("Locale to be valid.")
+    });
+    let fallbacks = [Locale::from(Language::PT)];
+
+    let lookup = |tag: &str| {
+        tag.parse::<Locale>()
+            .expect("Locale to be valid.")
+            .lookup(&available, &fallbacks)
+    };
+
+    assert_eq!(lookup("es-419"), Some(0));
+    assert_eq!(lookup("zh-Hant-TW"), Some(2));
+    assert_eq!(lookup("fr"), Some(1));
+    assert_eq!(Locale::from(Language::FR).lookup(&available, &[]), None);
+}
@@ -1,6 +1,12 @@
 pub mod collection_generation;
+pub mod display_to_error_tokens;
+pub mod gettext;
+pub mod glob;
+pub mod json;
 pub mod language_enum;
+pub mod locale;
+pub mod negotiation;
+pub mod plural;
 pub mod runtime_error;
 pub mod templating;
 pub mod translation_collection;
-pub mod display_to_error_tokens;
@@ -0,0 +1,108 @@
+use translatable::{Locale, NegotiationReason, negotiate_language, parse_accept_language};
+
+fn locales(tags: &[&str]) -> Vec<Locale> {
+    tags.iter()
+        .map(|tag| {
+            tag.parse()
+                .expect("Locale to be valid.")
+        })
+        .collect()
+}
+
+#[test]
+pub fn accept_language_parsing_sorts_by_quality() {
+    let ranges = parse_accept_language("en;q=0.8, es-ES, *;q=0.1, es;q=0.9");
+
+    let tags = ranges
+        .iter()
+        .map(|range| {
+            range
+                .locale()
+                .map(Locale::to_string)
+                .unwrap_or("*".into())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(tags, ["es-ES", "es", "en", "*"]);
+    assert_eq!(ranges[0].quality(), 1.0);
+}
+
+#[test]
+pub fn accept_language_parsing_skips_invalid() {
+    let ranges = parse_accept_language("x-klingon, en;q=2, es;q=abc, fr;q=0.5,");
+
+    assert_eq!(ranges.len(), 1);
+    assert_eq!(ranges[0].quality(), 0.5);
+}
+
+#[test]
+pub fn negotiation_exact_match() {
+    let negotiation = negotiate_language("es-ES,es;q=0.9,en;q=0.8", &locales(&["en", "es-ES"]))
+        .expect("A language to be negotiated.");
+
+    assert_eq!(
+        negotiation
+            .locale()
+            .to_string(),
+        "es-ES"
+    );
+    assert_eq!(negotiation.reason(), &NegotiationReason::Exact(1.0));
+}
+
+#[test]
+pub fn negotiation_less_and_more_specific() {
+    let less =
+        negotiate_language("pt-BR", &locales(&["en", "pt"])).expect("A language to be negotiated.");
+
+    assert_eq!(
+        less.locale()
+            .to_string(),
+        "pt"
+    );
+    assert!(matches!(less.reason(), NegotiationReason::LessSpecific(..)));
+
+    let more =
+        negotiate_language("pt", &locales(&["en", "pt-BR"])).expect("A language to be negotiated.");
+
+    assert_eq!(
+        more.locale()
+            .to_string(),
+        "pt-BR"
+    );
+    assert!(matches!(more.reason(), NegotiationReason::MoreSpecific(..)));
+}
+
+#[test]
+pub fn negotiation_wildcard_and_rejection() {
+    let available = locales(&["en", "es"]);
+
+    let negotiation = negotiate_language("fr, en;q=0, *;q=0.5", &available)
+        .expect("A language to be negotiated.");
+
+    assert_eq!(
+        negotiation
+            .locale()
+            .to_string(),
+        "es"
+    );
+    assert_eq!(negotiation.reason(), &NegotiationReason::Wildcard(0.5));
+
+    assert!(negotiate_language("fr, en;q=0", &available).is_none());
+}
+
+#[test]
+pub fn negotiation_rejection_excludes_every_match() {
+    let negotiation = negotiate_language("es;q=0, *", &locales(&["es", "es-ES", "en"]))
+        .expect("A language to be negotiated.");
+
+    assert_eq!(
+        negotiation
+            .locale()
+            .to_string(),
+        "en"
+    );
+    assert_eq!(negotiation.reason(), &NegotiationReason::Wildcard(1.0));
+
+    assert!(negotiate_language("es-ES, es;q=0", &locales(&["es"])).is_none());
+    assert!(negotiate_language("es, es-MX;q=0", &locales(&["es-MX"])).is_none());
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use toml_edit::DocumentMut;
+use translatable::{Language, Locale};
+use translatable_shared::misc::plural::{
+    PluralCategory, PluralOperands, PluralRule, PluralTranslation,
+};
+use translatable_shared::misc::templating::FormatString;
+use translatable_shared::translations::node::{Translation, TranslationNode, TranslationNodeError};
+
+fn select(language: &Language, count: &str) -> PluralCategory {
+    PluralRule::from(language).select(
+        &count
+            .parse::<PluralOperands>()
+            .expect("Operands to be valid."),
+    )
+}
+
+#[test]
+pub fn selects_english_categories() {
+    assert_eq!(select(&Language::EN, "1"), PluralCategory::One);
+    assert_eq!(select(&Language::EN, "1.0"), PluralCategory::Other);
+    assert_eq!(select(&Language::EN, "0"), PluralCategory::Other);
+    assert_eq!(select(&Language::ES, "1.0"), PluralCategory::One);
+    assert_eq!(select(&Language::FR, "0"), PluralCategory::One);
+}
+
+#[test]
+pub fn selects_romance_many_category() {
+    assert_eq!(select(&Language::ES, "1000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::ES, "2000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::ES, "1000001"), PluralCategory::Other);
+    assert_eq!(select(&Language::ES, "1000000.5"), PluralCategory::Other);
+    assert_eq!(select(&Language::FR, "1000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::IT, "1000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::CA, "3000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::PT, "1000000"), PluralCategory::Many);
+    assert_eq!(select(&Language::EN, "1000000"), PluralCategory::Other);
+}
+
+#[test]
+pub fn selects_with_compact_exponent() {
+    assert_eq!(select(&Language::ES, "1c6"), PluralCategory::Many);
+    assert_eq!(select(&Language::ES, "1.5c6"), PluralCategory::Many);
+    assert_eq!(select(&Language::ES, "1e3"), PluralCategory::Other);
+    assert_eq!(select(&Language::ES, "1.2c3"), PluralCategory::Other);
+    assert_eq!(select(&Language::FR, "1.5c9"), PluralCategory::Many);
+    assert_eq!(select(&Language::EN, "1e0"), PluralCategory::One);
+    assert_eq!(select(&Language::PT, "5e-1"), PluralCategory::One);
+}
+
+#[test]
+pub fn selects_rule_from_locale() {
+    let select = |locale: &str, count: &str| {
+        PluralRule::from(
+            &locale
+                .parse::<Locale>()
+                .expect("Locale to be valid."),
+        )
+        .select(
+            &count
+                .parse::<PluralOperands>()
+                .expect("Operands to be valid."),
+        )
+    };
+
+    assert_eq!(select("pt", "0"), PluralCategory::One);
+    assert_eq!(select("pt-BR", "0"), PluralCategory::One);
+    assert_eq!(select("pt-PT", "0"), PluralCategory::Other);
+    assert_eq!(select("pt-AO", "0"), PluralCategory::Other);
+    assert_eq!(select("pt-PT", "1"), PluralCategory::One);
+    assert_eq!(select("pt-PT", "1000000"), PluralCategory::Many);
+    assert_eq!(select("es-MX", "1000000"), PluralCategory::Many);
+}
+
+#[test]
+pub fn selects_slavic_categories() {
+    assert_eq!(select(&Language::RU, "21"), PluralCategory::One);
+    assert_eq!(select(&Language::RU, "22"), PluralCategory::Few);
+    assert_eq!(select(&Language::RU, "12"), PluralCategory::Many);
+    assert_eq!(select(&Language::PL, "22"), PluralCategory::Few);
+    assert_eq!(select(&Language::PL, "21"), PluralCategory::Many);
+    assert_eq!(select(&Language::CS, "0.5"), PluralCategory::Many);
+}
+
+#[test]
+pub fn selects_arabic_categories() {
+    assert_eq!(select(&Language::AR, "0"), PluralCategory::Zero);
+    assert_eq!(select(&Language::AR, "2"), PluralCategory::Two);
+    assert_eq!(select(&Language::AR, "103"), PluralCategory::Few);
+    assert_eq!(select(&Language::AR, "111"), PluralCategory::Many);
+    assert_eq!(select(&Language::AR, "100"), PluralCategory::Other);
+}
+
+#[test]
+pub fn operands_reject_non_numbers() {
+    assert!(
+        "abc"
+            .parse::<PluralOperands>()
+            .is_err()
+    );
+    assert!(
+        ".5".parse::<PluralOperands>()
+            .is_err()
+    );
+    assert!(
+        "1e".parse::<PluralOperands>()
+            .is_err()
+    );
+    assert!(
+        "1e400"
+            .parse::<PluralOperands>()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn plural_translation_replaces_selected_variant() {
+    let node = TranslationNode::try_from(
+        r#"
+        [files.count.en]
+        one = "{count} file"
+        other = "{count} files"
+        "#
+        .parse::<DocumentMut>()
+        .expect("TOML to be parsed correctly.")
+        .as_table(),
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let translation = node
+        .find_path(&vec!["files", "count"])
+        .expect("Translation to be found.")
+        .get(&Language::EN)
+        .expect("Language to be available.");
+
+    assert_eq!(translation.replace_with(&HashMap::from([("count".into(), "1".into())])), "1 file");
+    assert_eq!(translation.replace_with(&HashMap::new()), "{count} files");
+}
+
+#[test]
+pub fn plural_translation_requires_other() {
+    let result = TranslationNode::try_from(
+        r#"
+        [files.count]
+        en = { one = "{count} file" }
+        "#
+        .parse::<DocumentMut>()
+        .expect("TOML to be parsed correctly.")
+        .as_table(),
+    );
+
+    assert!(matches!(result, Err(TranslationNodeError::MissingOtherCategory(_))));
+}
+
+#[test]
+pub fn plural_translation_falls_back_to_other() {
+    let template = |text: &str| {
+        text.parse::<FormatString>()
+            .expect("Template to be valid.")
+    };
+
+    let translation = Translation::Plural(PluralTranslation::new(
+        PluralRule::from(&Language::EN),
+        template("{count} files"),
+        HashMap::from([
+            (PluralCategory::One, template("{count} file")),
+            (PluralCategory::Other, template("ignored")),
+        ]),
+    ));
+
+    let values = HashMap::from([("count".to_string(), "1".to_string())]);
+    assert_eq!(translation.replace_with(&values), "1 file");
+
+    let values = HashMap::from([("count".to_string(), "2".to_string())]);
+    assert_eq!(translation.replace_with(&values), "2 files");
+
+    let values = HashMap::from([("count".to_string(), "many".to_string())]);
+    assert_eq!(translation.replace_with(&values), "many files");
+}
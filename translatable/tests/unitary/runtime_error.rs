@@ -1,3 +1,4 @@
+use translatable::shared::misc::templating::ArgumentError;
 use translatable::{Error, Language};
 
 #[test]
@@ -8,7 +9,18 @@ pub fn runtime_error_outputs() {
     );
 
     assert_eq!(
-        Error::LanguageNotAvailable(Language::ES, "path::to::translation".into()).cause(),
-        "The language 'ES' ('Spanish') is not available for the path 'path::to::translation'"
-    )
+        Error::LanguageNotAvailable(Language::ES.into(), "path::to::translation".into()).cause(),
+        "The language 'es' is not available for the path 'path::to::translation'"
+    );
+
+    assert_eq!(
+        Error::InvalidArgument(
+            ArgumentError::Unknown("usr".into()),
+            Language::ES.into(),
+            "path::to::translation".into()
+        )
+        .cause(),
+        "The argument 'usr' doesn't match any placeholder in the 'es' translation for the path \
+         'path::to::translation'"
+    );
 }
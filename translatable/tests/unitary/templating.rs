@@ -1,7 +1,15 @@
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::str::FromStr;
 
-use translatable_shared::misc::templating::FormatString;
+use toml_edit::ImDocument;
+use translatable_shared::misc::format_spec::{
+    FormatProbe, FormatSpec, FormatSpecError, FormatType, LowerHexFallback, LowerHexProbe,
+};
+use translatable_shared::misc::templating::{
+    ArgumentError, FormatArgument, FormatString, TemplateError,
+};
+use translatable_shared::translations::node::{Translation, TranslationNode, TranslationNodeError};
 
 #[test]
 pub fn does_not_replace_not_found() {
@@ -12,6 +20,35 @@ pub fn does_not_replace_not_found() {
     assert_eq!(result, "Hello {name}");
 }
 
+#[test]
+pub fn literal_only_without_templates() {
+    let literal = |template: &str| {
+        FormatString::from_str(template)
+            .expect("Format string to be valid.")
+            .literal()
+            .map(str::to_string)
+    };
+
+    assert_eq!(literal("Hello {{name}}"), Some("Hello {{name}}".into()));
+    assert_eq!(literal("Hello {name}"), None);
+    assert_eq!(literal("{gender, select, other {They}}"), None);
+}
+
+#[test]
+pub fn writes_display_arguments() {
+    let mut output = String::new();
+
+    FormatString::from_str("{user} has {count} {kind, select, file {files} other {items}}")
+        .expect("Format string to be valid.")
+        .write_to(
+            &mut output,
+            &[("user", &"Ana" as &dyn Display), ("count", &3), ("kind", &"file")],
+        )
+        .expect("Writing to a string to be OK.");
+
+    assert_eq!(output, "Ana has 3 files");
+}
+
 #[test]
 pub fn replaces_single_template() {
     let result = FormatString::from_str("Hello {name}")
@@ -65,3 +102,234 @@ pub fn gives_original_string() {
 
     assert_eq!(result.original(), "Hello {name} how are you doing {day}?");
 }
+
+#[test]
+pub fn selects_branch() {
+    let template = FormatString::from_str(
+        "{gender, select, male {He is {age}} female {She is {age}} other {They are {age}}}",
+    )
+    .expect("Format string to be valid.");
+
+    let result = template.replace_with(&HashMap::from([
+        ("gender".into(), "female".into()),
+        ("age".into(), "30".into()),
+    ]));
+
+    assert_eq!(result, "She is 30");
+
+    let result = template.replace_with(&HashMap::from([("gender".into(), "robot".into())]));
+
+    assert_eq!(result, "They are {age}");
+}
+
+#[test]
+pub fn selects_nested_branch() {
+    let result =
+        FormatString::from_str("{a, select, x {{b, select, y {xy} other {x?}}} other {?}}")
+            .expect("Format string to be valid.")
+            .replace_with(&HashMap::from([("a".into(), "x".into()), ("b".into(), "y".into())]));
+
+    assert_eq!(result, "xy");
+}
+
+#[test]
+pub fn displays_template_syntax() {
+    let template = "{{escaped}} {name}, {gender, select, male {He saw {name}} other {They}}!";
+    let displayed = FormatString::from_str(template)
+        .expect("Format string to be valid.")
+        .nodes()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<String>();
+
+    assert_eq!(displayed, template);
+}
+
+#[test]
+pub fn lists_keys() {
+    let template = FormatString::from_str("{name} {gender, select, male {{name}} other {{title}}}")
+        .expect("Format string to be valid.");
+
+    assert_eq!(template.keys(), ["name", "gender", "title"]);
+}
+
+#[test]
+pub fn checks_arguments() {
+    let translation = Translation::Singular(
+        FormatString::from_str("{author} deleted {target}").expect("Format string to be valid."),
+    );
+
+    assert_eq!(translation.check_arguments(&["author", "target"]), []);
+    assert_eq!(
+        translation.check_arguments(&["autor", "target"]),
+        [ArgumentError::Unknown("autor".into()), ArgumentError::Missing("author".into())]
+    );
+}
+
+#[test]
+pub fn finds_placeholder_mismatches() {
+    let source = "[a]\nen = \"{author} deleted {target}\"\nes = \"{author} borró\"\n";
+    let table = source
+        .parse::<ImDocument<String>>()
+        .expect("TOML to be parsed correctly.");
+
+    let node = TranslationNode::try_from(table.as_table()).expect("Translations to be valid.");
+    let objects = node.objects();
+    let mismatches = objects[0]
+        .1
+        .placeholder_mismatches();
+
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(
+        mismatches[0]
+            .0
+            .to_string(),
+        "es"
+    );
+    assert_eq!(mismatches[0].1, ["target"]);
+}
+
+#[test]
+pub fn fails_select_without_other() {
+    let result = FormatString::from_str("Hi {gender, select, male {him}}");
+
+    assert!(matches!(result, Err(TemplateError::MissingOtherBranch(_, span)) if span == (3..31)));
+}
+
+#[test]
+pub fn fails_invalid_format_spec() {
+    let result = FormatString::from_str("{price:.2f}");
+    assert!(matches!(
+        result,
+        Err(TemplateError::InvalidFormatSpec(FormatSpecError::UnknownType(kind), span))
+            if kind == "f" && span == (7..10)
+    ));
+
+    let result = FormatString::from_str("{price:.}");
+    assert!(matches!(
+        result,
+        Err(TemplateError::InvalidFormatSpec(FormatSpecError::Invalid(_), _))
+    ));
+
+    let result = FormatString::from_str("{price:{width}}");
+    assert!(matches!(result, Err(TemplateError::Expected(_, 7))));
+}
+
+#[test]
+pub fn format_spec_writes_as_format() {
+    let spec = |spec: &str| FormatSpec::from_str(spec).expect("Format spec to be valid.");
+
+    let display = |written: &str, value: &dyn Display| {
+        let mut output = String::new();
+        spec(written)
+            .write_display(value, &mut output)
+            .expect("Writing to a string to be OK.");
+        output
+    };
+
+    assert_eq!(display(".2", &19.98765), format!("{:.2}", 19.98765));
+    assert_eq!(display(">5", &42), format!("{:>5}", 42));
+    assert_eq!(display("*^7", &"ab"), format!("{:*^7}", "ab"));
+    assert_eq!(display("<4", &7), format!("{:<4}", 7));
+    assert_eq!(display("+08.3", &-1.5), format!("{:+08.3}", -1.5));
+    assert_eq!(display("5", &"ab"), format!("{:5}", "ab"));
+    assert_eq!(display("05", &-42), format!("{:05}", -42));
+
+    let mut output = String::new();
+    let hex = spec("#010x");
+    assert_eq!(hex.kind(), FormatType::LowerHex);
+    hex.write_lower_hex(&255, &mut output)
+        .expect("Writing to a string to be OK.");
+    assert_eq!(output, format!("{:#010x}", 255));
+
+    let mut output = String::new();
+    spec("-^9?")
+        .write_debug("hi", &mut output)
+        .expect("Writing to a string to be OK.");
+    assert_eq!(output, format!("{:-^9?}", "hi"));
+}
+
+#[test]
+pub fn writes_format_arguments() {
+    let template = FormatString::from_str("{id:#x} costs {price:>6.2} {price}")
+        .expect("Format string to be valid.");
+
+    let id = 255;
+    let price = 9.5;
+    let mut output = String::new();
+
+    let id_probe = &FormatProbe(&id);
+
+    template
+        .write_to(
+            &mut output,
+            &[
+                ("id", FormatArgument::new(&id, &[id_probe.lower_hex()])),
+                ("price", FormatArgument::new(&price, &[])),
+            ],
+        )
+        .expect("Writing to a string to be OK.");
+
+    assert_eq!(output, "0xff costs   9.50 9.5");
+
+    // pre-rendered values are formatted as strings.
+    let result = template.replace_with(&HashMap::from([
+        ("id".into(), "255".into()),
+        ("price".into(), "9.5".into()),
+    ]));
+
+    assert_eq!(result, format!("255 costs {:>6.2} 9.5", "9.5"));
+}
+
+#[test]
+pub fn checks_format_argument_traits() {
+    let translation = Translation::Singular(
+        FormatString::from_str("{id:#x} {name}").expect("Format string to be valid."),
+    );
+
+    let id = 255;
+    let name = "abc";
+
+    let id_probe = &FormatProbe(&id);
+    let name_probe = &FormatProbe(&name);
+
+    assert_eq!(
+        translation.check_formats(&[
+            ("id", FormatArgument::new(&id, &[id_probe.lower_hex()])),
+            ("name", FormatArgument::new(&name, &[name_probe.lower_hex()])),
+        ]),
+        None
+    );
+
+    assert_eq!(
+        translation.check_formats(&[("id", FormatArgument::new(&name, &[name_probe.lower_hex()]))]),
+        Some(ArgumentError::UnsupportedFormat("id".into(), FormatType::LowerHex))
+    );
+
+    // pre-rendered values are written with Display.
+    assert_eq!(translation.check_formats(&HashMap::from([("id".into(), "abc".into())])), None);
+}
+
+#[test]
+pub fn fails_unknown_argument_type() {
+    let result = FormatString::from_str("{count, number}");
+
+    assert!(matches!(result, Err(TemplateError::UnknownArgumentType(kind, _)) if kind == "number"));
+}
+
+#[test]
+pub fn locates_template_errors() {
+    let source = "[a]\nen = \"Hello {na me}\"\n";
+    let table = source
+        .parse::<ImDocument<String>>()
+        .expect("TOML to be parsed correctly.");
+
+    let error = TranslationNode::try_from(table.as_table())
+        .err()
+        .expect("Template to be invalid.")
+        .locate(source);
+
+    assert!(
+        matches!(&error, TranslationNodeError::TemplateValidation(_, Some(span)) if &source[span.clone()] == "na me")
+    );
+}
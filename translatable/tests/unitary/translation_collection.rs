@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use toml_edit::DocumentMut;
 use translatable::Language;
 use translatable_shared::translations::collection::TranslationNodeCollection;
-use translatable_shared::translations::node::TranslationNode;
+use translatable_shared::translations::node::{TranslationNode, TranslationNodeError};
 
 const FILE_1: &str = r#"
 [greetings.formal]
@@ -17,9 +17,17 @@ es = "Que haces?"
 en = "Wyd?"
 "#;
 
+const SINGLE_LANGUAGE_FILE: &str = r#"
+[greetings]
+formal = "Hello"
+
+[files]
+count = { one = "{count} file", other = "{count} files" }
+"#;
+
 #[test]
 pub fn loads_and_finds_collection() {
-    let collection = TranslationNodeCollection::new(HashMap::from([
+    let collection = TranslationNodeCollection::merged([
         (
             "a".into(),
             TranslationNode::try_from(
@@ -40,7 +48,8 @@ pub fn loads_and_finds_collection() {
             )
             .expect("TOML to follow the translation rules."),
         ),
-    ]));
+    ])
+    .expect("Files to be mergeable.");
 
     let translation = collection
         .find_path(
@@ -55,3 +64,150 @@ pub fn loads_and_finds_collection() {
 
     assert_eq!(translation, "Hola");
 }
+
+#[test]
+pub fn merges_collection_by_priority() {
+    let parse = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::merged([
+        ("a".into(), parse(FILE_1)),
+        ("b".into(), parse("[greetings.formal]\nes = \"Buenas\"\nfr = \"Bonjour\"")),
+    ])
+    .expect("Files to be mergeable.");
+
+    let formal = collection
+        .find_path(&vec!["greetings", "formal"])
+        .expect("Translation to be found.");
+
+    assert_eq!(
+        formal
+            .get(&Language::ES)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::new()),
+        "Hola"
+    );
+    assert_eq!(
+        formal
+            .get(&Language::FR)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::new()),
+        "Bonjour"
+    );
+
+    let conflict = TranslationNodeCollection::merged([
+        ("a".into(), parse(FILE_1)),
+        ("b".into(), parse("[greetings.formal.polite]\nes = \"Hola\"")),
+    ]);
+
+    assert!(matches!(
+        conflict,
+        Err((_, path, keys)) if path == "b" && keys == ["greetings", "formal"]
+    ));
+}
+
+#[test]
+pub fn merges_nodes() {
+    let parse = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let mut node = parse(FILE_1);
+    node.merge(parse(FILE_2))
+        .expect("Nodes to be mergeable.");
+    node.merge(parse("[greetings.formal]\nes = \"Buenas\"\nfr = \"Bonjour\""))
+        .expect("Nodes to be mergeable.");
+
+    let formal = node
+        .find_path(&vec!["greetings", "formal"])
+        .expect("Translation to be found.");
+
+    assert_eq!(
+        formal
+            .get(&Language::ES)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::new()),
+        "Hola"
+    );
+    assert!(
+        formal
+            .get(&Language::FR)
+            .is_some()
+    );
+    assert!(
+        node.find_path(&vec!["greetings", "informal"])
+            .is_some()
+    );
+    assert!(
+        node.merge(parse("[greetings.formal.polite]\nes = \"Hola\""))
+            .is_err()
+    );
+}
+
+#[test]
+pub fn keys_single_language_tables() {
+    let parse = |file: &str, locale: &str| {
+        TranslationNode::try_from_language_traced(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+            &locale
+                .parse()
+                .expect("Locale to be valid."),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let mut node = parse(SINGLE_LANGUAGE_FILE, "en");
+    node.merge(parse("[greetings]\nformal = \"Hola\"", "es"))
+        .expect("Nodes to be mergeable.");
+
+    let formal = node
+        .find_path(&vec!["greetings", "formal"])
+        .expect("Translation to be found.");
+
+    assert_eq!(
+        formal
+            .get(&Language::ES)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::new()),
+        "Hola"
+    );
+    assert_eq!(
+        node.find_path(&vec!["files", "count"])
+            .expect("Translation to be found.")
+            .get(&Language::EN)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::from([("count".into(), "2".into())])),
+        "2 files"
+    );
+}
+
+#[test]
+pub fn rejects_duplicated_locales() {
+    let table = "[greetings.formal]\npt_BR = \"Olá\"\nen = \"Hello\"\npt-BR = \"Oi\""
+        .parse::<DocumentMut>()
+        .expect("TOML to be parsed correctly.");
+
+    let Err((error, keys)) = TranslationNode::try_from_traced(table.as_table()) else {
+        panic!("Duplicated locales to be rejected.");
+    };
+
+    assert!(matches!(
+        error,
+        TranslationNodeError::DuplicatedLocale(_, first, second)
+            if first == "pt_BR" && second == "pt-BR"
+    ));
+    assert_eq!(keys, vec!["greetings", "formal", "pt-BR"]);
+}
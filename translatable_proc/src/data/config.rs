@@ -4,14 +4,24 @@
 //! helper functions for parsing and loading
 //! user configuration files.
 
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::env::var;
-use std::fs::read_to_string;
+use std::fmt::Display;
+use std::fs::{canonicalize, read_to_string};
 use std::io::Error as IoError;
-use std::sync::OnceLock;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
 
 use strum::EnumString;
 use thiserror::Error;
-use toml_edit::{DocumentMut, TomlError};
+use toml_edit::{DocumentMut, Item, TomlError, Value};
+use translatable_shared::misc::glob::GlobPattern;
+use translatable_shared::misc::locale::Locale;
+
+use super::formats::{TranslationFormat, format_named};
+use super::location::SourceLocation;
+use super::stamps::FileStamps;
 
 /// Configuration error enum.
 ///
@@ -47,25 +57,19 @@ pub enum ConfigError {
     /// The configuration file contents could
     /// not be parsed as TOML.
     ///
-    /// The error is formatted displaying
-    /// the file name hardcoded as `./translatable.toml`
-    /// and appended with the line and character.
-    ///
-    /// The enum implements [`From<toml::de::Error>`] to
-    /// allow conversion from [`toml::de::Error`]
+    /// The error is formatted displaying the
+    /// file path appended with the line and column
+    /// of the error, and a snippet of that line.
     ///
     /// **Parameters**
     /// * `0` - The TOML deserialization error derivation.
-    ///
-    /// [`From<toml::de::Error>`]: toml::de::Error
+    /// * `1` - The location of the error in the configuration file.
     #[error(
-        "TOML parse error '{}'{}",
-        .0.message(),
-        .0.span()
-            .map(|l| format!(" in ./translatable.toml:{}:{}", l.start, l.end))
-            .unwrap_or_else(|| "".into())
+        "{1}: TOML parse error '{reason}'{snippet}",
+        reason = _0.message(),
+        snippet = _1.snippet()
     )]
-    ParseToml(#[from] TomlError),
+    ParseToml(TomlError, SourceLocation),
 
     /// Parse value error.
     ///
@@ -80,11 +84,22 @@ pub enum ConfigError {
     ///
     /// **Parameters**
     /// * `0` - The configuration key for which the entry
-    /// could not be parsed.
+    ///   could not be parsed.
     /// * `1` - The configuration value that couldn't be
-    /// parsed.
+    ///   parsed.
     #[error("Couldn't parse configuration entry '{1}' for '{0}'")]
     InvalidValue(String, String),
+
+    /// Invalid translation source.
+    ///
+    /// An entry of the `sources` array is not a table
+    /// or one of its keys has an invalid value.
+    ///
+    /// **Parameters**
+    /// * `0` - The one based position of the source in the array.
+    /// * `1` - The reason the source is invalid.
+    #[error("Invalid translation source #{0}, {1}")]
+    InvalidSource(usize, String),
 }
 
 /// Defines the search strategy for configuration files.
@@ -119,22 +134,238 @@ pub enum TranslationOverlap {
     Ignore,
 }
 
+/// Severity of a compile-time validation.
+///
+/// Represents the possible values of validation
+/// fields such as `argument_validation`, which
+/// determine whether a failed check stops the
+/// compilation or not.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum ValidationLevel {
+    /// The check fails the compilation.
+    Error,
+
+    /// The check emits a warning (default)
+    #[default]
+    Warning,
+
+    /// The check is skipped.
+    Off,
+}
+
+/// Runtime validation strategy.
+///
+/// Represents the possible values of the parsed
+/// `runtime_validation` field, which determine whether
+/// the translations resolved in runtime are validated
+/// against the provided arguments.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum RuntimeValidation {
+    /// Mismatched arguments are ignored (default)
+    #[default]
+    Lenient,
+
+    /// Mismatched arguments return an error
+    Strict,
+}
+
+/// Gettext key scheme.
+///
+/// Represents the possible values of the parsed
+/// `gettext_keys` field, which determine how the
+/// messages of gettext catalogs map to translation
+/// paths.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum GettextKeys {
+    /// The `msgctxt` is the dotted translation path and the
+    /// `msgid` is the source text (default)
+    #[default]
+    Context,
+
+    /// The `msgid` is the dotted translation path
+    MessageId,
+}
+
+/// Translation file layout.
+///
+/// Represents the possible values of the parsed
+/// `layout` field, which determine whether the TOML,
+/// JSON and YAML translation files hold every language
+/// or a single one.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum FileLayout {
+    /// Every file holds translation objects keyed
+    /// by language (default)
+    #[default]
+    Combined,
+
+    /// Every file holds the plain translations of the
+    /// language named by the file or one of its directories
+    PerLanguage,
+}
+
+/// Translation path namespace.
+///
+/// Represents the possible values of the parsed
+/// `namespace` field, which determine whether the
+/// path of a translation file is part of the path
+/// of the translations it contains.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum PathNamespace {
+    /// Every file shares the root of the
+    /// translation paths (default)
+    #[default]
+    Global,
+
+    /// The file path relative to the translations
+    /// directory prefixes its translation paths
+    FilePath,
+}
+
+/// Shadowed translation report.
+///
+/// Represents the possible values of the parsed
+/// `overlap_report` field, which determine how the
+/// languages of a translation dropped by the `overlap`
+/// strategy are reported.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum OverlapReport {
+    /// The first shadowed translation fails the compilation.
+    Error,
+
+    /// Each shadowed translation emits a warning.
+    Warning,
+
+    /// The shadowed translations are written to
+    /// `target/translatable/overlaps-<crate>.json`,
+    /// relative to the workspace root directory.
+    File,
+
+    /// The shadowed translations are not reported (default)
+    #[default]
+    Off,
+}
+
+/// Hidden file handling.
+///
+/// Represents the possible values of the parsed
+/// `hidden_files` field, which determine whether the
+/// files and directories whose name starts with a dot,
+/// such as `.gitkeep`, are loaded as translations.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum HiddenFiles {
+    /// Hidden files and directories are skipped (default)
+    #[default]
+    Ignore,
+
+    /// Hidden files and directories are loaded
+    /// like any other file.
+    Load,
+}
+
+/// Symbolic link handling.
+///
+/// Represents the possible values of the parsed
+/// `symlinks` field, which determine what happens
+/// with the symbolic links found in the translations
+/// directory.
+#[derive(Default, Clone, Copy, PartialEq, EnumString)]
+pub enum SymlinkPolicy {
+    /// Symbolic links are loaded as their target (default)
+    #[default]
+    Follow,
+
+    /// Symbolic links are skipped.
+    Ignore,
+
+    /// Symbolic links fail the compilation.
+    Reject,
+}
+
+/// Translation source.
+///
+/// A directory translations are loaded from, declared
+/// in the `sources` array of the configuration file
+/// along its priority. The sources with higher priority
+/// shadow the lower ones for each translation language.
+///
+/// # Example
+/// ```toml
+/// [[sources]]
+/// path = "./vendor/ui/translations"
+/// prefix = "vendor::ui"
+/// format = "json"
+///
+/// [[sources]]
+/// path = "./translations"
+/// priority = 10
+/// ```
+pub struct TranslationSource {
+    /// Directory containing the translation files.
+    ///
+    /// Resolved against the directory of the
+    /// configuration file that declares it.
+    path: String,
+
+    /// Format of every file in the directory.
+    ///
+    /// If `None` the format of each file is
+    /// inferred from its extension.
+    format: Option<&'static dyn TranslationFormat>,
+
+    /// Translation path prefix.
+    ///
+    /// The path segments every translation
+    /// of the source is nested under.
+    prefix: Vec<String>,
+}
+
+impl TranslationSource {
+    /// Get the source directory.
+    ///
+    /// **Returns**
+    /// The path to the directory the source files
+    /// are loaded from.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the source format.
+    ///
+    /// **Returns**
+    /// The format every file of the source is parsed
+    /// as, `None` if it's inferred from each file.
+    pub fn format(&self) -> Option<&'static dyn TranslationFormat> {
+        self.format
+    }
+
+    /// Get the translation path prefix.
+    ///
+    /// **Returns**
+    /// The path segments the source translations
+    /// are nested under, empty if there is no prefix.
+    pub fn prefix(&self) -> &[String] {
+        &self.prefix
+    }
+}
+
 /// Main configuration structure for the translation system.
 ///
 /// Holds all the core parameters used to control how translation files are
 /// located, processed, and how conflicts are resolved between overlapping
 /// translations.
 pub struct MacroConfig {
-    /// Path to the directory containing translation files.
+    /// Translation sources.
     ///
-    /// Specifies the base location where the system will search for
-    /// translation files.
+    /// The directories translations are loaded from, sorted
+    /// by priority, read [`TranslationSource`]. A single `path`
+    /// key declares a single source.
     ///
     /// # Example
     /// ```toml
     /// path = "./locales"
     /// ```
-    path: String,
+    sources: Vec<TranslationSource>,
 
     /// File processing order strategy.
     ///
@@ -147,16 +378,127 @@ pub struct MacroConfig {
     /// Determines the behavior when multiple files contain the same
     /// translation key.
     overlap: TranslationOverlap,
+
+    /// Language fallback chain.
+    ///
+    /// Languages tried in order when the requested language
+    /// is not available for a translation, unless a macro
+    /// invocation overrides it.
+    ///
+    /// # Example
+    /// ```toml
+    /// fallback = ["es", "en"]
+    /// ```
+    fallback: Vec<Locale>,
+
+    /// Argument validation severity.
+    ///
+    /// Determines whether unknown arguments and missing
+    /// placeholders in static paths are reported as
+    /// errors or warnings.
+    argument_validation: ValidationLevel,
+
+    /// Runtime argument validation strategy.
+    ///
+    /// Determines whether translations resolved from dynamic
+    /// paths fail when the arguments don't match their placeholders.
+    runtime_validation: RuntimeValidation,
+
+    /// Placeholder consistency validation severity.
+    ///
+    /// Determines whether translation objects whose languages
+    /// use different placeholders are reported as errors or
+    /// warnings while loading the translations.
+    placeholder_consistency: ValidationLevel,
+
+    /// Gettext key scheme.
+    ///
+    /// Determines whether the `msgctxt` or the `msgid` of
+    /// gettext messages holds the translation path.
+    gettext_keys: GettextKeys,
+
+    /// Translation file layout.
+    ///
+    /// Determines whether structured translation files hold
+    /// every language or the one named by their path.
+    ///
+    /// # Example
+    /// ```toml
+    /// layout = "PerLanguage"
+    /// ```
+    layout: FileLayout,
+
+    /// Translation path namespace.
+    ///
+    /// Determines whether the translation paths are
+    /// prefixed with the path of the file that
+    /// contains them.
+    ///
+    /// # Example
+    /// ```toml
+    /// namespace = "FilePath"
+    /// ```
+    namespace: PathNamespace,
+
+    /// Shadowed translation report.
+    ///
+    /// Determines whether the languages of a translation
+    /// that more than one file defines are reported as errors,
+    /// warnings or in a report file.
+    overlap_report: OverlapReport,
+
+    /// Translation file inclusion patterns.
+    ///
+    /// Only the files whose path relative to the translations
+    /// directory matches any of these patterns are loaded. All
+    /// files are loaded if there are none.
+    ///
+    /// # Example
+    /// ```toml
+    /// include = ["**/*.toml", "**/*.ftl"]
+    /// ```
+    include: Vec<GlobPattern>,
+
+    /// Translation file exclusion patterns.
+    ///
+    /// The files and directories whose path relative to the
+    /// translations directory matches any of these patterns
+    /// are skipped, even if they match an inclusion pattern.
+    ///
+    /// # Example
+    /// ```toml
+    /// exclude = ["*.md", "drafts"]
+    /// ```
+    exclude: Vec<GlobPattern>,
+
+    /// Hidden file handling.
+    ///
+    /// Determines whether the files and directories whose
+    /// name starts with a dot are loaded.
+    hidden_files: HiddenFiles,
+
+    /// Symbolic link handling.
+    ///
+    /// Determines whether symbolic links in the translations
+    /// directory are followed, skipped or rejected.
+    symlinks: SymlinkPolicy,
+
+    /// Configuration file paths.
+    ///
+    /// The canonical paths of the configuration files
+    /// the values were read from, which are tracked as
+    /// build dependencies along the translation files.
+    files: Vec<String>,
 }
 
 impl MacroConfig {
-    /// Get reference to the configured locales path.
+    /// Get the configured translation sources.
     ///
     /// **Returns**
-    /// The path to the directory where translation files are expected
-    /// to be located.
-    pub fn path(&self) -> &str {
-        &self.path
+    /// The sources translation files are loaded from,
+    /// sorted from the highest to the lowest priority.
+    pub fn sources(&self) -> &[TranslationSource] {
+        &self.sources
     }
 
     /// Get the current seek mode strategy.
@@ -176,63 +518,480 @@ impl MacroConfig {
     pub fn overlap(&self) -> TranslationOverlap {
         self.overlap
     }
+
+    /// Get the configured language fallback chain.
+    ///
+    /// **Returns**
+    /// The languages to try in order when the requested one is not
+    /// available, empty if there is no fallback.
+    pub fn fallback(&self) -> &[Locale] {
+        &self.fallback
+    }
+
+    /// Get the argument validation severity.
+    ///
+    /// **Returns**
+    /// The configured severity for argument mismatches
+    /// found in compile time.
+    pub fn argument_validation(&self) -> ValidationLevel {
+        self.argument_validation
+    }
+
+    /// Get the runtime argument validation strategy.
+    ///
+    /// **Returns**
+    /// The configured strategy for argument mismatches
+    /// found in runtime.
+    pub fn runtime_validation(&self) -> RuntimeValidation {
+        self.runtime_validation
+    }
+
+    /// Get the placeholder consistency validation severity.
+    ///
+    /// **Returns**
+    /// The configured severity for translation objects whose
+    /// languages disagree on their placeholders.
+    pub fn placeholder_consistency(&self) -> ValidationLevel {
+        self.placeholder_consistency
+    }
+
+    /// Get the gettext key scheme.
+    ///
+    /// **Returns**
+    /// The configured field of gettext messages that
+    /// holds the translation path.
+    pub fn gettext_keys(&self) -> GettextKeys {
+        self.gettext_keys
+    }
+
+    /// Get the translation file layout.
+    ///
+    /// **Returns**
+    /// The configured layout of the TOML, JSON
+    /// and YAML translation files.
+    pub fn layout(&self) -> FileLayout {
+        self.layout
+    }
+
+    /// Get the translation path namespace.
+    ///
+    /// **Returns**
+    /// The configured namespace of the translation
+    /// paths in each file.
+    pub fn namespace(&self) -> PathNamespace {
+        self.namespace
+    }
+
+    /// Get the shadowed translation report.
+    ///
+    /// **Returns**
+    /// The configured report for the translations
+    /// dropped by the overlap strategy.
+    pub fn overlap_report(&self) -> OverlapReport {
+        self.overlap_report
+    }
+
+    /// Get the translation file inclusion patterns.
+    ///
+    /// **Returns**
+    /// The patterns a translation file must match to
+    /// be loaded, empty if every file is loaded.
+    pub fn include(&self) -> &[GlobPattern] {
+        &self.include
+    }
+
+    /// Get the translation file exclusion patterns.
+    ///
+    /// **Returns**
+    /// The patterns of the files and directories that
+    /// are skipped, empty if none is.
+    pub fn exclude(&self) -> &[GlobPattern] {
+        &self.exclude
+    }
+
+    /// Get the hidden file handling.
+    ///
+    /// **Returns**
+    /// The configured handling of the files and
+    /// directories whose name starts with a dot.
+    pub fn hidden_files(&self) -> HiddenFiles {
+        self.hidden_files
+    }
+
+    /// Get the symbolic link handling.
+    ///
+    /// **Returns**
+    /// The configured handling of the symbolic
+    /// links in the translations directory.
+    pub fn symlinks(&self) -> SymlinkPolicy {
+        self.symlinks
+    }
+
+    /// Get the configuration file paths.
+    ///
+    /// **Returns**
+    /// The canonical paths of the configuration files
+    /// that were read, empty if there are none.
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
 }
 
+/// Cached configuration.
+///
+/// A loaded configuration along the stamps of the
+/// configuration files it may be read from.
+type CachedConfig = (Arc<MacroConfig>, FileStamps);
+
 /// Global configuration cache.
 ///
-/// Stores the initialized [`MacroConfig`] instance, which holds the
-/// configuration for the translation system. The [`OnceLock`] ensures the
-/// configuration is initialized only once and can be safely accessed across
-/// multiple threads after that initialization.
-static TRANSLATABLE_CONFIG: OnceLock<MacroConfig> = OnceLock::new();
+/// Stores the loaded [`MacroConfig`] of each crate keyed by
+/// its directory, as a long-lived proc-macro server such as
+/// rust-analyzer expands the macros of every workspace member
+/// in the same process. Each configuration is read again
+/// once any of its files is edited, created or removed.
+static TRANSLATABLE_CONFIG: LazyLock<Mutex<HashMap<PathBuf, CachedConfig>>> =
+    LazyLock::new(Mutex::default);
+
+/// Configuration file name.
+///
+/// The name of the configuration file looked up in the
+/// crate directory and in the workspace root directory.
+const CONFIG_FILE: &str = "translatable.toml";
+
+/// Workspace root lookup.
+///
+/// The workspace root is the closest directory to the
+/// crate, including the crate directory itself, whose
+/// `Cargo.toml` declares a `[workspace]` table.
+///
+/// **Arguments**
+/// * `crate_dir` - The directory of the crate invoking the macros.
+///
+/// **Returns**
+/// The workspace root directory, if the crate belongs to a workspace.
+pub fn workspace_root(crate_dir: &Path) -> Option<PathBuf> {
+    crate_dir
+        .ancestors()
+        .find(|directory| {
+            read_to_string(directory.join("Cargo.toml"))
+                .ok()
+                .and_then(|manifest| {
+                    manifest
+                        .parse::<DocumentMut>()
+                        .ok()
+                })
+                .is_some_and(|manifest| manifest.contains_key("workspace"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Configured path resolution.
+///
+/// **Arguments**
+/// * `directory` - The directory relative paths are resolved against.
+/// * `path` - The configured path.
+///
+/// **Returns**
+/// The path joined to the directory, unless it's absolute.
+fn resolve_path(directory: &Path, path: &str) -> String {
+    let path = Path::new(path);
+
+    directory
+        .join(
+            path.strip_prefix(".")
+                .unwrap_or(path),
+        )
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Translation sources parsing.
+///
+/// Parses the `sources` array, which may be an array
+/// of tables or an array of inline tables, and sorts
+/// the sources by priority. Sources with the same
+/// priority keep their declaration order.
+///
+/// **Arguments**
+/// * `item` - The `sources` configuration value.
+/// * `directory` - The directory of the configuration file declaring it.
+///
+/// **Returns**
+/// The parsed sources from the highest to the lowest priority,
+/// or an [`InvalidSource`] error.
+///
+/// [`InvalidSource`]: ConfigError::InvalidSource
+fn parse_sources(item: &Item, directory: &Path) -> Result<Vec<TranslationSource>, ConfigError> {
+    let tables = match item {
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .map(|table| {
+                Some(
+                    table
+                        .clone()
+                        .into_inline_table(),
+                )
+            })
+            .collect::<Vec<_>>(),
+        Item::Value(Value::Array(array)) => array
+            .iter()
+            .map(|value| {
+                value
+                    .as_inline_table()
+                    .cloned()
+            })
+            .collect(),
+        _ => return Err(ConfigError::InvalidValue("sources".into(), item.to_string())),
+    };
+
+    let mut sources = Vec::new();
+    for (index, table) in tables
+        .into_iter()
+        .enumerate()
+    {
+        let invalid = |reason: String| ConfigError::InvalidSource(index + 1, reason);
+        let table = table.ok_or_else(|| invalid("expected a table".into()))?;
+
+        let mut path = None;
+        let mut priority = 0;
+        let mut format = None;
+        let mut prefix = Vec::new();
+
+        for (key, value) in &table {
+            match key {
+                "path" => {
+                    path = Some(
+                        value
+                            .as_str()
+                            .ok_or_else(|| invalid("'path' must be a string".into()))?,
+                    )
+                },
+
+                "priority" => {
+                    priority = value
+                        .as_integer()
+                        .ok_or_else(|| invalid("'priority' must be an integer".into()))?
+                },
+
+                "format" => {
+                    let name = value
+                        .as_str()
+                        .ok_or_else(|| invalid("'format' must be a string".into()))?;
+
+                    format = Some(
+                        format_named(name)
+                            .ok_or_else(|| invalid(format!("unknown format '{name}'")))?,
+                    );
+                },
+
+                "prefix" => {
+                    prefix = value
+                        .as_str()
+                        .ok_or_else(|| invalid("'prefix' must be a string".into()))?
+                        .split("::")
+                        .map(|segment| {
+                            segment
+                                .trim()
+                                .to_string()
+                        })
+                        .collect();
+
+                    if prefix
+                        .iter()
+                        .any(String::is_empty)
+                    {
+                        return Err(invalid("'prefix' has an empty segment".into()));
+                    }
+                },
+
+                key => return Err(invalid(format!("unknown key '{key}'"))),
+            }
+        }
+
+        let path = path.ok_or_else(|| invalid("'path' is required".into()))?;
+
+        sources.push((
+            priority,
+            TranslationSource {
+                path: resolve_path(directory, path),
+                format,
+                prefix,
+            },
+        ));
+    }
+
+    sources.sort_by_key(|(priority, _)| Reverse(*priority));
+
+    Ok(sources
+        .into_iter()
+        .map(|(_, source)| source)
+        .collect())
+}
+
+/// Directory of the crate invoking the macros.
+///
+/// **Returns**
+/// The `CARGO_MANIFEST_DIR` directory, or the current
+/// directory if it's not set.
+pub fn crate_dir() -> PathBuf {
+    var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."))
+}
 
-/// Load the global translation configuration.
+/// Load the translation configuration of the invoking crate.
 ///
-/// Initializes and returns a reference to the shared [`MacroConfig`] instance.
+/// Initializes and returns a reference to the [`MacroConfig`] instance
+/// of the crate invoking the macros.
 /// Configuration values are loaded in the following priority order:
-/// environment variables override `translatable.toml`, and missing values fall
-/// back to hardcoded defaults.
+/// environment variables override the `translatable.toml` in the directory
+/// of the crate invoking the macros, which overrides the `translatable.toml`
+/// in the workspace root directory, and missing values fall back to hardcoded
+/// defaults.
+///
+/// The crate directory is taken from `CARGO_MANIFEST_DIR`, or the current
+/// directory if it's not set, read [`crate_dir`].
 ///
-/// The configuration is cached after the first successful load, and reused on
-/// subsequent calls.
+/// The configuration is cached per crate directory after each successful
+/// load, and reused on subsequent calls from the same crate until any of
+/// the configuration files is modified, created or removed.
 ///
 /// **Returns**
 /// A `Result` containing either:
-/// * [`Ok(&MacroConfig)`] — The loaded configuration as a reference to the
-///   cached macro configuration.
+/// * [`Ok(Arc<MacroConfig>)`] — The loaded configuration, shared with the
+///   cache.
 /// * [`Err(ConfigError)`] — An error because environment couldn't be read or
 ///   `translatable.toml` couldn't be read.
 ///
-/// [`Ok(&MacroConfig)`]: MacroConfig
+/// [`Ok(Arc<MacroConfig>)`]: MacroConfig
 /// [`Err(ConfigError)`]: ConfigError
-pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
-    if let Some(config) = TRANSLATABLE_CONFIG.get() {
+pub fn load_config() -> Result<Arc<MacroConfig>, ConfigError> {
+    let crate_dir = crate_dir();
+
+    if let Some(config) = TRANSLATABLE_CONFIG
+        .lock()
+        .ok()
+        .and_then(|configs| {
+            configs
+                .get(&crate_dir)
+                .filter(|(_, stamps)| stamps.is_fresh())
+                .map(|(config, _)| Arc::clone(config))
+        })
+    {
         return Ok(config);
     }
 
-    let toml_content = read_to_string("./translatable.toml")
-        .unwrap_or_default()
-        .parse::<DocumentMut>()?;
+    // stamped before reading, so edits made while reading reload it again.
+    let config_files = config_files(&crate_dir);
+    let stamps = FileStamps::new(
+        config_files
+            .iter()
+            .map(|(file, _)| file),
+    );
 
-    macro_rules! config_value {
-        ($env_var:expr, $key:expr, $default:expr) => {
-            var($env_var)
-                .ok()
-                .or_else(|| {
-                    toml_content
-                        .get($key)
-                        .and_then(|v| v.as_str())
-                        .map(|v| v.to_string())
-                })
-                .unwrap_or_else(|| $default.into())
+    let config = Arc::new(read_config(&crate_dir, config_files)?);
+
+    TRANSLATABLE_CONFIG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(crate_dir, (Arc::clone(&config), stamps));
+
+    Ok(config)
+}
+
+/// Configuration files of a crate.
+///
+/// **Arguments**
+/// * `crate_dir` - The directory of the crate invoking the macros.
+///
+/// **Returns**
+/// The configuration files the crate may be configured with,
+/// by priority, along whether they must exist.
+fn config_files(crate_dir: &Path) -> Vec<(PathBuf, bool)> {
+    let mut config_files = match var("TRANSLATABLE_CONFIG") {
+        Ok(file) => vec![(crate_dir.join(file), true)],
+        Err(_) => vec![(crate_dir.join(CONFIG_FILE), false)],
+    };
+
+    if let Some(workspace_dir) = workspace_root(crate_dir)
+        && workspace_dir != crate_dir
+    {
+        config_files.push((workspace_dir.join(CONFIG_FILE), false));
+    }
+
+    config_files
+}
+
+/// Reads the translation configuration of a crate.
+///
+/// **Arguments**
+/// * `crate_dir` - The directory of the crate invoking the macros.
+/// * `config_files` - The configuration files by priority, along whether they
+///   must exist, read [`config_files`].
+///
+/// **Returns**
+/// The configuration read from the environment and the configuration
+/// files, read [`load_config`].
+fn read_config(
+    crate_dir: &Path,
+    config_files: Vec<(PathBuf, bool)>,
+) -> Result<MacroConfig, ConfigError> {
+    let crate_dir = crate_dir.to_path_buf();
+
+    // configuration files along their directory, by priority.
+    let mut sources = Vec::new();
+    let mut files = Vec::new();
+    for (file, required) in config_files {
+        let content = match read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) if !required => continue,
+            Err(err) => return Err(err.into()),
         };
 
+        let file_path = canonicalize(&file)?;
+        let directory = file_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let file_path = file_path
+            .to_string_lossy()
+            .to_string();
+
+        let content = content
+            .parse::<DocumentMut>()
+            .map_err(|err| {
+                let location = SourceLocation::new(&file_path);
+                let location = match err.span() {
+                    Some(span) => location.at_offset(&content, span.start),
+                    None => location,
+                };
+
+                ConfigError::ParseToml(err, location)
+            })?;
+
+        sources.push((directory, content));
+        files.push(file_path);
+    }
+
+    let lookup = |key: &str| {
+        sources
+            .iter()
+            .find_map(|(directory, content)| {
+                content
+                    .get(key)
+                    .map(|item| (directory.as_path(), item))
+            })
+    };
+
+    macro_rules! config_value {
         (parse($env_var:expr, $key:expr, $default:expr)) => {{
             let value = var($env_var)
                 .ok()
                 .or_else(|| {
-                    toml_content
-                        .get($key)
+                    lookup($key)
+                        .map(|(_, item)| item)
                         .and_then(|v| v.as_str())
                         .map(|v| v.to_string())
                 });
@@ -245,10 +1004,107 @@ pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
                 Ok($default)
             }
         }};
+
+        (list($env_var:expr, $key:expr)) => {{
+            let values = var($env_var)
+                .ok()
+                .map(|v| {
+                    v.split(',')
+                        .map(|v| {
+                            v.trim()
+                                .to_string()
+                        })
+                        .filter(|v| !v.is_empty())
+                        .collect::<Vec<_>>()
+                })
+                .map(Ok)
+                .or_else(|| {
+                    lookup($key)
+                        .map(|(_, item)| item)
+                        .map(|v| {
+                            // entries that aren't strings are rejected, not skipped.
+                            let invalid = |value: &dyn Display| {
+                                ConfigError::InvalidValue(
+                                    $key.into(),
+                                    value
+                                        .to_string()
+                                        .trim()
+                                        .to_string(),
+                                )
+                            };
+
+                            match v.as_array() {
+                                Some(array) => array
+                                    .iter()
+                                    .map(|value| {
+                                        value
+                                            .as_str()
+                                            .map(str::to_string)
+                                            .ok_or_else(|| invalid(value))
+                                    })
+                                    .collect(),
+                                None => v
+                                    .as_str()
+                                    .map(|value| vec![value.to_string()])
+                                    .ok_or_else(|| invalid(v)),
+                            }
+                        })
+                })
+                .unwrap_or_else(|| Ok(Vec::new()));
+
+            values.and_then(|values| {
+                values
+                    .into_iter()
+                    .map(|value| {
+                        value
+                            .parse()
+                            .map_err(|_| ConfigError::InvalidValue($key.into(), value))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+        }};
     }
 
     let config = MacroConfig {
-        path: config_value!("TRANSLATABLE_LOCALES_PATH", "path", "./translations"),
+        sources: match var("TRANSLATABLE_LOCALES_PATH") {
+            Ok(path) => vec![TranslationSource {
+                path: resolve_path(&crate_dir, &path),
+                format: None,
+                prefix: Vec::new(),
+            }],
+
+            // the sources come from the first file declaring either key.
+            Err(_) => match sources
+                .iter()
+                .find_map(|(directory, content)| {
+                    content
+                        .get("sources")
+                        .map(|item| (directory, Some(item), None))
+                        .or_else(|| {
+                            content
+                                .get("path")
+                                .map(|item| (directory, None, Some(item)))
+                        })
+                }) {
+                Some((directory, Some(item), _)) => parse_sources(item, directory)?,
+                Some((directory, _, Some(item))) => vec![TranslationSource {
+                    path: resolve_path(
+                        directory,
+                        item.as_str()
+                            .ok_or_else(|| {
+                                ConfigError::InvalidValue("path".into(), item.to_string())
+                            })?,
+                    ),
+                    format: None,
+                    prefix: Vec::new(),
+                }],
+                _ => vec![TranslationSource {
+                    path: resolve_path(&crate_dir, "./translations"),
+                    format: None,
+                    prefix: Vec::new(),
+                }],
+            },
+        },
         overlap: config_value!(parse(
             "TRANSLATABLE_OVERLAP",
             "overlap",
@@ -259,7 +1115,48 @@ pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
             "seek_mode",
             SeekMode::Alphabetical
         ))?,
+        fallback: config_value!(list("TRANSLATABLE_FALLBACK", "fallback"))?,
+        argument_validation: config_value!(parse(
+            "TRANSLATABLE_ARGUMENT_VALIDATION",
+            "argument_validation",
+            ValidationLevel::Warning
+        ))?,
+        runtime_validation: config_value!(parse(
+            "TRANSLATABLE_RUNTIME_VALIDATION",
+            "runtime_validation",
+            RuntimeValidation::Lenient
+        ))?,
+        placeholder_consistency: config_value!(parse(
+            "TRANSLATABLE_PLACEHOLDER_CONSISTENCY",
+            "placeholder_consistency",
+            ValidationLevel::Warning
+        ))?,
+        gettext_keys: config_value!(parse(
+            "TRANSLATABLE_GETTEXT_KEYS",
+            "gettext_keys",
+            GettextKeys::Context
+        ))?,
+        layout: config_value!(parse("TRANSLATABLE_LAYOUT", "layout", FileLayout::Combined))?,
+        namespace: config_value!(parse(
+            "TRANSLATABLE_NAMESPACE",
+            "namespace",
+            PathNamespace::Global
+        ))?,
+        overlap_report: config_value!(parse(
+            "TRANSLATABLE_OVERLAP_REPORT",
+            "overlap_report",
+            OverlapReport::Off
+        ))?,
+        include: config_value!(list("TRANSLATABLE_INCLUDE", "include"))?,
+        exclude: config_value!(list("TRANSLATABLE_EXCLUDE", "exclude"))?,
+        hidden_files: config_value!(parse(
+            "TRANSLATABLE_HIDDEN_FILES",
+            "hidden_files",
+            HiddenFiles::Ignore
+        ))?,
+        symlinks: config_value!(parse("TRANSLATABLE_SYMLINKS", "symlinks", SymlinkPolicy::Follow))?,
+        files,
     };
 
-    Ok(TRANSLATABLE_CONFIG.get_or_init(|| config))
+    Ok(config)
 }
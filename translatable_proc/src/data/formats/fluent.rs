@@ -0,0 +1,1174 @@
+//! Fluent translation file parsing module.
+//!
+//! This module parses [Project Fluent] resources
+//! into a [`TranslationNode`], so `.ftl` files can
+//! sit alongside the other translation files.
+//!
+//! A Fluent resource contains a single language, which
+//! is inferred from the file path by the loader. Message
+//! ids map to translation paths, message attributes map
+//! to a nested path and terms are inlined where they are
+//! referenced.
+//!
+//! Variables map to placeholders and select expressions
+//! map to select arguments, unless they select on the
+//! plural `count` variable with plural category keys, in
+//! which case the message becomes a plural translation.
+//!
+//! [Project Fluent]: https://projectfluent.org/
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use syn::{Ident, parse_str};
+use thiserror::Error;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::misc::plural::{PluralCategory, PluralRule, PluralTranslation};
+use translatable_shared::misc::templating::{FormatString, TemplateNode};
+use translatable_shared::translations::node::{Translation, TranslationNode, TranslationObject};
+
+use super::TranslationFormat;
+use crate::data::config::MacroConfig;
+use crate::data::location::SourceLocation;
+use crate::data::translations::TranslationDataError;
+
+/// Fluent parsing errors.
+///
+/// Every error carries the byte index in the
+/// resource where it was found, read
+/// [`FluentError::position`].
+#[derive(Error, Debug)]
+pub enum FluentError {
+    /// Unexpected character error.
+    ///
+    /// **Parameters**
+    /// * `0` - What was expected.
+    /// * `1` - The byte index where it was expected.
+    #[error("Expected {0}")]
+    Expected(&'static str, usize),
+
+    /// Invalid variable error.
+    ///
+    /// Variables map to macro arguments, so
+    /// they must be valid identifiers, this is
+    /// not the case for Fluent identifiers with
+    /// dashes.
+    ///
+    /// **Parameters**
+    /// * `0` - The variable name.
+    /// * `1` - The byte index of the variable.
+    #[error("The variable '${0}' is not a valid identifier")]
+    InvalidVariable(String, usize),
+
+    /// Unsupported function error.
+    ///
+    /// Only `NUMBER` is supported, and its
+    /// options are ignored.
+    ///
+    /// **Parameters**
+    /// * `0` - The function name.
+    /// * `1` - The byte index of the function call.
+    #[error("The function '{0}' is not supported, only 'NUMBER' is")]
+    UnsupportedFunction(String, usize),
+
+    /// Unsupported selector error.
+    ///
+    /// Select expressions map to select arguments,
+    /// which can only select on a variable.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the select expression.
+    #[error("Only variables can be used as selectors")]
+    UnsupportedSelector(usize),
+
+    /// Missing default variant error.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the select expression.
+    #[error("The select expression must have a '*' default variant")]
+    MissingDefault(usize),
+
+    /// Ambiguous default variant error.
+    ///
+    /// The default variant is mapped to the `other`
+    /// branch, thus there can't be another `other`
+    /// variant.
+    ///
+    /// **Parameters**
+    /// * `0` - The default variant key.
+    /// * `1` - The byte index of the select expression.
+    #[error("The select expression has both a '*[{0}]' default and an '[other]' variant")]
+    AmbiguousDefault(String, usize),
+
+    /// Unknown reference error.
+    ///
+    /// **Parameters**
+    /// * `0` - The referenced message or term id.
+    /// * `1` - The byte index of the reference.
+    #[error("The referenced entry '{0}' could not be found")]
+    UnknownReference(String, usize),
+
+    /// Cyclic reference error.
+    ///
+    /// **Parameters**
+    /// * `0` - The referenced message or term id.
+    /// * `1` - The byte index of the reference.
+    #[error("The reference to '{0}' is cyclic")]
+    CyclicReference(String, usize),
+
+    /// Duplicated entry error.
+    ///
+    /// **Parameters**
+    /// * `0` - The message or term id.
+    /// * `1` - The byte index of the entry.
+    #[error("The entry '{0}' is defined more than once")]
+    DuplicatedEntry(String, usize),
+
+    /// Mixed values error.
+    ///
+    /// A message maps to a translation object if it
+    /// has a value and to a nesting if it has attributes,
+    /// thus it can't have both.
+    ///
+    /// **Parameters**
+    /// * `0` - The message id.
+    /// * `1` - The byte index of the message.
+    #[error("The message '{0}' can't have both a value and attributes")]
+    MixedValues(String, usize),
+}
+
+impl FluentError {
+    /// Error position getter.
+    ///
+    /// **Returns**
+    /// The byte index in the resource where the error was found.
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Expected(_, position)
+            | Self::InvalidVariable(_, position)
+            | Self::UnsupportedFunction(_, position)
+            | Self::UnsupportedSelector(position)
+            | Self::MissingDefault(position)
+            | Self::AmbiguousDefault(_, position)
+            | Self::UnknownReference(_, position)
+            | Self::CyclicReference(_, position)
+            | Self::DuplicatedEntry(_, position)
+            | Self::MixedValues(_, position) => *position,
+        }
+    }
+}
+
+/// Fluent pattern element.
+enum Element {
+    /// Literal text.
+    Text(String),
+
+    /// A placeable expression along with its byte span.
+    Placeable(Expression, Range<usize>),
+}
+
+/// Fluent inline or select expression.
+enum Expression {
+    /// A string or number literal.
+    Literal(String),
+
+    /// A `$variable` reference.
+    Variable(String, usize),
+
+    /// A message or term reference with an optional attribute.
+    Reference(String, Option<String>, usize),
+
+    /// A select expression on a variable.
+    Select(String, Vec<Variant>, usize),
+}
+
+/// Select expression variant.
+struct Variant {
+    /// The variant key.
+    key: String,
+
+    /// Whether this is the `*` default variant.
+    default: bool,
+
+    /// The variant pattern.
+    pattern: Pattern,
+}
+
+/// Fluent pattern.
+struct Pattern {
+    /// The pattern elements.
+    elements: Vec<Element>,
+
+    /// The byte span of the pattern in the resource.
+    span: Range<usize>,
+}
+
+/// Fluent message or term.
+struct Entry {
+    /// The entry id, terms are prefixed with `-`.
+    id: String,
+
+    /// The byte index of the entry.
+    position: usize,
+
+    /// The entry value.
+    value: Option<Pattern>,
+
+    /// The entry attributes in order of appearance.
+    attributes: Vec<(String, Pattern)>,
+}
+
+/// Fluent resource parser.
+///
+/// A recursive descent parser for the subset of
+/// the Fluent syntax that can be mapped to templates.
+struct FluentParser<'a> {
+    /// The resource source.
+    source: &'a str,
+
+    /// The current byte index.
+    position: usize,
+}
+
+/// Fluent pattern resolver.
+///
+/// Converts the parsed patterns into template nodes,
+/// inlining the referenced messages and terms.
+struct FluentResolver<'a> {
+    /// The resource source.
+    source: &'a str,
+
+    /// Every entry keyed by id.
+    entries: HashMap<&'a str, &'a Entry>,
+
+    /// The references being resolved, to detect cycles.
+    stack: Vec<String>,
+}
+
+/// Fluent resource parsing.
+///
+/// Parses a Fluent resource into a [`TranslationNode`] nesting
+/// whose translation objects contain a single locale.
+///
+/// **Arguments**
+/// * `source` - The resource source.
+/// * `locale` - The locale of the resource.
+///
+/// **Returns**
+/// The parsed translations or the first error found.
+pub fn parse_fluent(source: &str, locale: &Locale) -> Result<TranslationNode, FluentError> {
+    let entries = FluentParser { source, position: 0 }.parse_resource()?;
+
+    let mut resolver = FluentResolver {
+        source,
+        entries: HashMap::new(),
+        stack: Vec::new(),
+    };
+
+    for entry in &entries {
+        if resolver
+            .entries
+            .insert(&entry.id, entry)
+            .is_some()
+        {
+            return Err(FluentError::DuplicatedEntry(
+                entry
+                    .id
+                    .clone(),
+                entry.position,
+            ));
+        }
+    }
+
+    let mut nesting = HashMap::new();
+    for entry in entries
+        .iter()
+        .filter(|entry| {
+            !entry
+                .id
+                .starts_with('-')
+        })
+    {
+        let node = match (
+            &entry.value,
+            entry
+                .attributes
+                .is_empty(),
+        ) {
+            (Some(_), false) => {
+                return Err(FluentError::MixedValues(
+                    entry
+                        .id
+                        .clone(),
+                    entry.position,
+                ));
+            },
+
+            (Some(value), true) => resolver.translation_node(value, locale)?,
+
+            (None, _) => TranslationNode::Nesting(
+                entry
+                    .attributes
+                    .iter()
+                    .map(|(attribute, pattern)| {
+                        Ok((attribute.clone(), resolver.translation_node(pattern, locale)?))
+                    })
+                    .collect::<Result<_, FluentError>>()?,
+            ),
+        };
+
+        nesting.insert(
+            entry
+                .id
+                .clone(),
+            node,
+        );
+    }
+
+    Ok(TranslationNode::Nesting(nesting))
+}
+
+/// Fluent translation format.
+///
+/// Handles `.ftl` resources, which contain a single
+/// language inferred from the file path.
+pub struct FluentFormat;
+
+impl TranslationFormat for FluentFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["ftl"]
+    }
+
+    fn single_language(&self) -> bool {
+        true
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        _config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let locale =
+            locale.ok_or_else(|| TranslationDataError::UnknownLanguage(path.to_string()))?;
+
+        parse_fluent(source, locale).map_err(|err| {
+            let location = SourceLocation::new(path).at_offset(source, err.position());
+            TranslationDataError::ParseFluent(err, location)
+        })
+    }
+}
+
+impl<'a> FluentParser<'a> {
+    /// Peeks the current character.
+    ///
+    /// **Returns**
+    /// The character at the current position if any.
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..]
+            .chars()
+            .next()
+    }
+
+    /// Advances past the current character.
+    fn bump(&mut self) {
+        if let Some(character) = self.peek() {
+            self.position += character.len_utf8();
+        }
+    }
+
+    /// Consumes a string if it's at the current position.
+    ///
+    /// **Arguments**
+    /// * `expected` - The string to consume.
+    ///
+    /// **Returns**
+    /// Whether the string was consumed.
+    fn eat(&mut self, expected: &str) -> bool {
+        if self.source[self.position..].starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes a string or fails.
+    ///
+    /// **Arguments**
+    /// * `expected` - The string to consume.
+    /// * `description` - What was expected, for the error.
+    ///
+    /// **Returns**
+    /// An error if the string is not at the current position.
+    fn expect(&mut self, expected: &str, description: &'static str) -> Result<(), FluentError> {
+        match self.eat(expected) {
+            true => Ok(()),
+            false => Err(FluentError::Expected(description, self.position)),
+        }
+    }
+
+    /// Consumes a line ending.
+    ///
+    /// **Returns**
+    /// Whether a line ending was consumed.
+    fn eat_newline(&mut self) -> bool {
+        self.eat("\n") || self.eat("\r\n")
+    }
+
+    /// Skips spaces in the current line.
+    fn skip_inline_blank(&mut self) {
+        while self.peek() == Some(' ') {
+            self.bump();
+        }
+    }
+
+    /// Skips spaces and line endings.
+    fn skip_blank(&mut self) {
+        loop {
+            self.skip_inline_blank();
+
+            if !self.eat_newline() {
+                break;
+            }
+        }
+    }
+
+    /// Skips to the start of the next line.
+    fn skip_line(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.bump();
+        }
+
+        self.eat_newline();
+    }
+
+    /// Parses an identifier.
+    ///
+    /// **Returns**
+    /// An identifier matching `[a-zA-Z][a-zA-Z0-9_-]*`.
+    fn parse_identifier(&mut self) -> Result<String, FluentError> {
+        let start = self.position;
+
+        if !self
+            .peek()
+            .is_some_and(|character| character.is_ascii_alphabetic())
+        {
+            return Err(FluentError::Expected("an identifier", start));
+        }
+
+        while self
+            .peek()
+            .is_some_and(|character| {
+                character.is_ascii_alphanumeric() || matches!(character, '_' | '-')
+            })
+        {
+            self.bump();
+        }
+
+        Ok(self.source[start..self.position].to_string())
+    }
+
+    /// Parses every entry in the resource.
+    ///
+    /// Comments and blank lines are skipped.
+    ///
+    /// **Returns**
+    /// The messages and terms in order of appearance.
+    fn parse_resource(mut self) -> Result<Vec<Entry>, FluentError> {
+        let mut entries = Vec::new();
+
+        loop {
+            let line_start = self.position;
+            self.skip_inline_blank();
+
+            if self.eat_newline() {
+                continue;
+            }
+
+            match self.peek() {
+                None => break,
+                Some(_) if self.position != line_start => {
+                    return Err(FluentError::Expected(
+                        "an entry at the start of the line",
+                        self.position,
+                    ));
+                },
+                Some('#') => self.skip_line(),
+                Some(_) => entries.push(self.parse_entry()?),
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Parses a message or term.
+    ///
+    /// **Returns**
+    /// The parsed entry, the parser is left at the start of the next line.
+    fn parse_entry(&mut self) -> Result<Entry, FluentError> {
+        let position = self.position;
+        let term = self.eat("-");
+
+        let id = match term {
+            true => format!("-{}", self.parse_identifier()?),
+            false => self.parse_identifier()?,
+        };
+
+        self.skip_inline_blank();
+        self.expect("=", "'=' after the entry id")?;
+
+        let value = self.parse_pattern(false)?;
+
+        let mut attributes = Vec::new();
+        loop {
+            let checkpoint = self.position;
+            self.skip_blank();
+
+            if self.position == checkpoint || !self.eat(".") {
+                self.position = checkpoint;
+                break;
+            }
+
+            let attribute = self.parse_identifier()?;
+            self.skip_inline_blank();
+            self.expect("=", "'=' after the attribute name")?;
+
+            let pattern = self
+                .parse_pattern(false)?
+                .ok_or(FluentError::Expected("the attribute value", self.position))?;
+
+            attributes.push((attribute, pattern));
+        }
+
+        if value.is_none() && attributes.is_empty() {
+            return Err(FluentError::Expected("a value or attributes", self.position));
+        }
+
+        if !matches!(self.peek(), None | Some('\n' | '\r')) {
+            return Err(FluentError::Expected("the end of the line", self.position));
+        }
+        self.eat_newline();
+
+        Ok(Entry { id, position, value, attributes })
+    }
+
+    /// Parses a pattern.
+    ///
+    /// The pattern continues in the following lines if they
+    /// are indented and don't start with `.`, `[`, `*` or `}`.
+    /// The indentation of those lines is removed.
+    ///
+    /// **Arguments**
+    /// * `nested` - Whether the pattern is a variant, ended by `}`.
+    ///
+    /// **Returns**
+    /// The pattern or `None` if it's empty.
+    fn parse_pattern(&mut self, nested: bool) -> Result<Option<Pattern>, FluentError> {
+        self.skip_inline_blank();
+
+        let start = self.position;
+        let mut end = start;
+        let mut elements = Vec::new();
+        let mut text = String::new();
+
+        loop {
+            match self.peek() {
+                None => break,
+
+                Some('{') => {
+                    if !text.is_empty() {
+                        elements.push(Element::Text(std::mem::take(&mut text)));
+                    }
+
+                    let open = self.position;
+                    let expression = self.parse_placeable()?;
+                    elements.push(Element::Placeable(expression, open..self.position));
+                    end = self.position;
+                },
+
+                Some('}') if nested => break,
+
+                Some('}') => {
+                    return Err(FluentError::Expected(
+                        "text or a placeable, use {\"}\"} for a literal brace",
+                        self.position,
+                    ));
+                },
+
+                Some('\n' | '\r') => {
+                    let checkpoint = self.position;
+                    let mut newlines = 0;
+
+                    let indented = loop {
+                        if !self.eat_newline() {
+                            break false;
+                        }
+
+                        newlines += 1;
+                        let line_start = self.position;
+                        self.skip_inline_blank();
+
+                        if !matches!(self.peek(), Some('\n' | '\r')) {
+                            break self.position > line_start;
+                        }
+                    };
+
+                    match self.peek() {
+                        Some(character)
+                            if indented && !matches!(character, '.' | '[' | '*' | '}') =>
+                        {
+                            if !elements.is_empty() || !text.is_empty() {
+                                text.push_str(&"\n".repeat(newlines));
+                            }
+                        },
+
+                        _ => {
+                            self.position = checkpoint;
+                            break;
+                        },
+                    }
+                },
+
+                Some(character) => {
+                    text.push(character);
+                    self.bump();
+
+                    if character != ' ' {
+                        end = self.position;
+                    }
+                },
+            }
+        }
+
+        let text = text.trim_end_matches(' ');
+        if !text.is_empty() {
+            elements.push(Element::Text(text.to_string()));
+        }
+
+        Ok((!elements.is_empty()).then_some(Pattern { elements, span: start..end }))
+    }
+
+    /// Parses a placeable.
+    ///
+    /// **Returns**
+    /// The inline or select expression inside the braces.
+    fn parse_placeable(&mut self) -> Result<Expression, FluentError> {
+        let open = self.position;
+        self.expect("{", "'{'")?;
+        self.skip_blank();
+
+        let expression = self.parse_inline_expression()?;
+        self.skip_blank();
+
+        if !self.eat("->") {
+            self.expect("}", "'}' closing the placeable")?;
+            return Ok(expression);
+        }
+
+        let Expression::Variable(selector, _) = expression else {
+            return Err(FluentError::UnsupportedSelector(open));
+        };
+
+        let mut variants = Vec::new();
+        loop {
+            self.skip_blank();
+
+            if self.eat("}") {
+                break;
+            }
+
+            let default = self.eat("*");
+            self.expect("[", "a variant key")?;
+            self.skip_blank();
+
+            let key_start = self.position;
+            while self
+                .peek()
+                .is_some_and(|character| {
+                    character.is_ascii_alphanumeric() || matches!(character, '_' | '-' | '.')
+                })
+            {
+                self.bump();
+            }
+
+            if self.position == key_start {
+                return Err(FluentError::Expected("a variant key", key_start));
+            }
+
+            let key = self.source[key_start..self.position].to_string();
+            self.skip_blank();
+            self.expect("]", "']' closing the variant key")?;
+
+            let pattern = self
+                .parse_pattern(true)?
+                .unwrap_or(Pattern {
+                    elements: Vec::new(),
+                    span: self.position..self.position,
+                });
+
+            variants.push(Variant { key, default, pattern });
+        }
+
+        if !variants
+            .iter()
+            .any(|variant| variant.default)
+        {
+            return Err(FluentError::MissingDefault(open));
+        }
+
+        Ok(Expression::Select(selector, variants, open))
+    }
+
+    /// Parses an inline expression.
+    ///
+    /// **Returns**
+    /// A literal, a variable, a reference or a nested placeable.
+    fn parse_inline_expression(&mut self) -> Result<Expression, FluentError> {
+        let position = self.position;
+
+        match self.peek() {
+            Some('$') => {
+                self.bump();
+                Ok(Expression::Variable(self.parse_identifier()?, position))
+            },
+
+            Some('"') => self.parse_string(),
+
+            Some('{') => self.parse_placeable(),
+
+            Some('-')
+                if self.source[self.position + 1..]
+                    .starts_with(|character: char| character.is_ascii_alphabetic()) =>
+            {
+                self.bump();
+                let id = format!("-{}", self.parse_identifier()?);
+                let attribute = self.parse_attribute_accessor()?;
+
+                Ok(Expression::Reference(id, attribute, position))
+            },
+
+            Some(character) if character == '-' || character.is_ascii_digit() => {
+                self.bump();
+                while self
+                    .peek()
+                    .is_some_and(|character| character.is_ascii_digit() || character == '.')
+                {
+                    self.bump();
+                }
+
+                Ok(Expression::Literal(self.source[position..self.position].to_string()))
+            },
+
+            Some(character) if character.is_ascii_alphabetic() => {
+                let id = self.parse_identifier()?;
+
+                if self.peek() != Some('(') {
+                    let attribute = self.parse_attribute_accessor()?;
+                    return Ok(Expression::Reference(id, attribute, position));
+                }
+
+                if id != "NUMBER" {
+                    return Err(FluentError::UnsupportedFunction(id, position));
+                }
+
+                self.bump();
+                self.skip_blank();
+
+                let argument = self.parse_inline_expression()?;
+                while !matches!(self.peek(), None | Some(')')) {
+                    match self.peek() {
+                        Some('"') => {
+                            self.parse_string()?;
+                        },
+                        _ => self.bump(),
+                    }
+                }
+                self.expect(")", "')' closing the function call")?;
+
+                Ok(argument)
+            },
+
+            _ => Err(FluentError::Expected("an expression", position)),
+        }
+    }
+
+    /// Parses an optional `.attribute` accessor.
+    ///
+    /// **Returns**
+    /// The attribute name if there is an accessor.
+    fn parse_attribute_accessor(&mut self) -> Result<Option<String>, FluentError> {
+        match self.eat(".") {
+            true => Ok(Some(self.parse_identifier()?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Parses a string literal.
+    ///
+    /// Supports the `\"`, `\\`, `\uXXXX` and `\UXXXXXX` escapes.
+    ///
+    /// **Returns**
+    /// The unescaped string as a literal.
+    fn parse_string(&mut self) -> Result<Expression, FluentError> {
+        self.expect("\"", "'\"'")?;
+        let mut value = String::new();
+
+        loop {
+            match self.peek() {
+                None | Some('\n' | '\r') => {
+                    return Err(FluentError::Expected("'\"' closing the string", self.position));
+                },
+
+                Some('"') => {
+                    self.bump();
+                    break;
+                },
+
+                Some('\\') => {
+                    let escape = self.position;
+                    self.bump();
+
+                    let digits = match self.peek() {
+                        Some('u') => 4,
+                        Some('U') => 6,
+                        Some(character @ ('"' | '\\')) => {
+                            value.push(character);
+                            self.bump();
+                            continue;
+                        },
+                        _ => return Err(FluentError::Expected("a valid escape sequence", escape)),
+                    };
+
+                    self.bump();
+                    let character = self
+                        .source
+                        .get(self.position..self.position + digits)
+                        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                        .and_then(char::from_u32)
+                        .ok_or(FluentError::Expected("a valid escape sequence", escape))?;
+
+                    value.push(character);
+                    self.position += digits;
+                },
+
+                Some(character) => {
+                    value.push(character);
+                    self.bump();
+                },
+            }
+        }
+
+        Ok(Expression::Literal(value))
+    }
+}
+
+impl FluentResolver<'_> {
+    /// Translation node conversion.
+    ///
+    /// A pattern whose only top-level select expression is on
+    /// the plural `count` variable with plural category keys
+    /// becomes a plural translation, the text around the select
+    /// expression is repeated in every variant. Any other pattern
+    /// becomes a singular translation.
+    ///
+    /// **Arguments**
+    /// * `pattern` - The message value or attribute.
+    /// * `locale` - The locale of the resource.
+    ///
+    /// **Returns**
+    /// A translation object with a single locale.
+    fn translation_node(
+        &mut self,
+        pattern: &Pattern,
+        locale: &Locale,
+    ) -> Result<TranslationNode, FluentError> {
+        let original = &self.source[pattern
+            .span
+            .clone()];
+
+        let plural_selects = pattern
+            .elements
+            .iter()
+            .enumerate()
+            .filter_map(|(index, element)| match element {
+                Element::Placeable(Expression::Select(selector, variants, _), _)
+                    if selector == PluralTranslation::SELECTOR
+                        && variants
+                            .iter()
+                            .all(|variant| {
+                                variant.default
+                                    || variant
+                                        .key
+                                        .parse::<PluralCategory>()
+                                        .is_ok()
+                            }) =>
+                {
+                    Some((index, variants))
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let translation = match plural_selects.as_slice() {
+            [(index, variants)] => {
+                let (prefix, suffix) = pattern
+                    .elements
+                    .split_at(*index);
+
+                let prefix = self.resolve(
+                    prefix,
+                    pattern
+                        .span
+                        .start,
+                    None,
+                )?;
+                let suffix = self.resolve(
+                    &suffix[1..],
+                    pattern
+                        .span
+                        .start,
+                    None,
+                )?;
+
+                let mut variants = self
+                    .branches(
+                        variants,
+                        pattern
+                            .span
+                            .start,
+                    )?
+                    .into_iter()
+                    .filter_map(|(key, nodes)| {
+                        let category = key
+                            .parse::<PluralCategory>()
+                            .ok()?;
+
+                        let nodes = prefix
+                            .iter()
+                            .cloned()
+                            .chain(nodes)
+                            .chain(
+                                suffix
+                                    .iter()
+                                    .cloned(),
+                            )
+                            .collect();
+
+                        Some((category, FormatString::from_data(original, nodes)))
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                // the default variant is always mapped to the `other` branch.
+                let Some(other) = variants.remove(&PluralCategory::Other) else {
+                    return Err(FluentError::MissingDefault(
+                        pattern
+                            .span
+                            .start,
+                    ));
+                };
+
+                Translation::Plural(PluralTranslation::new(
+                    PluralRule::from(locale),
+                    other,
+                    variants,
+                ))
+            },
+
+            _ => Translation::Singular(FormatString::from_data(
+                original,
+                self.resolve(
+                    &pattern.elements,
+                    pattern
+                        .span
+                        .start,
+                    None,
+                )?,
+            )),
+        };
+
+        Ok(TranslationNode::Translation(TranslationObject::new(HashMap::from([(
+            locale.clone(),
+            translation,
+        )]))))
+    }
+
+    /// Pattern elements conversion.
+    ///
+    /// **Arguments**
+    /// * `elements` - The elements to convert.
+    /// * `base` - The byte index the placeholder spans are relative to.
+    /// * `inlined` - The span of the reference if the elements belong to a
+    ///   referenced entry, used as the span of every placeholder.
+    ///
+    /// **Returns**
+    /// The template nodes with adjacent text merged.
+    fn resolve(
+        &mut self,
+        elements: &[Element],
+        base: usize,
+        inlined: Option<&Range<usize>>,
+    ) -> Result<Vec<TemplateNode>, FluentError> {
+        let mut nodes = Vec::new();
+
+        for element in elements {
+            let (expression, span) = match element {
+                Element::Text(text) => {
+                    push_text(&mut nodes, text);
+                    continue;
+                },
+                Element::Placeable(expression, span) => (expression, span),
+            };
+
+            let relative = inlined
+                .cloned()
+                .unwrap_or(span.start - base..span.end - base);
+
+            match expression {
+                Expression::Literal(literal) => push_text(&mut nodes, literal),
+
+                Expression::Variable(variable, position) => {
+                    nodes.push(TemplateNode::Placeholder(
+                        validate_variable(variable, *position)?,
+                        relative,
+                        None,
+                    ));
+                },
+
+                Expression::Select(selector, variants, position) => {
+                    nodes.push(TemplateNode::Select(
+                        validate_variable(selector, *position)?,
+                        self.branches(variants, base)?,
+                    ));
+                },
+
+                Expression::Reference(id, attribute, position) => {
+                    let entry = *self
+                        .entries
+                        .get(id.as_str())
+                        .ok_or_else(|| FluentError::UnknownReference(id.clone(), *position))?;
+
+                    let pattern = match attribute {
+                        Some(attribute) => entry
+                            .attributes
+                            .iter()
+                            .find(|(name, _)| name == attribute)
+                            .map(|(_, pattern)| pattern),
+                        None => entry
+                            .value
+                            .as_ref(),
+                    }
+                    .ok_or_else(|| FluentError::UnknownReference(id.clone(), *position))?;
+
+                    if self
+                        .stack
+                        .contains(id)
+                    {
+                        return Err(FluentError::CyclicReference(id.clone(), *position));
+                    }
+
+                    self.stack
+                        .push(id.clone());
+                    let referenced = self.resolve(&pattern.elements, base, Some(&relative))?;
+                    self.stack
+                        .pop();
+
+                    for node in referenced {
+                        match node {
+                            TemplateNode::Text(text) => push_text(&mut nodes, &text),
+                            node => nodes.push(node),
+                        }
+                    }
+                },
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Select variants conversion.
+    ///
+    /// The default variant becomes the `other` branch,
+    /// as that's the branch used for unmatched values.
+    ///
+    /// **Arguments**
+    /// * `variants` - The select expression variants.
+    /// * `base` - The byte index the placeholder spans are relative to.
+    ///
+    /// **Returns**
+    /// The branches keyed by the variant key.
+    fn branches(
+        &mut self,
+        variants: &[Variant],
+        base: usize,
+    ) -> Result<Vec<(String, Vec<TemplateNode>)>, FluentError> {
+        let has_other = variants
+            .iter()
+            .any(|variant| !variant.default && variant.key == "other");
+
+        variants
+            .iter()
+            .map(|variant| {
+                let key = match variant.default {
+                    true if variant.key == "other" => variant
+                        .key
+                        .clone(),
+                    true if has_other => {
+                        return Err(FluentError::AmbiguousDefault(
+                            variant
+                                .key
+                                .clone(),
+                            variant
+                                .pattern
+                                .span
+                                .start,
+                        ));
+                    },
+                    true => "other".into(),
+                    false => variant
+                        .key
+                        .clone(),
+                };
+
+                Ok((
+                    key,
+                    self.resolve(
+                        &variant
+                            .pattern
+                            .elements,
+                        base,
+                        None,
+                    )?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Pushes text merging it with the previous text node.
+///
+/// **Arguments**
+/// * `nodes` - The nodes to push the text to.
+/// * `text` - The text to push.
+fn push_text(nodes: &mut Vec<TemplateNode>, text: &str) {
+    match nodes.last_mut() {
+        Some(TemplateNode::Text(previous)) => previous.push_str(text),
+        _ => nodes.push(TemplateNode::Text(text.to_string())),
+    }
+}
+
+/// Variable name validation.
+///
+/// **Arguments**
+/// * `variable` - The Fluent variable name.
+/// * `position` - The byte index of the variable.
+///
+/// **Returns**
+/// The variable name if it's a valid identifier.
+fn validate_variable(variable: &str, position: usize) -> Result<String, FluentError> {
+    match parse_str::<Ident>(variable) {
+        Ok(_) => Ok(variable.to_string()),
+        Err(_) => Err(FluentError::InvalidVariable(variable.to_string(), position)),
+    }
+}
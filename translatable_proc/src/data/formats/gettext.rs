@@ -0,0 +1,946 @@
+//! Gettext translation file format module.
+//!
+//! This module parses gettext `.po` catalogs into a
+//! [`TranslationNode`], and writes the loaded translations
+//! back as `.pot` templates or `.po` catalogs, so they can
+//! round-trip through the standard gettext tooling.
+//!
+//! A catalog contains a single language, which is inferred
+//! from the file path by the loader or taken from the
+//! `Language` header. Each message maps to a translation path
+//! following the `gettext_keys` configuration, either the
+//! `msgctxt` or the `msgid` holds the path in dotted notation,
+//! such as `greetings.formal`.
+//!
+//! Plural messages map to plural translations, the forms
+//! are mapped to CLDR categories by evaluating the catalog
+//! `Plural-Forms` header, read [`PluralForms`].
+//!
+//! Fuzzy, obsolete and untranslated messages are skipped,
+//! as `msgfmt` does. Translator (`#`) and extracted (`#.`)
+//! comments are kept with the translations and written
+//! back on export, so they survive a round-trip through
+//! an editor such as Poedit.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use thiserror::Error;
+use translatable_shared::misc::gettext::{PluralForms, PluralFormsError};
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::misc::plural::{
+    PluralCategory, PluralOperands, PluralRule, PluralTranslation,
+};
+use translatable_shared::misc::templating::{FormatString, TemplateError};
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::{Translation, TranslationNode};
+
+use super::{InsertConflict, TranslationFormat, insert_translation};
+use crate::data::config::{GettextKeys, MacroConfig};
+use crate::data::location::SourceLocation;
+use crate::data::translations::TranslationDataError;
+
+/// Gettext parsing errors.
+///
+/// Every error carries the byte index in the
+/// catalog where it was found, read [`PoError::position`].
+#[derive(Error, Debug)]
+pub enum PoError {
+    /// Unexpected content error.
+    ///
+    /// **Parameters**
+    /// * `0` - What was expected.
+    /// * `1` - The byte index where it was expected.
+    #[error("Expected {0}")]
+    Expected(&'static str, usize),
+
+    /// Unknown keyword error.
+    ///
+    /// **Parameters**
+    /// * `0` - The keyword found.
+    /// * `1` - The byte index of the keyword.
+    #[error("Unknown keyword '{0}'")]
+    UnknownKeyword(String, usize),
+
+    /// Invalid string escape error.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the escape sequence.
+    #[error("Invalid escape sequence")]
+    InvalidEscape(usize),
+
+    /// Missing context error.
+    ///
+    /// With the `Context` key scheme, every
+    /// message needs a `msgctxt` holding its path.
+    ///
+    /// **Parameters**
+    /// * `0` - The message id.
+    /// * `1` - The byte index of the message.
+    #[error("The message '{0}' has no msgctxt to take the translation path from")]
+    MissingContext(String, usize),
+
+    /// Invalid translation path error.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the message.
+    #[error("Invalid translation path '{0}'")]
+    InvalidPath(String, usize),
+
+    /// Duplicated message error.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the second message.
+    #[error("The translation path '{0}' is defined more than once")]
+    DuplicatedEntry(String, usize),
+
+    /// Mixed values error.
+    ///
+    /// A path can't be both a translation
+    /// and the prefix of another path.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the message.
+    #[error("The translation path '{0}' is both a translation and a nesting")]
+    MixedValues(String, usize),
+
+    /// Invalid plural forms error.
+    ///
+    /// **Parameters**
+    /// * `0` - The plural forms error.
+    /// * `1` - The byte index of the header.
+    #[error("Invalid Plural-Forms header, {0:#}")]
+    PluralForms(PluralFormsError, usize),
+
+    /// Unknown plural form error.
+    ///
+    /// Raised when a message has more plural
+    /// forms than the `Plural-Forms` header declares.
+    ///
+    /// **Parameters**
+    /// * `0` - The declared form count.
+    /// * `1` - The byte index of the message.
+    #[error("The message has more plural forms than the {0} declared in the Plural-Forms header")]
+    UnknownPluralForm(usize, usize),
+
+    /// Template error.
+    ///
+    /// **Parameters**
+    /// * `0` - The template error.
+    /// * `1` - The byte index of the message.
+    #[error("{0:#}")]
+    Template(TemplateError, usize),
+}
+
+impl PoError {
+    /// Error position getter.
+    ///
+    /// **Returns**
+    /// The byte index in the catalog where the error was found.
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Expected(_, position)
+            | Self::UnknownKeyword(_, position)
+            | Self::InvalidEscape(position)
+            | Self::MissingContext(_, position)
+            | Self::InvalidPath(_, position)
+            | Self::DuplicatedEntry(_, position)
+            | Self::MixedValues(_, position)
+            | Self::PluralForms(_, position)
+            | Self::UnknownPluralForm(_, position)
+            | Self::Template(_, position) => *position,
+        }
+    }
+}
+
+/// Gettext translation format.
+///
+/// Handles `.po` catalogs and `.pot` templates,
+/// templates have no translations so they are
+/// skipped by the loader.
+pub struct GettextFormat;
+
+/// Catalog message.
+#[derive(Default)]
+struct PoEntry {
+    /// The `msgctxt` field.
+    context: Option<String>,
+
+    /// The `msgid` field.
+    id: Option<String>,
+
+    /// The `msgid_plural` field.
+    plural_id: Option<String>,
+
+    /// The `msgstr` field, or the `msgstr[n]` fields in order.
+    translations: Vec<String>,
+
+    /// Whether the message has the `fuzzy` flag.
+    fuzzy: bool,
+
+    /// The translator and extracted comment lines.
+    comments: Vec<String>,
+
+    /// The byte index where the message starts.
+    position: usize,
+}
+
+/// The field continuation strings are appended to.
+#[derive(Clone, Copy)]
+enum PoField {
+    Context,
+    Id,
+    PluralId,
+    Translation(usize),
+}
+
+impl TranslationFormat for GettextFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["po", "pot"]
+    }
+
+    fn single_language(&self) -> bool {
+        true
+    }
+
+    fn is_template(&self, path: &str) -> bool {
+        Path::new(path)
+            .extension()
+            .is_some_and(|extension| extension == "pot")
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let keys = config.gettext_keys();
+        let located = |err: PoError| {
+            let location = SourceLocation::new(path).at_offset(source, err.position());
+            TranslationDataError::ParseGettext(err, location)
+        };
+
+        let entries = parse_entries(source).map_err(located)?;
+
+        let header = entries
+            .iter()
+            .find(|entry| {
+                entry
+                    .context
+                    .is_none()
+                    && entry
+                        .id
+                        .as_deref()
+                        == Some("")
+            });
+
+        let header_field = |name: &str| {
+            header?
+                .translations
+                .first()?
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| {
+                    value
+                        .trim()
+                        .to_string()
+                })
+        };
+
+        let translated = entries
+            .iter()
+            .filter(|entry| {
+                !entry.fuzzy
+                    && entry
+                        .id
+                        .as_deref()
+                        != Some("")
+                    && entry
+                        .translations
+                        .iter()
+                        .any(|translation| !translation.is_empty())
+            })
+            .collect::<Vec<_>>();
+
+        if translated.is_empty() {
+            return Ok(TranslationNode::Nesting(HashMap::new()));
+        }
+
+        let locale = match locale {
+            Some(locale) => locale.clone(),
+            None => header_field("Language")
+                .and_then(|language| header_locale(&language))
+                .ok_or_else(|| TranslationDataError::UnknownLanguage(path.to_string()))?,
+        };
+
+        let rule = PluralRule::from(&locale);
+        let header_position = header.map_or(0, |header| header.position);
+        let forms = match header_field("Plural-Forms") {
+            Some(forms) => forms
+                .parse::<PluralForms>()
+                .map_err(|err| located(PoError::PluralForms(err, header_position)))?,
+            None => rule
+                .plural_forms()
+                .parse::<PluralForms>()
+                .map_err(|err| located(PoError::PluralForms(err, header_position)))?,
+        };
+
+        let mapping = category_forms(&forms, rule);
+
+        let mut nesting = HashMap::new();
+        for entry in translated {
+            let dotted = match keys {
+                GettextKeys::Context => entry
+                    .context
+                    .clone()
+                    .ok_or_else(|| {
+                        PoError::MissingContext(
+                            entry
+                                .id
+                                .clone()
+                                .unwrap_or_default(),
+                            entry.position,
+                        )
+                    })
+                    .map_err(located)?,
+                GettextKeys::MessageId => entry
+                    .id
+                    .clone()
+                    .unwrap_or_default(),
+            };
+
+            let segments = dotted
+                .split('.')
+                .map(str::trim)
+                .collect::<Vec<_>>();
+
+            if segments
+                .iter()
+                .any(|segment| segment.is_empty())
+            {
+                return Err(located(PoError::InvalidPath(dotted, entry.position)));
+            }
+
+            let translation =
+                entry_translation(entry, rule, forms.count(), &mapping).map_err(located)?;
+
+            insert_translation(&mut nesting, &segments, &locale, translation)
+                .map_err(|conflict| {
+                    located(match conflict {
+                        InsertConflict::Duplicated => {
+                            PoError::DuplicatedEntry(dotted.clone(), entry.position)
+                        },
+                        InsertConflict::MixedValues => {
+                            PoError::MixedValues(dotted.clone(), entry.position)
+                        },
+                    })
+                })?
+                .set_comments(
+                    locale.clone(),
+                    entry
+                        .comments
+                        .clone(),
+                );
+        }
+
+        Ok(TranslationNode::Nesting(nesting))
+    }
+}
+
+/// Catalog parsing.
+///
+/// **Arguments**
+/// * `source` - The catalog source.
+///
+/// **Returns**
+/// The catalog messages in order, including the header
+/// and the fuzzy and untranslated messages.
+fn parse_entries(source: &str) -> Result<Vec<PoEntry>, PoError> {
+    let mut entries = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut field = None;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let position = offset
+            + (line.len()
+                - line
+                    .trim_start()
+                    .len());
+        let trimmed = line.trim();
+        offset += line.len();
+
+        // a comment or keyword after the translations starts a new message.
+        let starts_entry = !trimmed.starts_with('"')
+            && !trimmed.starts_with("msgstr")
+            && !trimmed.starts_with("msgid_plural")
+            && !entry
+                .translations
+                .is_empty();
+
+        if (trimmed.is_empty() || starts_entry)
+            && (entry
+                .id
+                .is_some()
+                || !entry
+                    .translations
+                    .is_empty())
+        {
+            entries.push(finish_entry(entry)?);
+            entry = PoEntry::default();
+            field = None;
+        }
+
+        if trimmed.is_empty() || trimmed.starts_with("#~") {
+            continue;
+        }
+
+        if let Some(flags) = trimmed.strip_prefix("#,") {
+            entry.fuzzy |= flags
+                .split(',')
+                .any(|flag| flag.trim() == "fuzzy");
+            continue;
+        }
+
+        // translator and extracted comments are kept for the export.
+        if trimmed == "#" || trimmed.starts_with("# ") || trimmed.starts_with("#.") {
+            entry
+                .comments
+                .push(trimmed.to_string());
+            continue;
+        }
+
+        if trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed.starts_with('"') {
+            let value = parse_string(trimmed, position)?;
+
+            match field.ok_or(PoError::Expected("a keyword", position))? {
+                PoField::Context => push_value(&mut entry.context, &value),
+                PoField::Id => push_value(&mut entry.id, &value),
+                PoField::PluralId => push_value(&mut entry.plural_id, &value),
+                PoField::Translation(index) => entry.translations[index].push_str(&value),
+            }
+
+            continue;
+        }
+
+        let (keyword, value) = trimmed
+            .split_once(char::is_whitespace)
+            .ok_or(PoError::Expected("a quoted string", position + trimmed.len()))?;
+
+        let value_position = position + keyword.len() + 1;
+        let value = parse_string(value.trim(), value_position)?;
+
+        match keyword {
+            "msgctxt" => {
+                entry.position = position;
+                entry.context = Some(value);
+                field = Some(PoField::Context);
+            },
+
+            "msgid" => {
+                if entry
+                    .context
+                    .is_none()
+                {
+                    entry.position = position;
+                }
+
+                entry.id = Some(value);
+                field = Some(PoField::Id);
+            },
+
+            "msgid_plural" => {
+                entry.plural_id = Some(value);
+                field = Some(PoField::PluralId);
+            },
+
+            "msgstr" => {
+                entry.translations = vec![value];
+                field = Some(PoField::Translation(0));
+            },
+
+            keyword => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|index| index.strip_suffix(']'))
+                    .ok_or_else(|| PoError::UnknownKeyword(keyword.to_string(), position))?
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|index| {
+                        *index
+                            == entry
+                                .translations
+                                .len()
+                    })
+                    .ok_or(PoError::Expected("the plural forms in order", position))?;
+
+                entry
+                    .translations
+                    .push(value);
+                field = Some(PoField::Translation(index));
+            },
+        }
+    }
+
+    if entry
+        .id
+        .is_some()
+        || !entry
+            .translations
+            .is_empty()
+    {
+        entries.push(finish_entry(entry)?);
+    }
+
+    Ok(entries)
+}
+
+/// Message validation.
+///
+/// **Arguments**
+/// * `entry` - The parsed message.
+///
+/// **Returns**
+/// The message if it has both a `msgid` and a translation.
+fn finish_entry(entry: PoEntry) -> Result<PoEntry, PoError> {
+    if entry
+        .id
+        .is_none()
+    {
+        return Err(PoError::Expected("msgid", entry.position));
+    }
+
+    if entry
+        .translations
+        .is_empty()
+    {
+        return Err(PoError::Expected("msgstr", entry.position));
+    }
+
+    Ok(entry)
+}
+
+/// Appends a continuation string to a field.
+///
+/// **Arguments**
+/// * `field` - The field to append to.
+/// * `value` - The continuation string.
+fn push_value(field: &mut Option<String>, value: &str) {
+    field
+        .get_or_insert_with(String::new)
+        .push_str(value);
+}
+
+/// Quoted string parsing.
+///
+/// **Arguments**
+/// * `quoted` - The quoted string, with the quotes.
+/// * `position` - The byte index of the string.
+///
+/// **Returns**
+/// The string contents with the escape sequences resolved.
+fn parse_string(quoted: &str, position: usize) -> Result<String, PoError> {
+    let inner = quoted
+        .strip_prefix('"')
+        .ok_or(PoError::Expected("a quoted string", position))?
+        .strip_suffix('"')
+        .ok_or(PoError::Expected("'\"'", position + quoted.len()))?;
+
+    let mut result = String::new();
+    let mut characters = inner.char_indices();
+
+    while let Some((index, character)) = characters.next() {
+        match character {
+            '\\' => result.push(match characters.next() {
+                Some((_, 'n')) => '\n',
+                Some((_, 't')) => '\t',
+                Some((_, 'r')) => '\r',
+                Some((_, '"')) => '"',
+                Some((_, '\\')) => '\\',
+                _ => return Err(PoError::InvalidEscape(position + index + 1)),
+            }),
+
+            '"' => return Err(PoError::InvalidEscape(position + index + 1)),
+
+            character => result.push(character),
+        }
+    }
+
+    Ok(result)
+}
+
+/// `Language` header conversion.
+///
+/// Gettext languages such as `pt_BR` or
+/// `sr@latin` are converted to BCP 47.
+///
+/// **Arguments**
+/// * `language` - The header value.
+///
+/// **Returns**
+/// The parsed locale, if it's valid.
+fn header_locale(language: &str) -> Option<Locale> {
+    language
+        .split(['.', '@'])
+        .next()?
+        .replace('_', "-")
+        .parse()
+        .ok()
+}
+
+/// Plural category to form mapping.
+///
+/// Each category is mapped to the form the plural
+/// expression selects for most of its numbers, so
+/// catalogs with more forms than categories still
+/// select the general form for `other`. Categories
+/// that no integer selects, such as the categories
+/// for decimals, are mapped to the unused form with
+/// their position in [`PluralRule::categories`].
+///
+/// **Arguments**
+/// * `forms` - The catalog plural forms.
+/// * `rule` - The plural rule of the catalog language.
+///
+/// **Returns**
+/// The form index of each category, in the category order.
+fn category_forms(forms: &PluralForms, rule: PluralRule) -> Vec<(PluralCategory, usize)> {
+    let mut hits = HashMap::<(PluralCategory, usize), usize>::new();
+
+    for n in (0..=1_000).chain([10_000, 100_000, 1_000_000]) {
+        let Ok(operands) = n
+            .to_string()
+            .parse::<PluralOperands>()
+        else {
+            continue;
+        };
+
+        *hits
+            .entry((rule.select(&operands), forms.index(n)))
+            .or_default() += 1;
+    }
+
+    let mut mapping = Vec::new();
+    for (position, category) in rule
+        .categories()
+        .iter()
+        .enumerate()
+    {
+        let selected = hits
+            .iter()
+            .filter(|((candidate, index), _)| candidate == category && *index < forms.count())
+            .max_by_key(|((_, index), count)| (**count, usize::MAX - index))
+            .map(|((_, index), _)| *index);
+
+        let unused = (position < forms.count()
+            && !hits
+                .keys()
+                .any(|(_, index)| *index == position))
+        .then_some(position);
+
+        if let Some(index) = selected.or(unused) {
+            mapping.push((*category, index));
+        }
+    }
+
+    mapping
+}
+
+/// Message to translation conversion.
+///
+/// Plural messages without a form for `other` use
+/// the last form, which gettext uses for the general case.
+///
+/// **Arguments**
+/// * `entry` - The translated message.
+/// * `rule` - The plural rule of the catalog language.
+/// * `forms` - The plural form count of the catalog.
+/// * `mapping` - The form index of each category.
+///
+/// **Returns**
+/// The translation of the message.
+fn entry_translation(
+    entry: &PoEntry,
+    rule: PluralRule,
+    forms: usize,
+    mapping: &[(PluralCategory, usize)],
+) -> Result<Translation, PoError> {
+    let parse = |text: &str| {
+        text.parse::<FormatString>()
+            .map_err(|err| PoError::Template(err, entry.position))
+    };
+
+    if entry
+        .plural_id
+        .is_none()
+    {
+        return Ok(Translation::Singular(parse(&entry.translations[0])?));
+    }
+
+    if entry
+        .translations
+        .len()
+        > forms
+    {
+        return Err(PoError::UnknownPluralForm(forms, entry.position));
+    }
+
+    let mut variants = HashMap::new();
+    for (category, index) in mapping {
+        if let Some(text) = entry
+            .translations
+            .get(*index)
+            .filter(|text| !text.is_empty())
+        {
+            variants.insert(*category, parse(text)?);
+        }
+    }
+
+    // the last translated form stands for `other` when
+    // its own form is untranslated or not mapped.
+    let other = match variants.remove(&PluralCategory::Other) {
+        Some(other) => other,
+        None => parse(
+            entry
+                .translations
+                .iter()
+                .rfind(|text| !text.is_empty())
+                .map_or("", String::as_str),
+        )?,
+    };
+
+    Ok(Translation::Plural(PluralTranslation::new(rule, other, variants)))
+}
+
+/// Catalog writing.
+///
+/// Writes the translations as a gettext catalog, with
+/// the messages sorted by translation path. The `source`
+/// language provides the `msgid` text, messages without
+/// it are skipped with the `Context` key scheme.
+///
+/// Catalogs keep the comments of the `target` translation,
+/// templates the extracted (`#.`) comments of the source one.
+///
+/// **Arguments**
+/// * `collection` - The loaded translations.
+/// * `source` - The source language.
+/// * `target` - The translated language, or `None` for a `.pot` template.
+/// * `keys` - The key scheme that maps messages to translation paths.
+///
+/// **Returns**
+/// The catalog contents.
+pub fn write_catalog(
+    collection: &TranslationNodeCollection,
+    source: &Locale,
+    target: Option<&Locale>,
+    keys: GettextKeys,
+) -> String {
+    let mut paths = collection
+        .objects()
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+
+    paths.sort();
+    paths.dedup();
+
+    let mut output = String::new();
+
+    write_field(&mut output, "msgid", "");
+    write_field(
+        &mut output,
+        "msgstr",
+        &match target {
+            Some(target) => format!(
+                "Content-Type: text/plain; charset=UTF-8\nContent-Transfer-Encoding: \
+                 8bit\nLanguage: {target}\nPlural-Forms: {}\n",
+                PluralRule::from(target).plural_forms()
+            ),
+            None => "Content-Type: text/plain; charset=UTF-8\nContent-Transfer-Encoding: \
+                     8bit\nPlural-Forms: nplurals=INTEGER; plural=EXPRESSION;\n"
+                .to_string(),
+        },
+    );
+
+    for path in paths {
+        let segments = path
+            .split("::")
+            .collect::<Vec<_>>();
+
+        let Some(object) = collection.find_path(&segments) else {
+            continue;
+        };
+
+        let source_translation = object.get(source);
+        let target_translation = target.and_then(|target| object.get_exact(target));
+
+        let (singular, plural) = match source_translation {
+            Some(Translation::Singular(text)) => (text.original(), None),
+            Some(Translation::Plural(plural)) => (
+                variant(plural, PluralCategory::One)
+                    .or_else(|| variant(plural, PluralCategory::Other))
+                    .unwrap_or_default(),
+                variant(plural, PluralCategory::Other),
+            ),
+            None if keys == GettextKeys::Context => continue,
+            None => ("", None),
+        };
+
+        let is_plural =
+            plural.is_some() || matches!(target_translation, Some(Translation::Plural(_)));
+
+        let dotted = segments.join(".");
+
+        let mut comments = match target {
+            Some(target) => object
+                .comments(target)
+                .to_vec(),
+            None => source
+                .fallbacks()
+                .find(|locale| {
+                    object
+                        .get_exact(locale)
+                        .is_some()
+                })
+                .map(|locale| {
+                    object
+                        .comments(&locale)
+                        .iter()
+                        .filter(|comment| comment.starts_with("#."))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        // the source text is written as an extracted comment for message ids,
+        // which an imported catalog may already have.
+        if keys == GettextKeys::MessageId {
+            for line in singular.lines() {
+                let line = format!("#. {line}");
+
+                if !comments.contains(&line) {
+                    comments.push(line);
+                }
+            }
+        }
+
+        output.push('\n');
+        for comment in comments {
+            let _ = writeln!(output, "{comment}");
+        }
+
+        match keys {
+            GettextKeys::Context => {
+                write_field(&mut output, "msgctxt", &dotted);
+                write_field(&mut output, "msgid", singular);
+
+                if is_plural {
+                    write_field(&mut output, "msgid_plural", plural.unwrap_or(singular));
+                }
+            },
+
+            GettextKeys::MessageId => {
+                write_field(&mut output, "msgid", &dotted);
+
+                if is_plural {
+                    write_field(&mut output, "msgid_plural", &dotted);
+                }
+            },
+        }
+
+        if !is_plural {
+            let text = match target_translation {
+                Some(Translation::Singular(text)) => text.original(),
+                _ => "",
+            };
+
+            write_field(&mut output, "msgstr", text);
+            continue;
+        }
+
+        let categories = match target {
+            Some(target) => PluralRule::from(target).categories(),
+            None => &[PluralCategory::One, PluralCategory::Other],
+        };
+
+        for (index, category) in categories
+            .iter()
+            .enumerate()
+        {
+            let text = match target_translation {
+                Some(Translation::Plural(plural)) => variant(plural, *category),
+                Some(Translation::Singular(text)) => Some(text.original()),
+                None => None,
+            };
+
+            write_field(&mut output, &format!("msgstr[{index}]"), text.unwrap_or_default());
+        }
+    }
+
+    output
+}
+
+/// Plural variant text.
+///
+/// **Arguments**
+/// * `plural` - The plural translation.
+/// * `category` - The category of the variant.
+///
+/// **Returns**
+/// The original text of the variant, if it's defined.
+fn variant(plural: &PluralTranslation, category: PluralCategory) -> Option<&str> {
+    plural
+        .variant(category)
+        .map(FormatString::original)
+}
+
+/// Field writing.
+///
+/// Multiline values are split after each
+/// newline, as gettext tools do.
+///
+/// **Arguments**
+/// * `output` - The catalog being written.
+/// * `keyword` - The field keyword, such as `msgid`.
+/// * `value` - The unescaped field value.
+fn write_field(output: &mut String, keyword: &str, value: &str) {
+    let escape = |line: &str| {
+        line.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\t', "\\t")
+            .replace('\r', "\\r")
+            .replace('\n', "\\n")
+    };
+
+    let lines = value
+        .split_inclusive('\n')
+        .collect::<Vec<_>>();
+
+    match lines.as_slice() {
+        [] => {
+            let _ = writeln!(output, "{keyword} \"\"");
+        },
+        [line] => {
+            let _ = writeln!(output, "{keyword} \"{}\"", escape(line));
+        },
+        lines => {
+            let _ = writeln!(output, "{keyword} \"\"");
+            for line in lines {
+                let _ = writeln!(output, "\"{}\"", escape(line));
+            }
+        },
+    }
+}
@@ -0,0 +1,79 @@
+//! JSON translation file format module.
+//!
+//! JSON files follow the same structure as the TOML
+//! files, objects map to tables and strings map to
+//! translations, so the following files are equivalent.
+//!
+//! ```json
+//! { "greetings": { "formal": { "en": "Hello", "es": "Hola" } } }
+//! ```
+//!
+//! ```toml
+//! [greetings.formal]
+//! en = "Hello"
+//! es = "Hola"
+//! ```
+//!
+//! JSON has no inline tables, so plural translations are
+//! objects whose keys are all plural categories, the
+//! same way as TOML standard tables.
+//!
+//! Documents are parsed by [`JsonParser`], which records
+//! the line and column of every key, so the errors found
+//! while converting the document point to the file.
+
+use translatable_shared::misc::json::JsonParser;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::translations::node::TranslationNode;
+
+use super::{TranslationFormat, closest_location, located_node};
+use crate::data::config::MacroConfig;
+use crate::data::location::SourceLocation;
+use crate::data::translations::TranslationDataError;
+
+/// JSON translation format.
+///
+/// Handles `.json` files.
+pub struct JsonFormat;
+
+impl TranslationFormat for JsonFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["json"]
+    }
+
+    fn per_language_layout(&self) -> bool {
+        true
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        _config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let mut parser = JsonParser::new(source);
+
+        let table = parser
+            .parse_document()
+            .map_err(|err| {
+                let location = SourceLocation::new(path)
+                    .at_offset(source, err.position())
+                    .with_keys(
+                        parser
+                            .keys()
+                            .to_vec(),
+                    );
+                TranslationDataError::ParseJson(err, location)
+            })?;
+
+        located_node(&table, parser.locations(), source, path, locale)
+    }
+
+    fn locate(&self, source: &str, keys: &[String]) -> Option<(usize, usize)> {
+        let mut parser = JsonParser::new(source);
+        let _ = parser.parse_document();
+
+        closest_location(parser.locations(), keys)
+    }
+}
@@ -0,0 +1,363 @@
+//! Translation file formats module.
+//!
+//! This module contains the supported translation
+//! file formats, each one in its own sub-module
+//! implementing [`TranslationFormat`].
+//!
+//! The loader picks the format of each file based on
+//! its extension, read [`format_for`]. Adding a format
+//! only requires implementing the trait and listing
+//! it in [`FORMATS`].
+
+pub mod fluent;
+pub mod gettext;
+pub mod json;
+pub mod toml;
+pub mod xliff;
+pub mod yaml;
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use toml_edit::Table;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::translations::node::{
+    Translation, TranslationNode, TranslationNodeError, TranslationObject,
+};
+
+use self::fluent::FluentFormat;
+use self::gettext::GettextFormat;
+use self::json::JsonFormat;
+use self::toml::TomlFormat;
+use self::xliff::XliffFormat;
+use self::yaml::YamlFormat;
+use super::config::MacroConfig;
+use super::location::SourceLocation;
+use super::translations::TranslationDataError;
+
+/// Translation file format.
+///
+/// Represents a file format translations can be
+/// loaded from. Formats are chosen by file extension
+/// and must produce the same [`TranslationNode`]
+/// structure, so the rest of the crate doesn't know
+/// where a translation came from.
+pub trait TranslationFormat: Sync {
+    /// Handled file extensions.
+    ///
+    /// **Returns**
+    /// The file extensions handled by this format,
+    /// without the leading dot.
+    fn extensions(&self) -> &'static [&'static str];
+
+    /// Single language files.
+    ///
+    /// Formats whose files contain a single language
+    /// get the language inferred from the file path,
+    /// and the files of the same resource in different
+    /// languages are merged together.
+    ///
+    /// **Returns**
+    /// Whether each file contains a single language.
+    fn single_language(&self) -> bool {
+        false
+    }
+
+    /// Per-language layout support.
+    ///
+    /// Formats whose files hold translation objects keyed
+    /// by language can also hold the plain translations of
+    /// a single language, which is the layout used when
+    /// `layout` is configured as `PerLanguage`.
+    ///
+    /// **Returns**
+    /// Whether the format supports the per-language layout.
+    fn per_language_layout(&self) -> bool {
+        false
+    }
+
+    /// Template file check.
+    ///
+    /// Templates, such as gettext `.pot` files, list the
+    /// messages to translate without their translations,
+    /// so they are skipped instead of loaded as a source.
+    ///
+    /// **Arguments**
+    /// * `path` — The file path.
+    ///
+    /// **Returns**
+    /// Whether the file is a template.
+    fn is_template(&self, path: &str) -> bool {
+        let _ = path;
+        false
+    }
+
+    /// File parsing.
+    ///
+    /// **Arguments**
+    /// * `source` — The file contents.
+    /// * `path` — The file path, used in the error messages.
+    /// * `locale` — The language inferred from the file path, only provided
+    ///   for single language formats and for every file when the
+    ///   per-language layout is configured.
+    /// * `config` — The loaded configuration, for the format specific
+    ///   settings such as `gettext_keys`.
+    ///
+    /// **Returns**
+    /// The parsed translations or an error pointing to the
+    /// file location where it was found.
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError>;
+
+    /// Key path location.
+    ///
+    /// Used to locate the errors found after parsing,
+    /// such as a path that is a translation in a file
+    /// and a nesting in another one.
+    ///
+    /// **Arguments**
+    /// * `source` — The file contents.
+    /// * `keys` — The key path to locate, as found in the file.
+    ///
+    /// **Returns**
+    /// The one based line and column of the longest prefix of
+    /// the key path found in the file, `None` if the format
+    /// can't locate keys or the first key is not found.
+    fn locate(&self, source: &str, keys: &[String]) -> Option<(usize, usize)> {
+        let _ = (source, keys);
+        None
+    }
+}
+
+/// Supported translation formats.
+///
+/// The first format handling an extension
+/// is the one used for it.
+pub static FORMATS: &[&dyn TranslationFormat] =
+    &[&TomlFormat, &FluentFormat, &JsonFormat, &YamlFormat, &GettextFormat, &XliffFormat];
+
+/// Format lookup.
+///
+/// **Arguments**
+/// * `path` — The translation file path.
+///
+/// **Returns**
+/// The format handling the file extension, if any.
+pub fn format_for(path: &str) -> Option<&'static dyn TranslationFormat> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(OsStr::to_str)?;
+
+    format_named(extension)
+}
+
+/// Format lookup by name.
+///
+/// Formats are named after any of the extensions
+/// they handle, such as `yaml` or `yml`.
+///
+/// **Arguments**
+/// * `name` — The format name, case insensitive.
+///
+/// **Returns**
+/// The format with that name, if any.
+pub fn format_named(name: &str) -> Option<&'static dyn TranslationFormat> {
+    FORMATS
+        .iter()
+        .copied()
+        .find(|format| {
+            format
+                .extensions()
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(name))
+        })
+}
+
+/// Supported extensions listing.
+///
+/// **Returns**
+/// The supported extensions, dotted and comma
+/// separated, for use in error messages.
+pub fn supported_extensions() -> String {
+    FORMATS
+        .iter()
+        .flat_map(|format| format.extensions())
+        .map(|extension| format!(".{extension}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Translation insertion conflict.
+///
+/// Returned by [`insert_translation`] when a
+/// path can't hold the inserted translation.
+pub enum InsertConflict {
+    /// The path already has a translation for the language.
+    Duplicated,
+
+    /// The path mixes translations and nestings.
+    MixedValues,
+}
+
+/// Translation insertion.
+///
+/// Formats whose entries are flat, such as gettext
+/// catalogs, build the nesting by inserting each
+/// translation under its path.
+///
+/// **Arguments**
+/// * `nesting` — The nesting to insert the translation into.
+/// * `segments` — The translation path.
+/// * `locale` — The language of the translation.
+/// * `translation` — The translation to insert.
+///
+/// **Returns**
+/// The object holding the translation, or an
+/// [`InsertConflict`] if it can't be inserted.
+pub fn insert_translation<'n>(
+    nesting: &'n mut HashMap<String, TranslationNode>,
+    segments: &[&str],
+    locale: &Locale,
+    translation: Translation,
+) -> Result<&'n mut TranslationObject, InsertConflict> {
+    let Some((last, parents)) = segments.split_last() else {
+        return Err(InsertConflict::MixedValues);
+    };
+
+    let mut current = nesting;
+    for segment in parents {
+        let node = current
+            .entry(segment.to_string())
+            .or_insert_with(|| TranslationNode::Nesting(HashMap::new()));
+
+        match node {
+            TranslationNode::Nesting(nested) => current = nested,
+            TranslationNode::Translation(_) => return Err(InsertConflict::MixedValues),
+        }
+    }
+
+    let node = current
+        .entry(last.to_string())
+        .or_insert_with(|| TranslationNode::Translation(TranslationObject::new(HashMap::new())));
+
+    let TranslationNode::Translation(object) = node else {
+        return Err(InsertConflict::MixedValues);
+    };
+
+    if object
+        .get_exact(locale)
+        .is_some()
+    {
+        return Err(InsertConflict::Duplicated);
+    }
+
+    object.merge(
+        [(locale.clone(), translation)]
+            .into_iter()
+            .collect(),
+    );
+    Ok(object)
+}
+
+/// Key locations.
+///
+/// The one based line and column of each key path
+/// of a file, built by the formats that don't keep
+/// spans in a TOML [`Table`].
+pub type KeyLocations = HashMap<Vec<String>, (usize, usize)>;
+
+/// Closest key location lookup.
+///
+/// **Arguments**
+/// * `locations` — The key locations of the file.
+/// * `keys` — The key path to look up.
+///
+/// **Returns**
+/// The location of the longest prefix of the key path
+/// that has one, if any.
+pub fn closest_location(locations: &KeyLocations, keys: &[String]) -> Option<(usize, usize)> {
+    (0..=keys.len())
+        .rev()
+        .find_map(|end| {
+            locations
+                .get(&keys[..end])
+                .copied()
+        })
+}
+
+/// Located table conversion.
+///
+/// Formats that don't keep spans in a TOML [`Table`], such
+/// as JSON and YAML, build the table along with the location
+/// of each key, and convert it with this function so they
+/// follow the same rules as TOML files.
+///
+/// **Arguments**
+/// * `table` — The table built from the file.
+/// * `locations` — The location of each key path.
+/// * `source` — The file contents, used in the error snippets.
+/// * `path` — The file path, used in the error messages.
+/// * `locale` — The language of the file if it holds a single one.
+///
+/// **Returns**
+/// The converted node or an error pointing to the
+/// closest located key to the error, read [`node_error`].
+pub fn located_node(
+    table: &Table,
+    locations: &KeyLocations,
+    source: &str,
+    path: &str,
+    locale: Option<&Locale>,
+) -> Result<TranslationNode, TranslationDataError> {
+    let node = match locale {
+        Some(locale) => TranslationNode::try_from_language_traced(table, locale),
+        None => TranslationNode::try_from_traced(table),
+    };
+
+    node.map_err(|(error, keys)| {
+        node_error(error, keys, |keys| {
+            let (line, column) = closest_location(locations, &keys).unwrap_or((1, 1));
+
+            SourceLocation::new(path)
+                .at(source, line, column)
+                .with_keys(keys)
+        })
+    })
+}
+
+/// Table conversion error location.
+///
+/// Duplicated locales are pointed to both of their
+/// keys, as either of them may be the one to rename,
+/// every other error to the offending key path.
+///
+/// **Arguments**
+/// * `error` — The error raised converting the table.
+/// * `keys` — The key path leading to the offending value.
+/// * `locate` — Locates a key path in the file.
+///
+/// **Returns**
+/// The located [`TranslationDataError`].
+pub fn node_error(
+    error: TranslationNodeError,
+    keys: Vec<String>,
+    locate: impl Fn(Vec<String>) -> SourceLocation,
+) -> TranslationDataError {
+    let TranslationNodeError::DuplicatedLocale(_, first, _) = &error else {
+        return TranslationDataError::Node(error, locate(keys));
+    };
+
+    let mut first_keys = keys.clone();
+    if let Some(last) = first_keys.last_mut() {
+        *last = first.clone();
+    }
+
+    let first = locate(first_keys);
+    TranslationDataError::DuplicatedLocale(error, locate(keys), first)
+}
@@ -0,0 +1,117 @@
+//! TOML translation file format module.
+//!
+//! TOML is the original translation file format,
+//! every file may contain any number of languages
+//! and its structure maps directly to a
+//! [`TranslationNode`]. With the per-language
+//! layout every file holds a single language.
+
+use toml_edit::{ImDocument, Key, Table, TableLike};
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::translations::node::{TranslationNode, TranslationNodeError};
+
+use super::{TranslationFormat, node_error};
+use crate::data::config::MacroConfig;
+use crate::data::location::{SourceLocation, line_column};
+use crate::data::translations::TranslationDataError;
+
+/// TOML translation format.
+///
+/// Handles `.toml` files.
+pub struct TomlFormat;
+
+impl TranslationFormat for TomlFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["toml"]
+    }
+
+    fn per_language_layout(&self) -> bool {
+        true
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        _config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let table = source
+            .parse::<ImDocument<String>>()
+            .map_err(|err| {
+                let location = SourceLocation::new(path);
+                let location = match err.span() {
+                    Some(span) => location.at_offset(source, span.start),
+                    None => location,
+                };
+
+                TranslationDataError::ParseToml(err, location)
+            })?;
+
+        let node = match locale {
+            Some(locale) => TranslationNode::try_from_language_traced(table.as_table(), locale),
+            None => TranslationNode::try_from_traced(table.as_table()),
+        };
+
+        node.map_err(|(err, keys)| {
+            let err = err.locate(table.raw());
+            let template_offset = match &err {
+                TranslationNodeError::TemplateValidation(_, Some(span)) => Some(span.start),
+                _ => None,
+            };
+
+            node_error(err, keys, |keys| {
+                let offset = template_offset.or_else(|| key_offset(table.as_table(), &keys));
+
+                let location = SourceLocation::new(path).with_keys(keys);
+                match offset {
+                    Some(offset) => location.at_offset(source, offset),
+                    None => location,
+                }
+            })
+        })
+    }
+
+    fn locate(&self, source: &str, keys: &[String]) -> Option<(usize, usize)> {
+        let table = source
+            .parse::<ImDocument<String>>()
+            .ok()?;
+
+        key_offset(table.as_table(), keys).map(|offset| line_column(source, offset))
+    }
+}
+
+/// Key path offset lookup.
+///
+/// **Arguments**
+/// * `table` — The table parsed keeping spans.
+/// * `keys` — The key path to look up.
+///
+/// **Returns**
+/// The byte offset of the deepest key of the path
+/// found in the table, `None` if the first one isn't.
+fn key_offset(table: &Table, keys: &[String]) -> Option<usize> {
+    let mut offset = None;
+    let mut current: &dyn TableLike = table;
+
+    for key in keys {
+        let Some(item) = current.get(key) else {
+            break;
+        };
+
+        // implicit tables such as `greetings` in `[greetings.formal]` have no span.
+        if let Some(span) = current
+            .key(key)
+            .and_then(Key::span)
+        {
+            offset = Some(span.start);
+        }
+
+        match item.as_table_like() {
+            Some(nested) => current = nested,
+            None => break,
+        }
+    }
+
+    offset
+}
@@ -0,0 +1,818 @@
+//! XLIFF translation file format module.
+//!
+//! This module parses XLIFF 2.0 documents into a
+//! [`TranslationNode`], and writes the loaded translations
+//! as XLIFF 2.0 documents, so they can be exchanged with
+//! translation vendors and loaded back once translated.
+//!
+//! A document has a source and a target language, only
+//! the target translations are loaded. Each unit maps to
+//! a translation path through its `id`, such as
+//! `greetings.formal`, and the units of a group typed
+//! `translatable:plural` map to the plural variants of
+//! the group path through their `name`.
+//!
+//! Placeholders and select arguments are protected as
+//! `<ph>` elements, whose `equiv` attribute holds the
+//! template argument. Target placeholders are resolved
+//! by `id` against the source ones, so a vendor can move
+//! or drop them but can't alter nor introduce them, and
+//! braces typed as text are escaped.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use roxmltree::{Document, Error as XmlError, Node};
+use thiserror::Error;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::misc::plural::{PluralCategory, PluralRule, PluralTranslation};
+use translatable_shared::misc::templating::{FormatString, TemplateError, TemplateNode};
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::{Translation, TranslationNode};
+
+use super::{InsertConflict, TranslationFormat, insert_translation};
+use crate::data::config::MacroConfig;
+use crate::data::location::SourceLocation;
+use crate::data::translations::TranslationDataError;
+
+/// The XLIFF 2.0 core namespace.
+const NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:2.0";
+
+/// The group type of plural translations.
+const PLURAL_GROUP: &str = "translatable:plural";
+
+/// XLIFF parsing errors.
+///
+/// Every error carries the byte index in the
+/// document where it was found, read [`XliffError::position`].
+#[derive(Error, Debug)]
+pub enum XliffError {
+    /// XML syntax error.
+    ///
+    /// **Parameters**
+    /// * `0` - The underlying XML error.
+    /// * `1` - The byte index of the error.
+    #[error("{0}")]
+    Syntax(XmlError, usize),
+
+    /// Unsupported document error.
+    ///
+    /// The root element must be an XLIFF 2
+    /// `<xliff>` element.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the root element.
+    #[error("Expected an XLIFF 2 document")]
+    UnsupportedDocument(usize),
+
+    /// Missing attribute error.
+    ///
+    /// **Parameters**
+    /// * `0` - The missing attribute.
+    /// * `1` - The element name.
+    /// * `2` - The byte index of the element.
+    #[error("Missing the '{0}' attribute in <{1}>")]
+    MissingAttribute(&'static str, String, usize),
+
+    /// Unsupported inline element error.
+    ///
+    /// Only `<ph>` and `<mrk>` elements can be
+    /// used inside the source and target text.
+    ///
+    /// **Parameters**
+    /// * `0` - The element name.
+    /// * `1` - The byte index of the element.
+    #[error("The inline element <{0}> is not supported, only <ph> and <mrk> are")]
+    UnsupportedElement(String, usize),
+
+    /// Unknown placeholder error.
+    ///
+    /// Raised when a target `<ph>` doesn't match
+    /// any placeholder of the source text.
+    ///
+    /// **Parameters**
+    /// * `0` - The placeholder id.
+    /// * `1` - The byte index of the placeholder.
+    #[error("The placeholder '{0}' is not found in the source text")]
+    UnknownPlaceholder(String, usize),
+
+    /// Unknown plural category error.
+    ///
+    /// **Parameters**
+    /// * `0` - The unit name.
+    /// * `1` - The byte index of the unit.
+    #[error("The plural unit name '{0}' is not a plural category")]
+    UnknownPluralCategory(String, usize),
+
+    /// Missing `other` unit error.
+    ///
+    /// Plural translations need an `other` unit,
+    /// used for the categories without a unit.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the first unit.
+    #[error("The plural translation '{0}' has no 'other' unit")]
+    MissingOtherUnit(String, usize),
+
+    /// Invalid translation path error.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the unit.
+    #[error("Invalid translation path '{0}'")]
+    InvalidPath(String, usize),
+
+    /// Duplicated unit error.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the second unit.
+    #[error("The translation path '{0}' is defined more than once")]
+    DuplicatedUnit(String, usize),
+
+    /// Mixed values error.
+    ///
+    /// A path can't be both a translation
+    /// and the prefix of another path.
+    ///
+    /// **Parameters**
+    /// * `0` - The dotted translation path.
+    /// * `1` - The byte index of the unit.
+    #[error("The translation path '{0}' is both a translation and a nesting")]
+    MixedValues(String, usize),
+
+    /// Template error.
+    ///
+    /// **Parameters**
+    /// * `0` - The template error.
+    /// * `1` - The byte index of the target text.
+    #[error("{0:#}")]
+    Template(TemplateError, usize),
+}
+
+impl XliffError {
+    /// Error position getter.
+    ///
+    /// **Returns**
+    /// The byte index in the document where the error was found.
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Syntax(_, position)
+            | Self::UnsupportedDocument(position)
+            | Self::MissingAttribute(_, _, position)
+            | Self::UnsupportedElement(_, position)
+            | Self::UnknownPlaceholder(_, position)
+            | Self::UnknownPluralCategory(_, position)
+            | Self::MissingOtherUnit(_, position)
+            | Self::InvalidPath(_, position)
+            | Self::DuplicatedUnit(_, position)
+            | Self::MixedValues(_, position)
+            | Self::Template(_, position) => *position,
+        }
+    }
+}
+
+/// XLIFF translation format.
+///
+/// Handles `.xlf` and `.xliff` documents.
+pub struct XliffFormat;
+
+/// A translated unit.
+struct XliffUnit {
+    /// The dotted translation path.
+    path: String,
+
+    /// The plural category, for the units of a plural group.
+    category: Option<PluralCategory>,
+
+    /// The translated template.
+    template: FormatString,
+
+    /// The byte index of the unit.
+    position: usize,
+}
+
+impl TranslationFormat for XliffFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["xlf", "xliff"]
+    }
+
+    fn single_language(&self) -> bool {
+        true
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        _config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let located = |err: XliffError| {
+            let location = SourceLocation::new(path).at_offset(source, err.position());
+            TranslationDataError::ParseXliff(err, location)
+        };
+
+        let document = Document::parse(source).map_err(|err| {
+            let position = err.pos();
+            let line_start = source
+                .split_inclusive('\n')
+                .take(position.row as usize - 1)
+                .map(str::len)
+                .sum::<usize>();
+
+            let index = source[line_start..]
+                .char_indices()
+                .nth(position.col as usize - 1)
+                .map_or(source.len(), |(index, _)| line_start + index);
+
+            located(XliffError::Syntax(err, index))
+        })?;
+
+        let root = document.root_element();
+        if root
+            .tag_name()
+            .name()
+            != "xliff"
+            || !root
+                .attribute("version")
+                .is_some_and(|version| version.starts_with("2."))
+        {
+            return Err(located(XliffError::UnsupportedDocument(
+                root.range()
+                    .start,
+            )));
+        }
+
+        let units = read_units(root).map_err(located)?;
+
+        if units.is_empty() {
+            return Ok(TranslationNode::Nesting(HashMap::new()));
+        }
+
+        let locale = match locale {
+            Some(locale) => locale.clone(),
+            None => root
+                .attribute("trgLang")
+                .and_then(|language| {
+                    language
+                        .parse()
+                        .ok()
+                })
+                .ok_or_else(|| TranslationDataError::UnknownLanguage(path.to_string()))?,
+        };
+
+        let rule = PluralRule::from(&locale);
+
+        // plural units are gathered by path, keeping the document order.
+        let mut translations = Vec::<(String, usize, Option<FormatString>, HashMap<_, _>)>::new();
+        for unit in units {
+            let existing = translations
+                .iter_mut()
+                .find(|(path, ..)| *path == unit.path);
+
+            match (unit.category, existing) {
+                (Some(category), Some((_, _, None, variants)))
+                    if !variants.contains_key(&category) =>
+                {
+                    variants.insert(category, unit.template);
+                },
+
+                (_, Some(_)) => {
+                    return Err(located(XliffError::DuplicatedUnit(unit.path, unit.position)));
+                },
+
+                (Some(category), None) => translations.push((
+                    unit.path,
+                    unit.position,
+                    None,
+                    [(category, unit.template)]
+                        .into_iter()
+                        .collect(),
+                )),
+
+                (None, None) => translations.push((
+                    unit.path,
+                    unit.position,
+                    Some(unit.template),
+                    HashMap::new(),
+                )),
+            }
+        }
+
+        let mut nesting = HashMap::new();
+        for (dotted, position, singular, mut variants) in translations {
+            let translation = match singular {
+                Some(template) => Translation::Singular(template),
+                None => {
+                    let Some(other) = variants.remove(&PluralCategory::Other) else {
+                        return Err(located(XliffError::MissingOtherUnit(dotted, position)));
+                    };
+
+                    Translation::Plural(PluralTranslation::new(rule, other, variants))
+                },
+            };
+
+            let segments = dotted
+                .split('.')
+                .map(str::trim)
+                .collect::<Vec<_>>();
+
+            if segments
+                .iter()
+                .any(|segment| segment.is_empty())
+            {
+                return Err(located(XliffError::InvalidPath(dotted, position)));
+            }
+
+            insert_translation(&mut nesting, &segments, &locale, translation).map_err(
+                |conflict| {
+                    located(match conflict {
+                        InsertConflict::Duplicated => {
+                            XliffError::DuplicatedUnit(dotted.clone(), position)
+                        },
+                        InsertConflict::MixedValues => {
+                            XliffError::MixedValues(dotted.clone(), position)
+                        },
+                    })
+                },
+            )?;
+        }
+
+        Ok(TranslationNode::Nesting(nesting))
+    }
+}
+
+/// Translated units reading.
+///
+/// Units without a target text are skipped,
+/// as they are not translated yet.
+///
+/// **Arguments**
+/// * `root` - The `<xliff>` element.
+///
+/// **Returns**
+/// The translated units in document order.
+fn read_units(root: Node) -> Result<Vec<XliffUnit>, XliffError> {
+    let mut units = Vec::new();
+
+    for unit in root
+        .descendants()
+        .filter(|node| has_name(node, "unit"))
+    {
+        let position = unit
+            .range()
+            .start;
+
+        let plural_group = unit
+            .parent_element()
+            .filter(|parent| {
+                has_name(parent, "group") && parent.attribute("type") == Some(PLURAL_GROUP)
+            });
+
+        let (path, category) = match plural_group {
+            Some(group) => {
+                let name = required_attribute(&unit, "name")?;
+                let category = PluralCategory::from_str(name)
+                    .map_err(|_| XliffError::UnknownPluralCategory(name.to_string(), position))?;
+
+                (required_attribute(&group, "id")?, Some(category))
+            },
+
+            None => (required_attribute(&unit, "id")?, None),
+        };
+
+        let segments = unit
+            .children()
+            .filter(|node| has_name(node, "segment") || has_name(node, "ignorable"))
+            .collect::<Vec<_>>();
+
+        // a unit is translated once all its segments have a target.
+        let translated = segments
+            .iter()
+            .any(|segment| has_name(segment, "segment"))
+            && segments
+                .iter()
+                .filter(|segment| has_name(segment, "segment"))
+                .all(|segment| {
+                    segment
+                        .children()
+                        .any(|node| has_name(&node, "target"))
+                });
+
+        if !translated {
+            continue;
+        }
+
+        let mut template = String::new();
+        for segment in segments {
+            let source = segment
+                .children()
+                .find(|node| has_name(node, "source"));
+
+            let mut placeholders = HashMap::new();
+            if let Some(source) = source {
+                collect_placeholders(source, &mut placeholders)?;
+            }
+
+            // ignorable segments may be left untranslated.
+            if let Some(text) = segment
+                .children()
+                .find(|node| has_name(node, "target"))
+                .or(source)
+            {
+                write_template(text, &placeholders, &mut template)?;
+            }
+        }
+
+        let template = template
+            .parse::<FormatString>()
+            .map_err(|err| XliffError::Template(err, position))?;
+
+        units.push(XliffUnit {
+            path: path.to_string(),
+            category,
+            template,
+            position,
+        });
+    }
+
+    Ok(units)
+}
+
+/// Source placeholders collection.
+///
+/// **Arguments**
+/// * `source` - The `<source>` element.
+/// * `placeholders` - The template argument of each placeholder, by id.
+fn collect_placeholders<'a>(
+    source: Node<'a, '_>,
+    placeholders: &mut HashMap<&'a str, &'a str>,
+) -> Result<(), XliffError> {
+    for placeholder in source
+        .descendants()
+        .filter(|node| has_name(node, "ph"))
+    {
+        placeholders.insert(
+            required_attribute(&placeholder, "id")?,
+            required_attribute(&placeholder, "equiv")?,
+        );
+    }
+
+    Ok(())
+}
+
+/// Inline content to template conversion.
+///
+/// Text is kept with its braces escaped, unless they
+/// are already escaped, and placeholders are replaced
+/// with their template argument.
+///
+/// **Arguments**
+/// * `element` - The `<source>` or `<target>` element.
+/// * `placeholders` - The template argument of each source placeholder, by
+///   id.
+/// * `template` - The template being written.
+fn write_template(
+    element: Node,
+    placeholders: &HashMap<&str, &str>,
+    template: &mut String,
+) -> Result<(), XliffError> {
+    for child in element.children() {
+        if let Some(text) = child
+            .is_text()
+            .then(|| child.text())
+            .flatten()
+        {
+            let mut characters = text
+                .chars()
+                .peekable();
+
+            while let Some(character) = characters.next() {
+                template.push(character);
+
+                // both lone and escaped braces result in an escaped brace.
+                if character == '{' {
+                    characters.next_if_eq(&'{');
+                    template.push('{');
+                }
+            }
+
+            continue;
+        }
+
+        if !child.is_element() {
+            continue;
+        }
+
+        let position = child
+            .range()
+            .start;
+
+        match child
+            .tag_name()
+            .name()
+        {
+            "ph" => {
+                let id = required_attribute(&child, "id")?;
+
+                let argument = placeholders
+                    .get(id)
+                    .ok_or_else(|| XliffError::UnknownPlaceholder(id.to_string(), position))?;
+
+                template.push_str(argument);
+            },
+
+            "mrk" => write_template(child, placeholders, template)?,
+
+            name => return Err(XliffError::UnsupportedElement(name.to_string(), position)),
+        }
+    }
+
+    Ok(())
+}
+
+/// XLIFF element check.
+///
+/// **Arguments**
+/// * `node` - The node to check.
+/// * `name` - The expected local name.
+///
+/// **Returns**
+/// Whether the node is an XLIFF element with that name.
+fn has_name(node: &Node, name: &str) -> bool {
+    node.is_element()
+        && node
+            .tag_name()
+            .name()
+            == name
+        && node
+            .tag_name()
+            .namespace()
+            .is_none_or(|namespace| namespace == NAMESPACE)
+}
+
+/// Required attribute getter.
+///
+/// **Arguments**
+/// * `node` - The element.
+/// * `attribute` - The attribute name.
+///
+/// **Returns**
+/// The attribute value.
+fn required_attribute<'a>(
+    node: &Node<'a, '_>,
+    attribute: &'static str,
+) -> Result<&'a str, XliffError> {
+    node.attribute(attribute)
+        .ok_or_else(|| {
+            XliffError::MissingAttribute(
+                attribute,
+                node.tag_name()
+                    .name()
+                    .to_string(),
+                node.range()
+                    .start,
+            )
+        })
+}
+
+/// Document writing.
+///
+/// Writes the translations as an XLIFF 2.0 document, with
+/// a unit per translation sorted by path. Translations
+/// without a source text are skipped, and units without a
+/// target text are left in the `initial` state.
+///
+/// Plural translations are written as a group with a unit
+/// for each category of the target language.
+///
+/// **Arguments**
+/// * `collection` - The loaded translations.
+/// * `source` - The source language.
+/// * `target` - The target language.
+///
+/// **Returns**
+/// The document contents.
+pub fn write_document(
+    collection: &TranslationNodeCollection,
+    source: &Locale,
+    target: &Locale,
+) -> String {
+    let mut paths = collection
+        .objects()
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+
+    paths.sort();
+    paths.dedup();
+
+    let mut output = String::new();
+
+    let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        output,
+        r#"<xliff xmlns="{NAMESPACE}" version="2.0" srcLang="{}" trgLang="{}">"#,
+        escape(&source.to_string()),
+        escape(&target.to_string())
+    );
+    let _ = writeln!(output, r#"  <file id="translations">"#);
+
+    for path in paths {
+        let segments = path
+            .split("::")
+            .collect::<Vec<_>>();
+
+        let Some(object) = collection.find_path(&segments) else {
+            continue;
+        };
+
+        let Some(source_translation) = object.get(source) else {
+            continue;
+        };
+
+        let target_translation = object.get_exact(target);
+        let dotted = escape(&segments.join("."));
+
+        let plural = matches!(
+            (source_translation, target_translation),
+            (Translation::Plural(_), _) | (_, Some(Translation::Plural(_)))
+        );
+
+        if !plural {
+            let target_template = match target_translation {
+                Some(Translation::Singular(template)) => Some(template),
+                _ => None,
+            };
+
+            let Translation::Singular(source_template) = source_translation else {
+                continue;
+            };
+
+            let _ = writeln!(output, r#"    <unit id="{dotted}">"#);
+            write_segment(&mut output, "    ", source_template, target_template);
+            let _ = writeln!(output, "    </unit>");
+
+            continue;
+        }
+
+        let _ = writeln!(output, r#"    <group id="{dotted}" type="{PLURAL_GROUP}">"#);
+
+        for category in PluralRule::from(target).categories() {
+            let source_template = match source_translation {
+                Translation::Singular(template) => template,
+                Translation::Plural(plural) => match plural
+                    .variant(*category)
+                    .or_else(|| plural.variant(PluralCategory::Other))
+                {
+                    Some(template) => template,
+                    None => continue,
+                },
+            };
+
+            let target_template = match target_translation {
+                Some(Translation::Plural(plural)) => plural.variant(*category),
+                Some(Translation::Singular(template)) => Some(template),
+                None => None,
+            };
+
+            let _ = writeln!(output, r#"      <unit id="{dotted}.{category}" name="{category}">"#);
+            write_segment(&mut output, "      ", source_template, target_template);
+            let _ = writeln!(output, "      </unit>");
+        }
+
+        let _ = writeln!(output, "    </group>");
+    }
+
+    let _ = writeln!(output, "  </file>");
+    let _ = writeln!(output, "</xliff>");
+
+    output
+}
+
+/// Segment writing.
+///
+/// **Arguments**
+/// * `output` - The document being written.
+/// * `indent` - The segment indentation.
+/// * `source` - The source template.
+/// * `target` - The target template, if translated.
+fn write_segment(
+    output: &mut String,
+    indent: &str,
+    source: &FormatString,
+    target: Option<&FormatString>,
+) {
+    let mut arguments = Vec::new();
+
+    let state = match target {
+        Some(_) => "translated",
+        None => "initial",
+    };
+
+    let _ = writeln!(output, r#"{indent}  <segment state="{state}">"#);
+    let _ = writeln!(
+        output,
+        "{indent}    <source>{}</source>",
+        write_inline(source, &mut arguments, true)
+    );
+
+    if let Some(target) = target {
+        let _ = writeln!(
+            output,
+            "{indent}    <target>{}</target>",
+            write_inline(target, &mut arguments, false)
+        );
+    }
+
+    let _ = writeln!(output, "{indent}  </segment>");
+}
+
+/// Inline content writing.
+///
+/// Template arguments are written as `<ph>` elements,
+/// the source ones are numbered in order and the target
+/// ones take the id of the matching source argument.
+///
+/// **Arguments**
+/// * `template` - The template to write.
+/// * `arguments` - The source arguments in order, with whether they were
+///   matched by a target argument.
+/// * `is_source` - Whether the template is the source text.
+///
+/// **Returns**
+/// The inline content.
+fn write_inline(
+    template: &FormatString,
+    arguments: &mut Vec<(String, bool)>,
+    is_source: bool,
+) -> String {
+    let mut content = String::new();
+
+    for node in template.nodes() {
+        let argument = match node {
+            TemplateNode::Text(text) => {
+                content.push_str(&escape(text));
+                continue;
+            },
+
+            node => node.to_string(),
+        };
+
+        let index = match is_source {
+            true => {
+                arguments.push((argument.clone(), false));
+                arguments.len() - 1
+            },
+
+            false => {
+                let matching = arguments
+                    .iter()
+                    .position(|(candidate, matched)| *candidate == argument && !matched)
+                    .or_else(|| {
+                        arguments
+                            .iter()
+                            .rposition(|(candidate, _)| *candidate == argument)
+                    });
+
+                match matching {
+                    Some(index) => {
+                        arguments[index].1 = true;
+                        index
+                    },
+
+                    None => {
+                        arguments.push((argument.clone(), true));
+                        arguments.len() - 1
+                    },
+                }
+            },
+        };
+
+        let argument = escape(&argument);
+        let _ = write!(
+            content,
+            r#"<ph id="{id}" equiv="{argument}" disp="{argument}"/>"#,
+            id = index + 1
+        );
+    }
+
+    content
+}
+
+/// XML escaping.
+///
+/// **Arguments**
+/// * `text` - The text to escape.
+///
+/// **Returns**
+/// The text, usable both as element and attribute content.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,332 @@
+//! YAML translation file format module.
+//!
+//! YAML files follow the same structure as the TOML
+//! files, mappings map to tables and scalars map to
+//! translations, so the following files are equivalent.
+//!
+//! ```yaml
+//! greetings:
+//!   formal:
+//!     en: Hello
+//!     es: Hola
+//! ```
+//!
+//! ```toml
+//! [greetings.formal]
+//! en = "Hello"
+//! es = "Hola"
+//! ```
+//!
+//! Every scalar is read as text, so values such as `yes`
+//! or `1` don't need quotes, except for empty values and
+//! `~` or `null`, which are rejected as translations.
+//! Anchors and aliases are supported, sequences and
+//! multiple documents are not.
+
+use std::collections::HashMap;
+use std::mem::take;
+use std::str::Chars;
+
+use thiserror::Error;
+use toml_edit::{Array, Item, Table, Value};
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::translations::node::TranslationNode;
+use yaml_rust2::parser::{Event, Parser};
+use yaml_rust2::scanner::{Marker, ScanError, TScalarStyle};
+
+use super::{KeyLocations, TranslationFormat, closest_location, located_node};
+use crate::data::config::MacroConfig;
+use crate::data::location::SourceLocation;
+use crate::data::translations::TranslationDataError;
+
+/// YAML parsing errors.
+///
+/// Every error carries the marker in the
+/// file where it was found, read [`YamlError::location`].
+#[derive(Error, Debug)]
+pub enum YamlError {
+    /// Syntax error.
+    ///
+    /// **Parameters**
+    /// * `0` - The underlying scanner error.
+    #[error("{}", _0.info())]
+    Syntax(ScanError),
+
+    /// Top level value error.
+    ///
+    /// The top level value of a translation
+    /// file must be a mapping.
+    ///
+    /// **Parameters**
+    /// * `0` - The marker of the top level value.
+    #[error("Expected a mapping at the top level")]
+    ExpectedMapping(Marker),
+
+    /// Unsupported key error.
+    ///
+    /// Keys map to translation paths and
+    /// languages, so they must be scalars.
+    ///
+    /// **Parameters**
+    /// * `0` - The marker of the key.
+    #[error("Only scalars can be used as keys")]
+    UnsupportedKey(Marker),
+
+    /// Duplicated key error.
+    ///
+    /// **Parameters**
+    /// * `0` - The duplicated key.
+    /// * `1` - The marker of the second occurrence.
+    #[error("The key '{0}' is defined more than once")]
+    DuplicatedKey(String, Marker),
+
+    /// Multiple documents error.
+    ///
+    /// **Parameters**
+    /// * `0` - The marker of the second document.
+    #[error("Only a single document is allowed per file")]
+    MultipleDocuments(Marker),
+}
+
+impl YamlError {
+    /// Error location getter.
+    ///
+    /// **Returns**
+    /// The one based line and column where the error was found.
+    pub fn location(&self) -> (usize, usize) {
+        let marker = match self {
+            Self::Syntax(error) => error.marker(),
+            Self::ExpectedMapping(marker)
+            | Self::UnsupportedKey(marker)
+            | Self::DuplicatedKey(_, marker)
+            | Self::MultipleDocuments(marker) => marker,
+        };
+
+        (marker.line(), marker.col() + 1)
+    }
+}
+
+/// YAML translation format.
+///
+/// Handles `.yaml` and `.yml` files.
+pub struct YamlFormat;
+
+/// YAML document builder.
+///
+/// Builds a TOML [`Table`] from the parser events, recording
+/// the location of every key so the errors found while
+/// converting the table can point to the file.
+struct YamlBuilder<'a> {
+    /// The event parser.
+    parser: Parser<Chars<'a>>,
+
+    /// The items of every anchor, by anchor id.
+    anchors: HashMap<usize, Item>,
+
+    /// The keys leading to the current value.
+    keys: Vec<String>,
+
+    /// The one based line and column of each key path.
+    locations: KeyLocations,
+}
+
+impl TranslationFormat for YamlFormat {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["yaml", "yml"]
+    }
+
+    fn per_language_layout(&self) -> bool {
+        true
+    }
+
+    fn parse(
+        &self,
+        source: &str,
+        path: &str,
+        locale: Option<&Locale>,
+        _config: &MacroConfig,
+    ) -> Result<TranslationNode, TranslationDataError> {
+        let mut builder = YamlBuilder::new(source);
+
+        let table = builder
+            .parse_stream()
+            .map_err(|err| {
+                let (line, column) = err.location();
+                let location = SourceLocation::new(path)
+                    .at(source, line, column)
+                    .with_keys(take(&mut builder.keys));
+                TranslationDataError::ParseYaml(err, location)
+            })?;
+
+        located_node(&table, &builder.locations, source, path, locale)
+    }
+
+    fn locate(&self, source: &str, keys: &[String]) -> Option<(usize, usize)> {
+        let mut builder = YamlBuilder::new(source);
+        let _ = builder.parse_stream();
+
+        closest_location(&builder.locations, keys)
+    }
+}
+
+impl<'a> YamlBuilder<'a> {
+    /// Creates a builder at the start of the source.
+    ///
+    /// **Arguments**
+    /// * `source` — The file contents.
+    ///
+    /// **Returns**
+    /// A builder without parsed keys.
+    fn new(source: &'a str) -> Self {
+        Self {
+            parser: Parser::new_from_str(source),
+            anchors: HashMap::new(),
+            keys: Vec::new(),
+            locations: KeyLocations::new(),
+        }
+    }
+
+    /// Stream parsing.
+    ///
+    /// **Returns**
+    /// The top level mapping as a table, or an
+    /// empty table if the file has no document.
+    fn parse_stream(&mut self) -> Result<Table, YamlError> {
+        let mut table = None;
+
+        loop {
+            match self.next()? {
+                (Event::StreamEnd, _) => return Ok(table.unwrap_or_default()),
+
+                (Event::DocumentStart, marker) if table.is_some() => {
+                    return Err(YamlError::MultipleDocuments(marker));
+                },
+
+                (Event::DocumentStart, _) => {
+                    table = Some(match self.next()? {
+                        (Event::MappingStart(..), _) => self.parse_mapping()?,
+                        (Event::Scalar(value, TScalarStyle::Plain, ..), _) if value.is_empty() => {
+                            Table::new()
+                        },
+                        (_, marker) => return Err(YamlError::ExpectedMapping(marker)),
+                    });
+                },
+
+                _ => {},
+            }
+        }
+    }
+
+    /// Value parsing.
+    ///
+    /// Mappings map to tables and scalars map to strings,
+    /// null scalars and sequences map to empty arrays, so
+    /// they are rejected as TOML arrays are.
+    ///
+    /// **Arguments**
+    /// * `event` - The event that starts the value.
+    ///
+    /// **Returns**
+    /// The parsed value as a TOML item.
+    fn parse_value(&mut self, event: Event) -> Result<Item, YamlError> {
+        let (item, anchor) = match event {
+            Event::Scalar(value, style, anchor, _) => {
+                let item = match style == TScalarStyle::Plain
+                    && matches!(value.as_str(), "" | "~" | "null" | "Null" | "NULL")
+                {
+                    true => Item::Value(Value::Array(Array::new())),
+                    false => Item::Value(Value::from(value)),
+                };
+
+                (item, anchor)
+            },
+
+            Event::MappingStart(anchor, _) => (Item::Table(self.parse_mapping()?), anchor),
+
+            Event::SequenceStart(anchor, _) => {
+                loop {
+                    match self.next()? {
+                        (Event::SequenceEnd, _) => break,
+                        (event, _) => {
+                            self.parse_value(event)?;
+                        },
+                    }
+                }
+
+                (Item::Value(Value::Array(Array::new())), anchor)
+            },
+
+            // the parser rejects aliases to unknown anchors.
+            Event::Alias(anchor) => (
+                self.anchors
+                    .get(&anchor)
+                    .cloned()
+                    .unwrap_or_default(),
+                0,
+            ),
+
+            _ => (Item::None, 0),
+        };
+
+        self.anchor(anchor, &item);
+        Ok(item)
+    }
+
+    /// Mapping parsing.
+    ///
+    /// **Returns**
+    /// The mapping as a table, preserving the key order.
+    fn parse_mapping(&mut self) -> Result<Table, YamlError> {
+        let mut table = Table::new();
+
+        loop {
+            let (key, marker) = match self.next()? {
+                (Event::MappingEnd, _) => return Ok(table),
+                (Event::Scalar(key, ..), marker) => (key, marker),
+                (_, marker) => return Err(YamlError::UnsupportedKey(marker)),
+            };
+
+            if table.contains_key(&key) {
+                return Err(YamlError::DuplicatedKey(key, marker));
+            }
+
+            self.keys
+                .push(key.clone());
+            self.locations
+                .insert(
+                    self.keys
+                        .clone(),
+                    (marker.line(), marker.col() + 1),
+                );
+
+            let (event, _) = self.next()?;
+            let value = self.parse_value(event)?;
+
+            self.keys
+                .pop();
+            table.insert(&key, value);
+        }
+    }
+
+    /// Anchor registration.
+    ///
+    /// **Arguments**
+    /// * `anchor` - The anchor id, `0` if the value has no anchor.
+    /// * `item` - The anchored value.
+    fn anchor(&mut self, anchor: usize, item: &Item) {
+        if anchor != 0 {
+            self.anchors
+                .insert(anchor, item.clone());
+        }
+    }
+
+    /// Next parser event.
+    ///
+    /// **Returns**
+    /// The next event along with its marker.
+    fn next(&mut self) -> Result<(Event, Marker), YamlError> {
+        self.parser
+            .next_token()
+            .map_err(YamlError::Syntax)
+    }
+}
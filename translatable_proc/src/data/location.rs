@@ -0,0 +1,206 @@
+//! Source location module.
+//!
+//! This module declares [`SourceLocation`], which
+//! points load errors to the offending place of a
+//! translation or configuration file, so every error
+//! reads the same regardless of the file format.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Source location.
+///
+/// A file path, optionally appended with the one based
+/// line and column of the error, the key path leading to
+/// the offending value and the source line it is found in.
+///
+/// Displays as `translations/app.toml:14:5 in `greetings.formal``,
+/// read [`snippet`] for the source line.
+///
+/// The location is boxed, as it's carried by the error
+/// enums and would otherwise make every `Result` large.
+///
+/// [`snippet`]: SourceLocation::snippet
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLocation(Box<Location>);
+
+/// Source location contents.
+#[derive(Debug, Clone, PartialEq)]
+struct Location {
+    /// The file path.
+    file: String,
+
+    /// The one based line and column.
+    position: Option<(usize, usize)>,
+
+    /// The keys leading to the offending value.
+    keys: Vec<String>,
+
+    /// The source line the position points to.
+    line: Option<String>,
+}
+
+impl SourceLocation {
+    /// Creates a location pointing to a whole file.
+    ///
+    /// **Arguments**
+    /// * `file` - The file path.
+    ///
+    /// **Returns**
+    /// A location without position nor key path.
+    pub fn new(file: &str) -> Self {
+        Self(Box::new(Location {
+            file: file.to_string(),
+            position: None,
+            keys: Vec::new(),
+            line: None,
+        }))
+    }
+
+    /// Sets the position in the file.
+    ///
+    /// **Arguments**
+    /// * `source` - The file source, to take the snippet from.
+    /// * `line` - The one based line.
+    /// * `column` - The one based column, in characters.
+    ///
+    /// **Returns**
+    /// The same location pointing to that position.
+    pub fn at(mut self, source: &str, line: usize, column: usize) -> Self {
+        self.0
+            .position = Some((line, column));
+        self.0
+            .line = source
+            .lines()
+            .nth(line.saturating_sub(1))
+            .map(|line| {
+                line.trim_end()
+                    .to_string()
+            });
+        self
+    }
+
+    /// Sets the position in the file from a byte offset.
+    ///
+    /// **Arguments**
+    /// * `source` - The file source.
+    /// * `offset` - The byte offset in the source.
+    ///
+    /// **Returns**
+    /// The same location pointing to the offset line and column.
+    pub fn at_offset(self, source: &str, offset: usize) -> Self {
+        let (line, column) = line_column(source, offset);
+        self.at(source, line, column)
+    }
+
+    /// Sets the key path.
+    ///
+    /// **Arguments**
+    /// * `keys` - The keys leading to the offending value.
+    ///
+    /// **Returns**
+    /// The same location with the key path.
+    pub fn with_keys(mut self, keys: Vec<String>) -> Self {
+        self.0
+            .keys = keys;
+        self
+    }
+
+    /// Source line snippet.
+    ///
+    /// Renders the source line the location points to,
+    /// with a caret under the column, preceded by a new
+    /// line so it can be appended to the error message.
+    ///
+    /// **Returns**
+    /// The rendered snippet, empty if there is no position.
+    pub fn snippet(&self) -> String {
+        let (Some((line, column)), Some(source_line)) = (
+            self.0
+                .position,
+            &self
+                .0
+                .line,
+        ) else {
+            return String::new();
+        };
+
+        let gutter = " ".repeat(
+            line.to_string()
+                .len(),
+        );
+
+        // tabs are kept so the caret lines up with the source.
+        let padding = source_line
+            .chars()
+            .take(column.saturating_sub(1))
+            .map(|character| match character {
+                '\t' => '\t',
+                _ => ' ',
+            })
+            .collect::<String>();
+
+        format!("\n{gutter} |\n{line} | {source_line}\n{gutter} | {padding}^")
+    }
+}
+
+/// Source location display.
+///
+/// Displays the file path, followed by the line
+/// and column and the dotted key path, if any.
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(
+            &self
+                .0
+                .file,
+        )?;
+
+        if let Some((line, column)) = self
+            .0
+            .position
+        {
+            write!(f, ":{line}:{column}")?;
+        }
+
+        if !self
+            .0
+            .keys
+            .is_empty()
+        {
+            write!(
+                f,
+                " in `{}`",
+                self.0
+                    .keys
+                    .join(".")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Byte index to line and column conversion.
+///
+/// **Arguments**
+/// * `source` — The file source.
+/// * `position` — The byte index in the source.
+///
+/// **Returns**
+/// The one based line and column of the index.
+pub fn line_column(source: &str, position: usize) -> (usize, usize) {
+    let before = &source[..position.min(source.len())];
+    let line = before
+        .matches('\n')
+        .count()
+        + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+
+    (line, column)
+}
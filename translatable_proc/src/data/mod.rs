@@ -10,4 +10,7 @@
 //! to read the translations from the files.
 
 pub mod config;
+pub mod formats;
+pub mod location;
+pub mod stamps;
 pub mod translations;
@@ -0,0 +1,69 @@
+//! File stamps module.
+//!
+//! This module declares [`FileStamps`], which records
+//! the modification time of the files a cached value
+//! was read from, so the caches of a long-lived
+//! proc-macro server such as rust-analyzer are
+//! reloaded once any of those files is edited.
+
+use std::fs::metadata;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Modification times of a set of files.
+///
+/// Files that don't exist are recorded without a
+/// time, so creating them also invalidates the stamps,
+/// such as a `translatable.toml` added to a crate.
+#[derive(Debug)]
+pub struct FileStamps(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl FileStamps {
+    /// Records the current modification time of the files.
+    ///
+    /// **Arguments**
+    /// * `files` — The files to record.
+    ///
+    /// **Returns**
+    /// The stamps of every file.
+    pub fn new<P: AsRef<Path>>(files: impl IntoIterator<Item = P>) -> Self {
+        Self(
+            files
+                .into_iter()
+                .map(|file| {
+                    let file = file
+                        .as_ref()
+                        .to_path_buf();
+                    let modified = modified(&file);
+
+                    (file, modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Freshness check.
+    ///
+    /// **Returns**
+    /// Whether every file still has the recorded
+    /// modification time, or still doesn't exist.
+    pub fn is_fresh(&self) -> bool {
+        self.0
+            .iter()
+            .all(|(file, recorded)| modified(file) == *recorded)
+    }
+}
+
+/// File modification time.
+///
+/// **Arguments**
+/// * `file` — The file path.
+///
+/// **Returns**
+/// The modification time, `None` if the file
+/// doesn't exist or it can't be read.
+fn modified(file: &Path) -> Option<SystemTime> {
+    metadata(file)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
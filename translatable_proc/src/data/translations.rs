@@ -8,16 +8,36 @@
 //! based on the configuration provided
 //! by the module.
 
-use std::fs::{read_dir, read_to_string};
+use std::collections::{HashMap, HashSet};
+use std::env::var;
+use std::ffi::OsStr;
+use std::fs::{canonicalize, create_dir_all, metadata, read_dir, read_to_string, write};
 use std::io::Error as IoError;
-use std::sync::OnceLock;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
 
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
 use thiserror::Error;
-use toml_edit::{DocumentMut, TomlError};
+use toml_edit::TomlError;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::json::{JsonError, json_object};
+use translatable_shared::misc::locale::Locale;
 use translatable_shared::translations::collection::TranslationNodeCollection;
 use translatable_shared::translations::node::{TranslationNode, TranslationNodeError};
 
-use super::config::{ConfigError, SeekMode, TranslationOverlap, load_config};
+use super::config::{
+    ConfigError, FileLayout, HiddenFiles, MacroConfig, OverlapReport, PathNamespace, SeekMode,
+    SymlinkPolicy, TranslationOverlap, TranslationSource, ValidationLevel, crate_dir, load_config,
+    workspace_root,
+};
+use super::formats::fluent::FluentError;
+use super::formats::gettext::PoError;
+use super::formats::xliff::XliffError;
+use super::formats::yaml::YamlError;
+use super::formats::{TranslationFormat, format_for, supported_extensions};
+use super::location::SourceLocation;
+use super::stamps::FileStamps;
 
 /// Translation retrieval error enum.
 ///
@@ -81,22 +101,20 @@ pub enum TranslationDataError {
     /// Raised when the contents of a translation file cannot be
     /// parsed as valid TOML data.
     ///
-    /// The formatted error message includes the deserialization reason,
-    /// the location within the file (if available), and the file path.
+    /// The formatted error message includes the file path, the line
+    /// and column of the error (if available), the deserialization
+    /// reason and a snippet of the offending line.
     ///
     /// **Parameters**
     /// * `0` — The [`toml::de::Error`] carrying the underlying deserialization
     ///   error.
-    /// * `1` — The file path of the TOML file being parsed.
+    /// * `1` — The location of the error in the TOML file being parsed.
     #[error(
-        "TOML Deserialization error '{reason}' {span} in {1}",
+        "{1}: TOML parse error '{reason}'{snippet}",
         reason = _0.message(),
-        span = _0
-            .span()
-            .map(|range| format!("on {}:{}", range.start, range.end))
-            .unwrap_or_else(String::new)
+        snippet = _1.snippet()
     )]
-    ParseToml(TomlError, String),
+    ParseToml(TomlError, SourceLocation),
 
     /// Translation node parsing failure.
     ///
@@ -104,42 +122,255 @@ pub enum TranslationDataError {
     /// a translation node, typically due to invalid formatting
     /// or missing expected data.
     ///
-    /// The enum implements [`From<TranslationNodeError>`] for
-    /// seamless conversion.
-    ///
     /// **Parameters**
     /// * `0` — The translation node error encountered.
-    #[error("{0:#}")]
-    Node(#[from] TranslationNodeError),
+    /// * `1` — The location of the offending value, along the key path
+    ///   leading to it.
+    #[error("{1}: {0:#}{snippet}", snippet = _1.snippet())]
+    Node(TranslationNodeError, SourceLocation),
+
+    /// Duplicated translation locale.
+    ///
+    /// Raised when two keys of a translation object are
+    /// spellings of the same locale, such as `en` and `EN`
+    /// or `pt_BR` and `pt-BR`, pointing to both keys.
+    ///
+    /// **Parameters**
+    /// * `0` — The [`TranslationNodeError::DuplicatedLocale`] error.
+    /// * `1` — The location of the key declaring the locale again.
+    /// * `2` — The location of the first key declaring the locale.
+    #[error(
+        "{1}: {0:#}{snippet}\n{2}: first declared here{first}",
+        snippet = _1.snippet(),
+        first = _2.snippet()
+    )]
+    DuplicatedLocale(TranslationNodeError, SourceLocation, SourceLocation),
+
+    /// Fluent parsing failure.
+    ///
+    /// Raised when the contents of a `.ftl` translation
+    /// file cannot be parsed or mapped to translations.
+    ///
+    /// **Parameters**
+    /// * `0` — The Fluent error encountered.
+    /// * `1` — The location of the error in the Fluent file being parsed.
+    #[error("{1}: Fluent parse error '{0:#}'{snippet}", snippet = _1.snippet())]
+    ParseFluent(FluentError, SourceLocation),
+
+    /// JSON parsing failure.
+    ///
+    /// Raised when the contents of a `.json` translation
+    /// file are not valid JSON.
+    ///
+    /// **Parameters**
+    /// * `0` — The JSON error encountered.
+    /// * `1` — The location of the error in the JSON file being parsed.
+    #[error("{1}: JSON parse error '{0:#}'{snippet}", snippet = _1.snippet())]
+    ParseJson(JsonError, SourceLocation),
+
+    /// YAML parsing failure.
+    ///
+    /// Raised when the contents of a `.yaml` or `.yml`
+    /// translation file are not valid YAML, or use YAML
+    /// features that can't map to translations.
+    ///
+    /// **Parameters**
+    /// * `0` — The YAML error encountered.
+    /// * `1` — The location of the error in the YAML file being parsed.
+    #[error("{1}: YAML parse error '{0:#}'{snippet}", snippet = _1.snippet())]
+    ParseYaml(YamlError, SourceLocation),
+
+    /// Gettext parsing failure.
+    ///
+    /// Raised when the contents of a `.po` or `.pot`
+    /// translation file are not a valid gettext catalog,
+    /// or its messages can't map to translations.
+    ///
+    /// **Parameters**
+    /// * `0` — The gettext error encountered.
+    /// * `1` — The location of the error in the catalog being parsed.
+    #[error("{1}: Gettext parse error '{0:#}'{snippet}", snippet = _1.snippet())]
+    ParseGettext(PoError, SourceLocation),
+
+    /// XLIFF parsing failure.
+    ///
+    /// Raised when the contents of a `.xlf` or `.xliff`
+    /// translation file are not a valid XLIFF 2 document,
+    /// or its units can't map to translations.
+    ///
+    /// **Parameters**
+    /// * `0` — The XLIFF error encountered.
+    /// * `1` — The location of the error in the document being parsed.
+    #[error("{1}: XLIFF parse error '{0:#}'{snippet}", snippet = _1.snippet())]
+    ParseXliff(XliffError, SourceLocation),
+
+    /// Unsupported file format.
+    ///
+    /// Raised when a file in the translations directory
+    /// has an extension no translation format handles and
+    /// isn't filtered out by the `include` and `exclude`
+    /// configuration.
+    ///
+    /// **Parameters**
+    /// * `0` — The file path of the translation file.
+    #[error(
+        "The file {0} was rejected, its extension is not supported. The supported extensions are \
+         {exts}, use the 'include' or 'exclude' configuration to skip other files",
+        exts = supported_extensions()
+    )]
+    UnsupportedFormat(String),
+
+    /// Rejected translations directory entry.
+    ///
+    /// Raised when an entry of the translations directory
+    /// can't be loaded, such as a broken symbolic link or
+    /// any symbolic link if `symlinks` is configured as
+    /// `Reject`.
+    ///
+    /// **Parameters**
+    /// * `0` — The path of the rejected entry.
+    /// * `1` — The reason the entry was rejected.
+    #[error("The file {0} was rejected, {1}")]
+    RejectedFile(String, String),
+
+    /// Unknown file language.
+    ///
+    /// Raised when the language of a single language
+    /// translation file, such as a `.ftl` file or any file
+    /// with the per-language layout, cannot be inferred
+    /// from its path.
+    ///
+    /// **Parameters**
+    /// * `0` — The file path of the translation file.
+    #[error(
+        "Couldn't infer the language of {0}, name the file or one of its directories after a \
+         BCP 47 tag such as 'es.ftl' or 'es/main.ftl'"
+    )]
+    UnknownLanguage(String),
+
+    /// Placeholder consistency failure.
+    ///
+    /// Raised when the languages of a translation object
+    /// don't use the same placeholders, usually because a
+    /// translator dropped or renamed one of them.
+    ///
+    /// Only raised as an error if `placeholder_consistency`
+    /// is configured as `Error`, read [`load_warnings`] for
+    /// the `Warning` configuration.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The file path of the first file defining the translation.
+    /// * `2` — The placeholders each language lacks.
+    #[error("The languages of '{0}' disagree on their placeholders, {2} in {1}")]
+    InconsistentPlaceholders(String, String, String),
+
+    /// Namespaced translation path collision.
+    ///
+    /// Raised when the `namespace` is configured as `FilePath`
+    /// and two translation files define the same translation path,
+    /// such as `admin.toml` defining `[users.title]` next to
+    /// `admin/users.toml` defining `[title]`.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The file path of the first file defining it.
+    /// * `2` — The file path of the second file defining it.
+    #[error("The translation path '{0}' is defined by both {1} and {2}")]
+    NamespaceCollision(String, String, String),
+
+    /// Shadowed translation.
+    ///
+    /// Raised when more than one file defines the same
+    /// language of a translation, so the `overlap` strategy
+    /// drops all but one of them.
+    ///
+    /// Only raised as an error if `overlap_report` is
+    /// configured as `Error`, with `Warning` it's emitted
+    /// as a warning, read [`load_warnings`].
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The shadowed language.
+    /// * `2` — The file path of the kept translation.
+    /// * `3` — The file path of the dropped translation.
+    #[error("The '{1}' translation of '{0}' in {3} is shadowed by the one in {2}")]
+    ShadowedTranslation(String, Locale, String, String),
+}
+
+/// Parsed translation files.
+///
+/// The translation nodes parsed from each file, or
+/// group of single language files, along their path.
+type TranslationFiles = Vec<(String, TranslationNode)>;
+
+/// Loaded translations of a crate.
+///
+/// The parsed translations along the files they
+/// were read from, cached by [`load_translations`].
+struct LoadedTranslations {
+    /// The merged translations.
+    translations: Arc<TranslationNodeCollection>,
+
+    /// The configuration the translations were loaded with.
+    config: Arc<MacroConfig>,
+
+    /// The canonical paths of the translation and
+    /// configuration files that were read, which are
+    /// tracked as build dependencies by [`load_dependencies`].
+    files: Vec<String>,
+
+    /// The modification time of the files when they
+    /// were read, the translations are reloaded once
+    /// any of them changes.
+    stamps: FileStamps,
+
+    /// The warnings found while loading the translations,
+    /// which are emitted by [`load_warnings`].
+    warnings: Vec<String>,
 }
 
 /// Global thread-safe cache for loaded translations.
 ///
-/// Stores all parsed translations in memory after the first
-/// successful load. Uses [`OnceLock`] to ensure that the translation
-/// data is initialized only once in a thread-safe manner.
-static TRANSLATIONS: OnceLock<TranslationNodeCollection> = OnceLock::new();
+/// Stores the parsed translations of each crate keyed by its
+/// directory after each successful load, as a long-lived
+/// proc-macro server such as rust-analyzer expands the macros
+/// of every workspace member in the same process. An entry is
+/// replaced once any of the files it was read from changes,
+/// read [`FileStamps`].
+static TRANSLATIONS: LazyLock<Mutex<HashMap<PathBuf, Arc<LoadedTranslations>>>> =
+    LazyLock::new(Mutex::default);
 
 /// Recursively walks the target directory to discover all translation files.
 ///
 /// Uses an iterative traversal strategy to avoid recursion depth limitations.
 /// Paths are returned as [`String`] values, ready for processing.
 ///
+/// Files and directories are filtered by their path relative to the root
+/// directory with the configured `include` and `exclude` patterns, hidden
+/// entries are skipped unless `hidden_files` is `Load` and symbolic links
+/// are handled as configured by `symlinks`. Directories reached more than
+/// once through symbolic links are only walked once.
+///
 /// Any filesystem errors, invalid paths, or read failures are reported
 /// via `TranslationDataError`.
 ///
 /// **Arguments**
 /// * `path` — Root directory to scan for translation files.
+/// * `config` — The configuration with the filters to apply.
 ///
 /// **Returns**
 /// A `Result` containing either:
 /// * [`Ok(Vec<String>)`] — A flat list of discovered file paths.
-/// * [`Err(TranslationDataError)`] — If traversal fails at any point.
+/// * [`Err(TranslationDataError)`] — If traversal fails at any point, or a
+///   file is rejected by the [`SymlinkPolicy`].
 ///
 /// [`Ok(Vec<String>)`]: std::vec::Vec<String>
 /// [`Err(TranslationDataError)`]: TranslationDataError
-fn walk_dir(path: &str) -> Result<Vec<String>, TranslationDataError> {
-    let mut stack = vec![path.to_string()];
+fn walk_dir(path: &str, config: &MacroConfig) -> Result<Vec<String>, TranslationDataError> {
+    let root = Path::new(path);
+    let mut visited = HashSet::from([canonicalize(root)?]);
+    let mut stack = vec![root.to_path_buf()];
     let mut result = Vec::new();
 
     while let Some(current_path) = stack.pop() {
@@ -147,17 +378,82 @@ fn walk_dir(path: &str) -> Result<Vec<String>, TranslationDataError> {
 
         for entry in directory {
             let path = entry.path();
-            if path.is_dir() {
-                stack.push(
-                    path.to_str()
-                        .ok_or(TranslationDataError::InvalidUnicode)?
-                        .to_string(),
-                );
-            } else {
-                result.push(
-                    path.to_string_lossy()
-                        .to_string(),
-                );
+            let display = path
+                .to_string_lossy()
+                .to_string();
+
+            if config.hidden_files() == HiddenFiles::Ignore
+                && entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with('.')
+            {
+                continue;
+            }
+
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|component| {
+                    component
+                        .as_os_str()
+                        .to_str()
+                        .ok_or(TranslationDataError::InvalidUnicode)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .join("/");
+
+            if config
+                .exclude()
+                .iter()
+                .any(|pattern| pattern.matches(&relative))
+            {
+                continue;
+            }
+
+            let mut file_type = entry.file_type()?;
+            if file_type.is_symlink() {
+                match config.symlinks() {
+                    SymlinkPolicy::Ignore => continue,
+                    SymlinkPolicy::Reject => {
+                        return Err(TranslationDataError::RejectedFile(
+                            display,
+                            "it's a symbolic link and 'symlinks' is configured as 'Reject'".into(),
+                        ));
+                    },
+                    SymlinkPolicy::Follow => {
+                        file_type = metadata(&path)
+                            .map_err(|_| {
+                                TranslationDataError::RejectedFile(
+                                    display.clone(),
+                                    "it's a broken symbolic link".into(),
+                                )
+                            })?
+                            .file_type();
+                    },
+                }
+            }
+
+            if file_type.is_dir() {
+                // symbolic links may lead to an already walked directory.
+                if visited.insert(canonicalize(&path)?) {
+                    stack.push(path);
+                }
+            } else if !file_type.is_file() {
+                return Err(TranslationDataError::RejectedFile(
+                    display,
+                    "it's not a regular file".into(),
+                ));
+            } else if config
+                .include()
+                .is_empty()
+                || config
+                    .include()
+                    .iter()
+                    .any(|pattern| pattern.matches(&relative))
+            {
+                result.push(display);
             }
         }
     }
@@ -170,50 +466,692 @@ fn walk_dir(path: &str) -> Result<Vec<String>, TranslationDataError> {
 /// On the first invocation, this function:
 /// - Reads the translation directory path from the loaded configuration.
 /// - Recursively walks the directory to discover all translation files.
-/// - Sorts the file list according to the configured `seek_mode`.
+/// - Sorts the file list according to the configured `seek_mode`
+///   and `overlap`.
 /// - Parses each file and validates its content.
+/// - Deep merges every file into a single translation tree, where
+///   each language of a translation is taken from the first file
+///   in that order that defines it.
 ///
 /// Once successfully loaded, the parsed translations are stored
-/// in a global [`OnceLock`]-backed cache and reused for the lifetime
-/// of the process.
+/// in a global cache keyed by the crate directory and reused until
+/// any of the translation or configuration files read is modified.
 ///
-/// This function will return a reference to the cached translations
-/// on every subsequent call.
+/// This function will return the cached translations on every
+/// subsequent call from the same crate while they are fresh.
 ///
 /// **Returns**
 /// A [`Result`] containing either:
-/// * [`Ok(&TranslationNodeCollection)`] — The parsed and cached translations.
+/// * [`Ok(Arc<TranslationNodeCollection>)`] — The parsed and cached
+///   translations.
 /// * [`Err(TranslationDataError)`] — An error because any of the translation
 ///   files couldn't be read.
 ///
-/// [`Ok(&TranslationNodeCollection)`]: TranslationNodeCollection
+/// [`Ok(Arc<TranslationNodeCollection>)`]: TranslationNodeCollection
 /// [`Err(TranslationDataError)`]: TranslationDataError
-pub fn load_translations() -> Result<&'static TranslationNodeCollection, TranslationDataError> {
-    if let Some(translations) = TRANSLATIONS.get() {
-        return Ok(translations);
+pub fn load_translations() -> Result<Arc<TranslationNodeCollection>, TranslationDataError> {
+    let crate_dir = crate_dir();
+    let config = load_config()?;
+
+    // a reloaded configuration may change which files are loaded.
+    if let Some(loaded) = cached_translations(&crate_dir)
+        && Arc::ptr_eq(&loaded.config, &config)
+    {
+        return Ok(Arc::clone(&loaded.translations));
     }
 
-    let config = load_config()?;
-    let mut translation_paths = walk_dir(config.path())?;
+    // sources are sorted by priority, so are their files.
+    let mut nodes = Vec::new();
+    let mut translation_paths = Vec::new();
+    for source in config.sources() {
+        let (source_nodes, source_paths) = load_source(&config, source)?;
+
+        nodes.extend(
+            source_nodes
+                .into_iter()
+                .map(|(path, node)| (path, nest_node(node, source.prefix()))),
+        );
+        translation_paths.extend(source_paths);
+    }
+
+    // the file each translation path and each of its languages is first defined in.
+    let mut origins = HashMap::<String, String>::new();
+    let mut language_origins = HashMap::<(String, Locale), String>::new();
+    let mut shadowed = Vec::new();
+
+    for (path, node) in &nodes {
+        let mut objects = node.objects();
+        objects.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (object_path, object) in objects {
+            origins
+                .entry(object_path.clone())
+                .or_insert_with(|| path.clone());
+
+            let mut locales = object
+                .locales()
+                .collect::<Vec<_>>();
+            locales.sort_by_key(|locale| locale.to_string());
+
+            for locale in locales {
+                let key = (object_path.clone(), locale.clone());
+                match language_origins.get(&key) {
+                    Some(kept) => shadowed.push(TranslationDataError::ShadowedTranslation(
+                        object_path.clone(),
+                        locale.clone(),
+                        kept.clone(),
+                        path.clone(),
+                    )),
+                    None => {
+                        language_origins.insert(key, path.clone());
+                    },
+                }
+            }
+        }
+    }
+
+    let mut warnings = Vec::new();
+    match config.overlap_report() {
+        OverlapReport::Error => {
+            if let Some(error) = shadowed
+                .into_iter()
+                .next()
+            {
+                return Err(error);
+            }
+        },
+        OverlapReport::Warning => warnings.extend(
+            shadowed
+                .iter()
+                .map(ToString::to_string),
+        ),
+        OverlapReport::File => write_overlap_report(&shadowed)?,
+        OverlapReport::Off => {},
+    }
 
-    // Apply sorting based on configuration
+    let translations = TranslationNodeCollection::merged(nodes).map_err(|(err, path, keys)| {
+        TranslationDataError::Node(err, locate_keys(&path, None, keys))
+    })?;
+
+    for error in check_placeholders(translations.root(), &origins) {
+        match config.placeholder_consistency() {
+            ValidationLevel::Error => return Err(error),
+            ValidationLevel::Warning => warnings.push(error.to_string()),
+            ValidationLevel::Off => {},
+        }
+    }
+
+    let mut loaded_files = config
+        .files()
+        .to_vec();
+    for path in &translation_paths {
+        loaded_files.push(
+            canonicalize(path)?
+                .to_str()
+                .ok_or(TranslationDataError::InvalidUnicode)?
+                .to_string(),
+        );
+    }
+
+    let translations = Arc::new(translations);
+    let loaded = LoadedTranslations {
+        translations: Arc::clone(&translations),
+        config,
+        stamps: FileStamps::new(&loaded_files),
+        files: loaded_files,
+        warnings,
+    };
+
+    TRANSLATIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(crate_dir, Arc::new(loaded));
+
+    Ok(translations)
+}
+
+/// Cached translations lookup.
+///
+/// **Arguments**
+/// * `crate_dir` — The directory of the crate invoking the macros.
+///
+/// **Returns**
+/// The translations loaded for the crate, if they were
+/// loaded and none of their files changed since.
+fn cached_translations(crate_dir: &Path) -> Option<Arc<LoadedTranslations>> {
+    TRANSLATIONS
+        .lock()
+        .ok()
+        .and_then(|cache| {
+            cache
+                .get(crate_dir)
+                .cloned()
+        })
+        .filter(|loaded| {
+            loaded
+                .stamps
+                .is_fresh()
+        })
+}
+
+/// Loads the translation files of a source.
+///
+/// The files are sorted by priority according to the
+/// configured `seek_mode` and `overlap`, and the files of the
+/// same resource in different languages are merged together.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `source` — The translation source to load.
+///
+/// **Returns**
+/// The parsed translation files along their file path, sorted
+/// by priority, and the path of every loaded file.
+fn load_source(
+    config: &MacroConfig,
+    source: &TranslationSource,
+) -> Result<(TranslationFiles, Vec<String>), TranslationDataError> {
+    let mut translation_paths = walk_dir(source.path(), config)?;
+
+    // Sort the files by priority, the first file defining a
+    // language of a translation is the one it's taken from.
     translation_paths.sort_by_key(|path| path.to_lowercase());
     if matches!(config.seek_mode(), SeekMode::Unalphabetical)
-        || matches!(config.overlap(), TranslationOverlap::Overwrite)
+        != matches!(config.overlap(), TranslationOverlap::Overwrite)
     {
         translation_paths.reverse();
     }
 
-    let translations = translation_paths
+    let mut nodes = TranslationFiles::new();
+
+    for path in &translation_paths {
+        let format = source
+            .format()
+            .or_else(|| format_for(path))
+            .ok_or_else(|| TranslationDataError::UnsupportedFormat(path.clone()))?;
+
+        if format.is_template(path) {
+            continue;
+        }
+
+        let content = read_to_string(path)?;
+
+        let per_language =
+            config.layout() == FileLayout::PerLanguage && format.per_language_layout();
+
+        let inferred = match format.single_language() || per_language {
+            true => path_locale(path, source.path(), per_language),
+            false => None,
+        };
+
+        if per_language && inferred.is_none() {
+            return Err(TranslationDataError::UnknownLanguage(path.clone()));
+        }
+
+        let node = format.parse(
+            &content,
+            path,
+            inferred
+                .as_ref()
+                .map(|(locale, _)| locale),
+            config,
+        )?;
+
+        // files of the same resource in different languages are merged.
+        let key = match inferred {
+            Some((_, group)) => group,
+            None => path.clone(),
+        };
+
+        match nodes
+            .iter_mut()
+            .find(|(existing_key, _)| *existing_key == key)
+        {
+            Some((_, existing)) => existing
+                .merge_traced(node)
+                .map_err(|(err, keys)| {
+                    TranslationDataError::Node(err, locate_keys(path, Some(format), keys))
+                })?,
+            None => nodes.push((key, node)),
+        }
+    }
+
+    if config.namespace() == PathNamespace::FilePath {
+        nodes = namespace_nodes(nodes, source.path())?;
+    }
+
+    Ok((nodes, translation_paths))
+}
+
+/// Key path location in a translation file.
+///
+/// Locates the errors found while merging translation
+/// files, whose key path may start with the segments the
+/// file is nested under, such as its source `prefix`, so
+/// the leading segments are skipped until the path is found.
+///
+/// **Arguments**
+/// * `path` — The translation file path, or the path of a
+///   group of single language files, which is not located.
+/// * `format` — The format of the file, inferred from the path if `None`.
+/// * `keys` — The conflicting key path.
+///
+/// **Returns**
+/// The location of the key path, along the key path, without
+/// line and column if the file or the key path can't be read.
+fn locate_keys(
+    path: &str,
+    format: Option<&dyn TranslationFormat>,
+    keys: Vec<String>,
+) -> SourceLocation {
+    let location = SourceLocation::new(path);
+
+    let position = format
+        .or_else(|| format_for(path))
+        .zip(read_to_string(path).ok())
+        .and_then(|(format, source)| {
+            (0..keys.len())
+                .find_map(|skip| format.locate(&source, &keys[skip..]))
+                .map(|(line, column)| (source, line, column))
+        });
+
+    let location = match position {
+        Some((source, line, column)) => location.at(&source, line, column),
+        None => location,
+    };
+
+    location.with_keys(keys)
+}
+
+/// Nests a node under a translation path.
+///
+/// **Arguments**
+/// * `node` — The node to nest.
+/// * `segments` — The translation path to nest it under.
+///
+/// **Returns**
+/// The nested node, the same node if the path is empty.
+fn nest_node(node: TranslationNode, segments: &[String]) -> TranslationNode {
+    segments
+        .iter()
+        .rev()
+        .fold(node, |node, key| TranslationNode::Nesting(HashMap::from([(key.clone(), node)])))
+}
+
+/// Single language file locale inference.
+///
+/// The locale is taken from the file name or, if it's not
+/// a BCP 47 tag, from the closest directory named after one
+/// inside the translations directory. With the per-language
+/// layout the directory is looked up first instead, so
+/// `en/it.toml` is English rather than Italian.
+///
+/// **Arguments**
+/// * `path` — The file path of the translation file.
+/// * `root` — The configured translations directory.
+/// * `directory_first` — Whether the directories are looked up
+///   before the file name.
+///
+/// **Returns**
+/// The inferred locale along with the path with that name
+/// replaced by `{locale}`, which identifies the files
+/// of the same resource in different languages.
+fn path_locale(path: &str, root: &str, directory_first: bool) -> Option<(Locale, String)> {
+    let path = Path::new(path);
+
+    match directory_first {
+        true => directory_locale(path, root).or_else(|| stem_locale(path)),
+        false => stem_locale(path).or_else(|| directory_locale(path, root)),
+    }
+}
+
+/// File name locale inference.
+///
+/// **Arguments**
+/// * `path` — The file path of the translation file.
+///
+/// **Returns**
+/// The locale the file is named after along with the
+/// path with its name replaced by `{locale}`, if the
+/// file name is a BCP 47 tag.
+fn stem_locale(path: &Path) -> Option<(Locale, String)> {
+    let locale = path
+        .file_stem()
+        .and_then(OsStr::to_str)?
+        .parse::<Locale>()
+        .ok()?;
+
+    let group = path.with_file_name(format!(
+        "{{locale}}.{}",
+        path.extension()
+            .and_then(OsStr::to_str)
+            .unwrap_or_default()
+    ));
+
+    Some((
+        locale,
+        group
+            .to_string_lossy()
+            .to_string(),
+    ))
+}
+
+/// Directory locale inference.
+///
+/// **Arguments**
+/// * `path` — The file path of the translation file.
+/// * `root` — The configured translations directory.
+///
+/// **Returns**
+/// The locale of the closest directory named after a BCP 47
+/// tag inside the translations directory along with the path
+/// with that directory replaced by `{locale}`, if any.
+fn directory_locale(path: &Path, root: &str) -> Option<(Locale, String)> {
+    let relative = path
+        .strip_prefix(root)
+        .unwrap_or(path);
+
+    relative
+        .parent()?
+        .components()
+        .rev()
+        .find_map(|component| {
+            let name = component
+                .as_os_str()
+                .to_str()?;
+
+            let locale = name
+                .parse::<Locale>()
+                .ok()?;
+
+            let group = path
+                .components()
+                .map(|other| match other == component {
+                    true => OsStr::new("{locale}"),
+                    false => other.as_os_str(),
+                })
+                .collect::<PathBuf>();
+
+            Some((
+                locale,
+                group
+                    .to_string_lossy()
+                    .to_string(),
+            ))
+        })
+}
+
+/// Translation file namespacing.
+///
+/// Nests every translation file under its path relative
+/// to the translations directory, without the extension
+/// and the language names of single language files, so
+/// `admin/users.toml` defines the `admin::users` paths.
+///
+/// **Arguments**
+/// * `nodes` — The parsed translation files along their file path.
+/// * `root` — The configured translations directory.
+///
+/// **Returns**
+/// The namespaced translation files or a [`NamespaceCollision`]
+/// error if two files define the same translation path.
+///
+/// [`NamespaceCollision`]: TranslationDataError::NamespaceCollision
+fn namespace_nodes(
+    nodes: TranslationFiles,
+    root: &str,
+) -> Result<TranslationFiles, TranslationDataError> {
+    let nodes = nodes
+        .into_iter()
+        .map(|(path, node)| {
+            let file_path = Path::new(&path);
+            let namespace = file_path
+                .strip_prefix(root)
+                .unwrap_or(file_path)
+                .with_extension("")
+                .components()
+                .filter_map(|component| match component {
+                    Component::Normal(name) => name.to_str(),
+                    _ => None,
+                })
+                .filter(|name| *name != "{locale}")
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+
+            (path, nest_node(node, &namespace))
+        })
+        .collect::<Vec<_>>();
+
+    // translation paths and the paths nesting them, along the file defining them.
+    let mut objects = HashMap::<String, &str>::new();
+    let mut nestings = HashMap::<String, &str>::new();
+
+    for (path, node) in &nodes {
+        let mut file_objects = node.objects();
+        file_objects.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (object_path, _) in file_objects {
+            let segments = object_path
+                .split("::")
+                .collect::<Vec<_>>();
+
+            let collision = (1..segments.len())
+                .map(|end| segments[..end].join("::"))
+                .chain([object_path.clone()])
+                .find_map(|prefix| {
+                    objects
+                        .get(&prefix)
+                        .filter(|owner| *owner != path)
+                        .map(|owner| (prefix, *owner))
+                })
+                .or_else(|| {
+                    nestings
+                        .get(&object_path)
+                        .filter(|owner| *owner != path)
+                        .map(|owner| (object_path.clone(), *owner))
+                });
+
+            if let Some((collision_path, owner)) = collision {
+                return Err(TranslationDataError::NamespaceCollision(
+                    collision_path,
+                    owner.to_string(),
+                    path.clone(),
+                ));
+            }
+
+            for end in 1..segments.len() {
+                nestings
+                    .entry(segments[..end].join("::"))
+                    .or_insert(path);
+            }
+
+            objects.insert(object_path, path);
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// Shadowed translation report writing.
+///
+/// Writes the shadowed translations as a JSON array to
+/// `translatable/overlaps-<crate>.json` inside the cargo
+/// target directory, where each entry has the translation
+/// `path`, the shadowed `language` and the `kept` and
+/// `shadowed` file paths. The report is written even if
+/// it's empty, so a previous report is never left behind.
+///
+/// The target directory is `CARGO_TARGET_DIR` if it's set,
+/// or `target` otherwise, relative paths are resolved against
+/// the workspace root directory, or the crate directory if
+/// it doesn't belong to a workspace, as cargo does. Each
+/// report is named after the package invoking the macros,
+/// so the crates sharing a target directory don't overwrite
+/// each other's report.
+///
+/// **Arguments**
+/// * `shadowed` — The [`ShadowedTranslation`] errors found while merging.
+///
+/// **Returns**
+/// An I/O error if the report couldn't be written.
+///
+/// [`ShadowedTranslation`]: TranslationDataError::ShadowedTranslation
+fn write_overlap_report(shadowed: &[TranslationDataError]) -> Result<(), TranslationDataError> {
+    let entries = shadowed
         .iter()
-        .map(|path| {
-            let table = read_to_string(path)?
-                .parse::<DocumentMut>()
-                .map_err(|err| TranslationDataError::ParseToml(err, path.clone()))?;
+        .filter_map(|error| match error {
+            TranslationDataError::ShadowedTranslation(path, locale, kept, dropped) => {
+                Some(json_object(&[
+                    ("path", path),
+                    ("language", &locale.to_string()),
+                    ("kept", kept),
+                    ("shadowed", dropped),
+                ]))
+            },
+            _ => None,
+        })
+        .map(|entry| format!("  {entry}"))
+        .collect::<Vec<_>>();
+
+    let report = match entries.is_empty() {
+        true => "[]\n".to_string(),
+        false => format!("[\n{}\n]\n", entries.join(",\n")),
+    };
+
+    let crate_dir = crate_dir();
+    let crate_name = var("CARGO_PKG_NAME").unwrap_or_else(|_| {
+        crate_dir
+            .file_name()
+            .map(|name| {
+                name.to_string_lossy()
+                    .to_string()
+            })
+            .unwrap_or_default()
+    });
 
-            Ok((path.clone(), TranslationNode::try_from(table.as_table())?))
+    let target_dir = var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+    let directory = workspace_root(&crate_dir)
+        .unwrap_or(crate_dir)
+        .join(target_dir)
+        .join("translatable");
+
+    create_dir_all(&directory)?;
+    write(directory.join(format!("overlaps-{crate_name}.json")), report)?;
+
+    Ok(())
+}
+
+/// Placeholder consistency validation.
+///
+/// Compares the placeholders used by each language of
+/// every translation object in the merged translations,
+/// read [`TranslationObject::placeholder_mismatches`].
+///
+/// **Arguments**
+/// * `node` — The merged translation tree.
+/// * `origins` — The file path each translation path is first defined in.
+///
+/// **Returns**
+/// An [`InconsistentPlaceholders`] error for each translation
+/// object whose languages disagree, sorted by translation path.
+///
+/// [`TranslationObject::placeholder_mismatches`]: translatable_shared::translations::node::TranslationObject::placeholder_mismatches
+/// [`InconsistentPlaceholders`]: TranslationDataError::InconsistentPlaceholders
+fn check_placeholders(
+    node: &TranslationNode,
+    origins: &HashMap<String, String>,
+) -> Vec<TranslationDataError> {
+    let mut objects = node.objects();
+    objects.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    objects
+        .into_iter()
+        .filter_map(|(object_path, object)| {
+            let mismatches = object.placeholder_mismatches();
+            if mismatches.is_empty() {
+                return None;
+            }
+
+            let details = mismatches
+                .iter()
+                .map(|(locale, keys)| {
+                    let keys = keys
+                        .iter()
+                        .map(|key| format!("'{key}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    format!("'{locale}' lacks {keys}")
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            let path = origins
+                .get(&object_path)
+                .cloned()
+                .unwrap_or_default();
+
+            Some(TranslationDataError::InconsistentPlaceholders(object_path, path, details))
+        })
+        .collect()
+}
+
+/// Emits the translation loading warnings.
+///
+/// Warnings found by [`load_translations`] are kept along
+/// the translations of each crate and emitted by [`init!()`],
+/// which is invoked once per crate, so each warning is
+/// reported once instead of on every macro call site. As
+/// the output only depends on the loaded translations, it
+/// is the same whenever the macro is expanded, such as when
+/// rust-analyzer expands it after an edit.
+///
+/// **Returns**
+/// Items emitting each warning at the invocation site.
+///
+/// [`init!()`]: crate::init
+pub fn load_warnings() -> TokenStream2 {
+    cached_translations(&crate_dir())
+        .map(|loaded| {
+            loaded
+                .warnings
+                .iter()
+                .map(|warning| warning.to_compile_warning(Span::call_site()))
+                .collect()
         })
-        .collect::<Result<TranslationNodeCollection, TranslationDataError>>()?;
+        .unwrap_or_default()
+}
 
-    Ok(TRANSLATIONS.get_or_init(|| translations))
+/// Tracks the loaded files as build dependencies.
+///
+/// Proc macros can't declare the files they read, so
+/// the output includes the bytes of every file read by
+/// [`load_translations`] in an unused constant. This way
+/// the compiler tracks them and editing a translation
+/// file or the configuration file invalidates the macro
+/// output, creating or removing files isn't tracked.
+///
+/// Cargo rebuilds the whole crate when any of its
+/// dependencies change, so the constant is only emitted
+/// by [`init!()`], which is invoked once per crate, rather
+/// than embedding every file on each macro call site.
+///
+/// **Returns**
+/// An item including every loaded file, empty if the
+/// translations weren't loaded.
+///
+/// [`init!()`]: crate::init
+pub fn load_dependencies() -> TokenStream2 {
+    let Some(loaded) = cached_translations(&crate_dir()) else {
+        return TokenStream2::new();
+    };
+
+    let files = &loaded.files;
+    if files.is_empty() {
+        return TokenStream2::new();
+    }
+
+    quote! {
+        const _: &[&[u8]] = &[#(::core::include_bytes!(#files)),*];
+    }
 }
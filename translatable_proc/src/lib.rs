@@ -10,16 +10,109 @@
 #![warn(missing_docs)]
 
 use macro_generation::context::context_macro;
-use macro_generation::translation::translation_macro;
+use macro_generation::gettext::gettext_catalog_macro;
+use macro_generation::init::init_macro;
+use macro_generation::languages::available_languages_macro;
+use macro_generation::translation::{TranslationOutput, translation_macro, translation_str_macro};
+use macro_generation::xliff::xliff_document_macro;
 use macro_input::context::{ContextMacroArgs, ContextMacroStruct};
+use macro_input::gettext::GettextCatalogArgs;
+use macro_input::languages::AvailableLanguagesArgs;
 use macro_input::translation::TranslationMacroArgs;
+use macro_input::xliff::XliffDocumentArgs;
 use proc_macro::TokenStream;
+use syn::parse::Nothing;
 use syn::parse_macro_input;
 
 mod data;
 mod macro_generation;
 mod macro_input;
 
+/// # Lazy translation macro.
+///
+/// This macro takes the same parameters and performs the
+/// same validation as [`translation!()`], but instead of
+/// rendering the translation to a [`String`] it evaluates
+/// to a value implementing `Display`, which renders the
+/// translation each time it's displayed.
+///
+/// This way translations can be written to a formatter,
+/// such as `write!`, `format_args!` or a logger, without
+/// allocating the rendered string nor converting the
+/// arguments to [`String`].
+///
+/// The arguments are moved into the returned value, pass
+/// references to keep using them.
+///
+/// **Returns**
+/// If the language and path are static an `impl Display`, otherwise
+/// a `Result` containing either:
+/// * `Ok(impl Display)` - If the translation was found.
+/// * `Err(translatable::Error)` - If the lookup fails with a runtime error.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_display(input: TokenStream) -> TokenStream {
+    translation_macro(parse_macro_input!(input as TranslationMacroArgs), TranslationOutput::Lazy)
+        .into()
+}
+
+/// # Template-free translation macro.
+///
+/// This macro resolves a translation in compile time and
+/// expands into its text as a `&'static str`, so it can be
+/// used in `const` and `static` items or `match` arms
+/// without allocating.
+///
+/// **Parameters**
+/// * `language` - A BCP 47 string literal, it may be wrapped in an array
+///   followed by language literals to override the configured fallback
+///   chain, such as `["es-AR", "es", "en"]`.
+/// * `path` - A path prefixed with `static`.
+///
+/// Only singular translations without templates can be
+/// expanded, translations with placeholders, select
+/// arguments or plural variants are compile errors, as
+/// are dynamic parameters and replacement arguments.
+///
+/// **Returns**
+/// A `&'static str` with the translation text.
+#[proc_macro]
+pub fn translation_str(input: TokenStream) -> TokenStream {
+    translation_str_macro(parse_macro_input!(input as TranslationMacroArgs)).into()
+}
+
+/// # Translation table initialization macro.
+///
+/// This macro declares the translation table shared by
+/// every [`translation!()`] call with a dynamic path, so
+/// the translation files are embedded once per crate
+/// instead of once per call site.
+///
+/// It must be invoked once in the crate root, before the
+/// dynamic path calls compile, and takes no parameters.
+///
+/// It also tracks the translation and configuration files
+/// as build dependencies and reports the warnings found while
+/// loading the translations, such as languages disagreeing on
+/// their placeholders, once for the whole crate. Crates only
+/// using static paths should invoke it too, otherwise editing
+/// a translation file doesn't recompile them.
+///
+/// Dynamic path calls in a crate that doesn't invoke it
+/// fail to compile with ``cannot find value
+/// `__TRANSLATABLE_TRANSLATIONS` in the crate root``.
+///
+/// **Returns**
+/// A hidden lazily initialized `static` with every translation.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn init(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as Nothing);
+    init_macro().into()
+}
+
 /// # Translation obtention macro.
 ///
 /// This macro generates the way to obtain a translation
@@ -27,11 +120,14 @@ mod macro_input;
 /// in the `translatable.toml` file.
 ///
 /// **Parameters**
-/// * `language` - A string literal for static inference or an instance of
-/// `translatable::Language` for dynamic inference.
+/// * `language` - A BCP 47 string literal for static inference or an
+///   instance of `translatable::Language` or `translatable::Locale` for
+///   dynamic inference. It may be wrapped in an array followed by language
+///   literals to override the configured fallback chain, such as `[language,
+///   "es", "en"]`.
 /// * `path` - A pat prefixed with `static` for static inference or a `Vec<impl
 ///   ToString>`
-/// for dynamic inference.
+///   for dynamic inference.
 /// * `replacements` - Arguments similar to python's `kwargs` for the
 ///   translation replacements.
 ///
@@ -40,13 +136,17 @@ mod macro_input;
 ///
 /// The optimizations are described the following way
 /// - If path is static, no runtime lookup will be required
-/// - If the path is dynamic, the file structure will be hardcoded.
+/// - If the path is dynamic, the translation table declared by
+///   `translatable::init!()` in the crate root is used.
 ///
 /// - If the language is static, the validation will be reported by
 ///   `rust-analyzer`.
 /// - If the language is dynamic the validation will be reported in runtime in
 ///   the `Err` branch.
 ///
+/// - Regional languages such as `pt-BR` fall back to `pt` if there is no
+///   specific translation for them.
+///
 /// - If both are dynamic a single [`String`] will be generated.
 ///
 /// Independently of any other parameter, the `replacements` parameter
@@ -55,8 +155,16 @@ mod macro_input;
 /// You can shorten it's invocation if a similar identifier is on scope,
 /// for example `x = x` can be shortened with `x`.
 ///
-/// Replacement parameters are not validated, if a parameter exists it will be
-/// replaced otherwise it won't.
+/// If the path is static, replacement parameters are validated against the
+/// placeholders of the translation, reporting unknown parameters and missing
+/// placeholders as warnings or errors depending on `argument_validation`.
+/// Otherwise, a parameter is replaced if it exists, unless `runtime_validation`
+/// is `Strict`, in which case a mismatch is returned in the `Err` branch.
+///
+/// Translations with plural variants are selected with the `count` parameter
+/// following the CLDR plural rules of the language, which is required if the
+/// path is static. The plural categories required by each language are
+/// validated in compile time.
 ///
 /// **Returns**
 /// A `Result` containing either:
@@ -64,7 +172,11 @@ mod macro_input;
 /// * `Err(translatable::Error)` - If the invocation fails with a runtime error.
 #[proc_macro]
 pub fn translation(input: TokenStream) -> TokenStream {
-    translation_macro(parse_macro_input!(input as TranslationMacroArgs).into()).into()
+    translation_macro(
+        parse_macro_input!(input as TranslationMacroArgs),
+        TranslationOutput::Rendered,
+    )
+    .into()
 }
 
 /// # Translation context macro
@@ -80,8 +192,10 @@ pub fn translation(input: TokenStream) -> TokenStream {
 /// You can configure some parameters as a punctuated [`MetaNameValue`],
 /// these are
 /// - `base_path`: A path that gets prepended to all fields.
-/// - `fallback_language`: A language that must be available for all
-/// paths and changes the return type of the `load_translations` method.
+/// - `fallback_language`: A language or an array of languages tried in order,
+///   the last one must be available for all paths and changes the return type
+///   of the `load_translations` method. Otherwise the configured fallback chain
+///   is used.
 ///
 /// All the fields on the struct now point to paths in your translation
 /// files, you can extend these paths applying the `#[path()]` attribute
@@ -105,3 +219,89 @@ pub fn translation_context(attr: TokenStream, item: TokenStream) -> TokenStream
     )
     .into()
 }
+
+/// # Available languages macro
+///
+/// This macro lists the languages found in the translation
+/// files as a `&'static [translatable::Locale]`, sorted by
+/// their BCP 47 representation.
+///
+/// **Parameters**
+/// * `path` - An optional translation path such as `greetings::formal`, if
+///   provided only the languages available for that translation are listed.
+///
+/// This is meant to be used along `translatable::negotiate_language` to
+/// match an `Accept-Language` header against the languages that
+/// actually exist.
+///
+/// **Returns**
+/// A `&'static [translatable::Locale]` with the available languages.
+#[proc_macro]
+pub fn available_languages(input: TokenStream) -> TokenStream {
+    available_languages_macro(parse_macro_input!(input as AvailableLanguagesArgs)).into()
+}
+
+/// # Gettext catalog macro
+///
+/// This macro exports the translation files as a gettext
+/// catalog, so they can be handed to translators using
+/// the standard gettext tooling.
+///
+/// **Parameters**
+/// * `source` - A BCP 47 string literal for the language the message ids are
+///   taken from.
+/// * `target` - An optional BCP 47 string literal for the translated language,
+///   if omitted a `.pot` template with empty translations is generated.
+/// * `path` - An optional `path = <expr>` parameter with the file to write the
+///   catalog to, any value implementing `AsRef<Path>` is accepted.
+///
+/// Messages are sorted by translation path and mapped to it
+/// following the `gettext_keys` configuration, the same way
+/// `.po` files are loaded, so an exported catalog can be
+/// translated and placed back in the translations directory.
+///
+/// When a path is provided the catalog is written to that
+/// file, creating its parent directories, which is meant to
+/// be done from a build script so the catalogs are exported
+/// whenever the translations change.
+///
+/// **Returns**
+/// A `&'static str` with the catalog contents, or a
+/// `std::io::Result<()>` if a path was provided.
+#[proc_macro]
+pub fn gettext_catalog(input: TokenStream) -> TokenStream {
+    gettext_catalog_macro(parse_macro_input!(input as GettextCatalogArgs)).into()
+}
+
+/// # XLIFF document macro
+///
+/// This macro exports the translation files as an XLIFF 2.0
+/// document, the exchange format translation vendors work with.
+///
+/// **Parameters**
+/// * `source` - A BCP 47 string literal for the language the source text is
+///   taken from.
+/// * `target` - A BCP 47 string literal for the translated language.
+/// * `path` - An optional `path = <expr>` parameter with the file to write the
+///   document to, any value implementing `AsRef<Path>` is accepted.
+///
+/// Units are sorted by translation path and identified by it,
+/// plural translations are written as a group with a unit per
+/// plural category of the target language. Placeholders are
+/// protected as `<ph>` elements, which are resolved against
+/// the source text when the document is loaded back from the
+/// translations directory.
+///
+/// When a path is provided the document is written to that
+/// file, creating its parent directories, the same way as
+/// [`gettext_catalog!()`] does.
+///
+/// **Returns**
+/// A `&'static str` with the document contents, or a
+/// `std::io::Result<()>` if a path was provided.
+///
+/// [`gettext_catalog!()`]: crate::gettext_catalog
+#[proc_macro]
+pub fn xliff_document(input: TokenStream) -> TokenStream {
+    xliff_document_macro(parse_macro_input!(input as XliffDocumentArgs)).into()
+}
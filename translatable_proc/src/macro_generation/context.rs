@@ -10,8 +10,9 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, quote};
 use thiserror::Error;
 use translatable_shared::handle_macro_result;
-use translatable_shared::macros::collections::map_to_tokens;
+use translatable_shared::misc::locale::Locale;
 
+use crate::data::config::load_config;
 use crate::data::translations::load_translations;
 use crate::macro_input::context::{ContextMacroArgs, ContextMacroStruct};
 
@@ -36,9 +37,26 @@ enum MacroCompileError {
     #[error("A translation with the path '{0}' could not be found")]
     TranslationNotFound(String),
 
-    /// A fallback is not available for a specified translation path.
-    #[error("One of the translations doesn't have the fallback language available")]
-    FallbackNotAvailable,
+    /// The last fallback is not available for a specified translation path.
+    ///
+    /// **Parameters**
+    /// * `0` — The last language of the fallback chain.
+    /// * `1` — The translation path, displayed in `::` notation.
+    #[error("The fallback language '{0}' is not available for the path '{1}'")]
+    FallbackNotAvailable(Locale, String),
+
+    /// A plural translation doesn't define all the categories
+    /// its language plural rule requires.
+    ///
+    /// **Parameters**
+    /// * `0` — The `Locale` of the plural translation.
+    /// * `1` — The translation path, displayed in `::` notation.
+    /// * `2` — The missing categories, comma separated.
+    #[error(
+        "The plural translation for '{0}' in '{1}' is missing the categories required by its \
+         plural rule: {2}"
+    )]
+    MissingPluralCategories(Locale, String, String),
 
     /// One of the fields type is not a &str or String.
     #[error("Only String' and '&str' is allowed for translation contexts")]
@@ -50,9 +68,13 @@ enum MacroCompileError {
 /// Expands into a struct that implements structured translation
 /// loading.
 ///
-/// If there is a fallback language configured, this is checked
-/// with all the paths and then the `load_translations` generated
-/// method will return the same structure instead of a Result.
+/// The languages are looked up following the fallback chain, either
+/// the one specified in the macro arguments or the configured one, and
+/// the last language of that chain is checked with all the paths.
+///
+/// If there is a fallback language specified in the arguments, the
+/// `load_translations` generated method will return the same structure
+/// instead of a Result.
 ///
 /// **Arguments**
 /// * `macro_args` - The parsed arguments for the macro invocation.
@@ -67,8 +89,13 @@ pub fn context_macro(
     macro_input: ContextMacroStruct,
 ) -> TokenStream2 {
     let translations = handle_macro_result!(out load_translations());
+    let config = handle_macro_result!(out load_config());
     let base_path = macro_args.base_path();
 
+    let fallback = macro_args
+        .fallback_language()
+        .unwrap_or(config.fallback());
+
     let struct_pub = macro_input.visibility();
     let struct_ident = macro_input.ident();
 
@@ -102,16 +129,36 @@ pub fn context_macro(
                     .find_path(&path_segments)
                     .ok_or(MacroCompileError::TranslationNotFound(path_segments.join("::")))?;
 
-                let translation_tokens = map_to_tokens(translation);
+                if let Some((locale, categories)) = translation.missing_plural_categories() {
+                    return Err(MacroCompileError::MissingPluralCategories(
+                        locale.clone(),
+                        path_segments_display,
+                        categories
+                            .iter()
+                            .map(|category| format!("'{category}'"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ));
+                }
+
+                let translation_tokens = translation.to_token_stream();
                 let ident = field.ident();
 
-                let handler = if let Some(fallback_language) = macro_args.fallback_language() {
-                    if let Some(translation) = translation.get(&fallback_language) {
-                        quote! {
-                            .unwrap_or(&#translation)
-                        }
-                    } else {
-                        return Err(MacroCompileError::FallbackNotAvailable);
+                let last_fallback = match fallback.last() {
+                    Some(last) => Some(translation.get(last).ok_or_else(|| {
+                        MacroCompileError::FallbackNotAvailable(
+                            last.clone(),
+                            path_segments_display.clone(),
+                        )
+                    })?),
+                    None => None,
+                };
+
+                let handler = if let (Some(last_fallback), Some(_)) =
+                    (last_fallback, macro_args.fallback_language())
+                {
+                    quote! {
+                        .unwrap_or(&#last_fallback)
                     }
                 } else {
                     quote! {
@@ -124,7 +171,7 @@ pub fn context_macro(
 
                 Ok(quote! {
                     #ident: #translation_tokens
-                        .get(&language)
+                        .get_or_fallback(&language, &[#(#fallback),*])
                         #handler
                         .replace_with(&replacements)
                 })
@@ -163,9 +210,11 @@ pub fn context_macro(
 
         impl #struct_ident {
             #struct_pub fn load_translations<K: ToString, V: ToString>(
-                language: translatable::Language,
+                language: impl Into<translatable::Locale>,
                 replacements: &std::collections::HashMap<K, V>
             ) -> #load_ret_ty {
+                let language: translatable::Locale = language.into();
+
                 let replacements = replacements
                     .iter()
                     .map(|(key, value)| (key.to_string(), value.to_string()))
@@ -0,0 +1,55 @@
+//! [`gettext_catalog!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`gettext_catalog!()`]
+//! macro tokens with intrinsics from [`macro_input::gettext`].
+//!
+//! [`gettext_catalog!()`]: crate::gettext_catalog
+//! [`macro_input::gettext`]: super::super::macro_input::gettext
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use translatable_shared::handle_macro_result;
+
+use crate::data::config::load_config;
+use crate::data::formats::gettext::write_catalog;
+use crate::data::translations::load_translations;
+use crate::macro_input::gettext::GettextCatalogArgs;
+
+/// [`gettext_catalog!()`] macro output generation.
+///
+/// Expands into a `&'static str` containing the loaded
+/// translations as a gettext catalog, a `.pot` template
+/// if no target language was provided.
+///
+/// If a path was provided, expands into an expression
+/// writing the catalog to that file instead, read
+/// [`ExportPath::write_tokens`].
+///
+/// **Arguments**
+/// * `input` — The languages and export path obtained from [`macro_input::gettext`].
+///
+/// **Returns**
+/// Generated `TokenStream2` representing the catalog string
+/// or the `std::io::Result<()>` of writing it.
+///
+/// [`ExportPath::write_tokens`]: crate::macro_input::utils::export_path::ExportPath::write_tokens
+/// [`gettext_catalog!()`]: crate::gettext_catalog
+/// [`macro_input::gettext`]: super::super::macro_input::gettext
+pub fn gettext_catalog_macro(input: GettextCatalogArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
+
+    let mut catalog =
+        write_catalog(&translations, input.source(), input.target(), config.gettext_keys())
+            .to_token_stream();
+    if let Some(path) = input.path() {
+        catalog = path.write_tokens(catalog);
+    }
+
+    quote! {
+        {
+            #catalog
+        }
+    }
+}
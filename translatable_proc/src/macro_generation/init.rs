@@ -0,0 +1,81 @@
+//! [`init!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`init!()`] macro tokens,
+//! which declares the translation table shared by
+//! every dynamic path lookup in a crate.
+//!
+//! [`init!()`]: crate::init
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::Ident;
+use translatable_shared::handle_macro_result;
+
+use crate::data::translations::{load_dependencies, load_translations, load_warnings};
+use crate::macro_generation::translation::check_plurals;
+
+/// Shared translation table name.
+///
+/// The name of the `static` declared by [`init!()`]
+/// in the crate root, read [`translations_static`].
+///
+/// [`init!()`]: crate::init
+const TRANSLATIONS_STATIC: &str = "__TRANSLATABLE_TRANSLATIONS";
+
+/// Shared translation table identifier.
+///
+/// The identifier is spanned at the call site, so
+/// `crate::` paths to it resolve to the crate
+/// invoking the macro.
+///
+/// **Returns**
+/// The identifier of the `static` declared by [`init!()`].
+///
+/// [`init!()`]: crate::init
+pub fn translations_static() -> Ident {
+    Ident::new(TRANSLATIONS_STATIC, Span::call_site())
+}
+
+/// [`init!()`] macro output generation.
+///
+/// Expands into a lazily initialized `static` holding every
+/// loaded translation, which [`translation!()`] calls with a
+/// dynamic path reference instead of building the whole
+/// translation tree on each call site.
+///
+/// As any translation may be looked up through the table,
+/// the plural translations of every path are validated
+/// here once, rather than on each dynamic path call site.
+///
+/// **Returns**
+/// Generated `TokenStream2` declaring the shared table.
+///
+/// [`init!()`]: crate::init
+/// [`translation!()`]: crate::translation
+pub fn init_macro() -> TokenStream2 {
+    let translations = handle_macro_result!(out load_translations());
+    let translations = translations.as_ref();
+    let translations_static = translations_static();
+
+    let mut objects = translations.objects();
+    objects.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (path_display, translation_object) in objects {
+        handle_macro_result!(out check_plurals(translation_object, &path_display));
+    }
+
+    let warnings = load_warnings();
+    let dependencies = load_dependencies();
+
+    quote! {
+        #warnings
+        #dependencies
+
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        static #translations_static: std::sync::LazyLock<
+            translatable::shared::translations::collection::TranslationNodeCollection
+        > = std::sync::LazyLock::new(|| #translations);
+    }
+}
@@ -0,0 +1,82 @@
+//! [`available_languages!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`available_languages!()`]
+//! macro tokens with intrinsics from [`macro_input::languages`].
+//!
+//! [`available_languages!()`]: crate::available_languages
+//! [`macro_input::languages`]: super::super::macro_input::languages
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use thiserror::Error;
+use translatable_shared::handle_macro_result;
+use translatable_shared::misc::locale::Locale;
+
+use crate::data::translations::load_translations;
+use crate::macro_input::languages::AvailableLanguagesArgs;
+
+/// Macro compile-time language listing error.
+///
+/// Represents errors that can occur while compiling the
+/// [`available_languages!()`] macro.
+///
+/// [`available_languages!()`]: crate::available_languages
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested translation path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    #[error("The path '{0}' could not be found")]
+    PathNotFound(String),
+}
+
+/// [`available_languages!()`] macro output generation.
+///
+/// Expands into a `&'static [Locale]` containing the locales
+/// found in the translation files, or the locales available
+/// for a specific translation path if one was provided.
+///
+/// The locales are sorted by their BCP 47 representation
+/// so the output is deterministic.
+///
+/// **Arguments**
+/// * `input` — The optional translation path obtained from
+///   [`macro_input::languages`].
+///
+/// **Returns**
+/// Generated `TokenStream2` representing the locale slice.
+///
+/// [`available_languages!()`]: crate::available_languages
+/// [`macro_input::languages`]: super::super::macro_input::languages
+pub fn available_languages_macro(input: AvailableLanguagesArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+
+    let mut locales = match input.path() {
+        Some(path) => {
+            let path_segments = path.segments();
+
+            handle_macro_result!(
+                translations
+                    .find_path(path_segments)
+                    .ok_or_else(|| MacroCompileError::PathNotFound(path_segments.join("::")))
+            )
+            .locales()
+            .collect::<Vec<&Locale>>()
+        },
+
+        None => translations
+            .locales()
+            .into_iter()
+            .collect(),
+    };
+
+    locales.sort_by_key(|locale| locale.to_string());
+    quote! {
+        {
+            const LANGUAGES: &[translatable::shared::misc::locale::Locale] = &[#(#locales),*];
+            LANGUAGES
+        }
+    }
+}
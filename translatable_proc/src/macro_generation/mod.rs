@@ -11,4 +11,8 @@
 //! [`macro_input`]: crate::macro_input
 
 pub mod context;
+pub mod gettext;
+pub mod init;
+pub mod languages;
 pub mod translation;
+pub mod xliff;
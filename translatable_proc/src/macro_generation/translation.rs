@@ -7,14 +7,24 @@
 //! [`translation!()`]: crate::translation
 //! [`macro_input::translation`]: super::super::macro_input::translation
 
-use proc_macro2::TokenStream as TokenStream2;
+use std::collections::{BTreeSet, HashMap};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
+use syn::{Error as SynError, Ident};
 use thiserror::Error;
 use translatable_shared::handle_macro_result;
-use translatable_shared::macros::collections::{map_to_tokens, map_transform_to_tokens};
-use translatable_shared::misc::language::Language;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::format_spec::FormatType;
+use translatable_shared::misc::locale::Locale;
+use translatable_shared::misc::plural::PluralTranslation;
+use translatable_shared::misc::templating::ArgumentError;
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::{Translation, TranslationObject};
 
+use crate::data::config::{MacroConfig, RuntimeValidation, ValidationLevel, load_config};
 use crate::data::translations::load_translations;
+use crate::macro_generation::init::translations_static;
 use crate::macro_input::translation::TranslationMacroArgs;
 use crate::macro_input::utils::input_type::InputType;
 
@@ -29,7 +39,7 @@ use crate::macro_input::utils::input_type::InputType;
 ///
 /// [`translation!()`]: crate::translation
 #[derive(Error, Debug)]
-enum MacroCompileError {
+pub enum MacroCompileError {
     /// The requested translation path could not be found.
     ///
     /// **Parameters**
@@ -41,10 +51,106 @@ enum MacroCompileError {
     /// path.
     ///
     /// **Parameters**
-    /// * `0` — The requested `Language`.
+    /// * `0` — The requested `Locale`.
+    /// * `1` — The translation path where the language was expected.
+    #[error("The language '{0}' is not available for the path '{1}'")]
+    LanguageNotAvailable(Locale, String),
+
+    /// The last language of the fallback chain is not available
+    /// for the provided translation path.
+    ///
+    /// **Parameters**
+    /// * `0` — The last `Locale` of the fallback chain.
     /// * `1` — The translation path where the language was expected.
-    #[error("The language '{0:?}' ('{0:#}') is not available for the path '{1}'")]
-    LanguageNotAvailable(Language, String),
+    #[error(
+        "The fallback chain ends in '{0}' which is not available for the path '{1}', every \
+         static path must have the last fallback language"
+    )]
+    FallbackNotAvailable(Locale, String),
+
+    /// A plural translation doesn't define all the categories
+    /// its language plural rule requires.
+    ///
+    /// **Parameters**
+    /// * `0` — The `Locale` of the plural translation.
+    /// * `1` — The translation path, displayed in `::` notation.
+    /// * `2` — The missing categories, comma separated.
+    #[error(
+        "The plural translation for '{0}' in '{1}' is missing the categories required by its \
+         plural rule: {2}"
+    )]
+    MissingPluralCategories(Locale, String, String),
+
+    /// A static path has plural translations but no
+    /// `count` argument was provided to select them.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    #[error(
+        "The path '{0}' has plural translations, a 'count' argument is required to select them"
+    )]
+    PluralCountMissing(String),
+
+    /// The arguments don't match the placeholders of
+    /// a translation of a static path.
+    ///
+    /// **Parameters**
+    /// * `0` — The mismatch found.
+    /// * `1` — The `Locale` of the translation.
+    /// * `2` — The translation path, displayed in `::` notation.
+    #[error("{0:#} in the '{1}' translation for the path '{2}'")]
+    InvalidArgument(ArgumentError, Locale, String),
+
+    /// A [`translation_str!()`] parameter is not static.
+    ///
+    /// [`translation_str!()`]: crate::translation_str
+    #[error(
+        "translation_str!() only takes a language literal and a static path, use translation!() \
+         for dynamic parameters"
+    )]
+    LiteralNotStatic,
+
+    /// [`translation_str!()`] was called with arguments.
+    ///
+    /// [`translation_str!()`]: crate::translation_str
+    #[error(
+        "translation_str!() doesn't take arguments, use translation!() for translations with \
+         templates"
+    )]
+    LiteralArguments,
+
+    /// The translation resolved by [`translation_str!()`]
+    /// has templates or plural variants.
+    ///
+    /// **Parameters**
+    /// * `0` — The `Locale` the translation was resolved to, which may be
+    ///   a less specific or fallback locale than the requested one.
+    /// * `1` — The translation path, displayed in `::` notation.
+    ///
+    /// [`translation_str!()`]: crate::translation_str
+    #[error(
+        "The '{0}' translation for the path '{1}' has templates or plural variants, use \
+         translation!() to render it"
+    )]
+    LiteralNotTemplateFree(Locale, String),
+}
+
+/// Translation macro output.
+///
+/// Both [`translation!()`] and [`translation_display!()`] resolve
+/// translations the same way, this decides what they evaluate to.
+///
+/// [`translation!()`]: crate::translation
+/// [`translation_display!()`]: crate::translation_display
+#[derive(Clone, Copy, PartialEq)]
+pub enum TranslationOutput {
+    /// The translation is rendered to a [`String`].
+    Rendered,
+
+    /// The translation is rendered each time it's displayed,
+    /// the value is a `LazyTranslation` which owns the
+    /// arguments.
+    Lazy,
 }
 
 /// [`translation!()`] macro output generation.
@@ -54,15 +160,21 @@ enum MacroCompileError {
 /// if applicable.
 ///
 /// If the language and path are fully static, the translation will be resolved
-/// during macro expansion. Otherwise, the generated code will include runtime
-/// resolution logic.
+/// during macro expansion and its template compiled into code that renders it,
+/// including select arguments and plural variants. Otherwise, the generated code
+/// will include runtime resolution logic.
 ///
 /// If the path or language is invalid at compile time, an appropriate
 /// `MacroCompileError` will be reported.
 ///
+/// The requested language is looked up first, and then the fallback
+/// chain, either the one specified in the invocation or the configured
+/// one. If the path is static, the chain is validated in compile time.
+///
 /// **Arguments**
 /// * `input` — Structured arguments defining the translation path, language,
-/// and any placeholder replacements obtained from [`macro_input::translation`].
+///   and any placeholder replacements obtained from [`macro_input::translation`].
+/// * `output` — Whether the translation is rendered or displayed lazily.
 ///
 /// **Returns**
 /// Generated `TokenStream2` representing the resolved translation string or
@@ -70,94 +182,808 @@ enum MacroCompileError {
 ///
 /// [`macro_input::translation`]: super::super::macro_input::translation
 /// [`translation!()`]: crate::translation
-pub fn translation_macro(input: TranslationMacroArgs) -> TokenStream2 {
+pub fn translation_macro(input: TranslationMacroArgs, output: TranslationOutput) -> TokenStream2 {
     let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
 
-    let template_replacements = map_transform_to_tokens(
-        input.replacements(),
-        |key, value| quote! { (stringify!(#key).to_string(), #value.to_string()) },
-    );
+    let fallback = input
+        .fallback()
+        .unwrap_or(config.fallback());
 
-    if let InputType::Static(language) = input.language() {
-        if let InputType::Static(path) = input.path() {
-            let path_segments = path.segments();
-            let static_path_display = path_segments.join("::");
+    if let InputType::Static(path) = input.path() {
+        let path_segments = path.segments();
+        let static_path_display = path_segments.join("::");
 
-            let translation_object = translations
+        let translation_object = handle_macro_result!(
+            translations
                 .find_path(path_segments)
-                .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()));
-
-            let translation = handle_macro_result!(
-                handle_macro_result!(translation_object)
-                    .get(language)
-                    .ok_or_else(|| {
-                        MacroCompileError::LanguageNotAvailable(
-                            language.clone(),
-                            static_path_display.clone(),
-                        )
-                    })
+                .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()))
+        );
+
+        handle_macro_result!(check_fallback(translation_object, fallback, &static_path_display));
+        handle_macro_result!(check_plurals(translation_object, &static_path_display));
+        handle_macro_result!(check_count(&input, translation_object, &static_path_display));
+
+        let InputType::Dynamic(language) = input.language() else {
+            return static_translation(
+                &input,
+                output,
+                &config,
+                translation_object,
+                fallback,
+                &static_path_display,
             );
+        };
 
-            return quote! {
-                #translation
-                    .replace_with(&#template_replacements)
-            };
-        }
+        let (bindings, values) = replacement_bindings(&input, output);
+
+        let mut object_translations = translation_object
+            .translations()
+            .collect::<Vec<_>>();
+        object_translations.sort_by_key(|(locale, _)| locale.to_string());
+
+        let warnings = match report_arguments(
+            check_arguments(&input, object_translations.clone(), &static_path_display),
+            config.argument_validation(),
+        ) {
+            Ok(warnings) => warnings,
+            Err(error) => return error_block(error),
+        };
+
+        // the available locales are a constant table, each
+        // index renders the translation of that locale.
+        let locales = object_translations
+            .iter()
+            .map(|(locale, _)| locale);
+
+        let arms = object_translations
+            .iter()
+            .enumerate()
+            .map(|(index, (_, translation))| {
+                let rendered = match output {
+                    TranslationOutput::Rendered => translation.render_tokens(&values),
+                    TranslationOutput::Lazy => translation.write_tokens(&values),
+                };
+
+                quote! { #index => #rendered, }
+            });
+
+        let selected = quote! {
+            match index {
+                #(#arms)*
+                _ => std::unreachable!(),
+            }
+        };
+
+        let value = match output {
+            TranslationOutput::Rendered => selected,
+            TranslationOutput::Lazy => quote! {
+                translatable::shared::translations::node::LazyTranslation::new(
+                    move |formatter| #selected
+                )
+            },
+        };
+
+        let language = runtime_locale(language);
+
+        return quote! {
+            (|| -> Result<_, translatable::Error> {
+                #warnings
+
+                #[doc(hidden)]
+                const LOCALES: &[translatable::shared::misc::locale::Locale] = &[#(#locales),*];
+
+                #[doc(hidden)]
+                const FALLBACK: &[translatable::shared::misc::locale::Locale] = &[#(#fallback),*];
+
+                #[doc(hidden)]
+                let language = #language;
+
+                #[doc(hidden)]
+                let index = language
+                    .lookup(LOCALES, FALLBACK)
+                    .ok_or_else(|| translatable::Error::LanguageNotAvailable(
+                        language.clone(),
+                        #static_path_display.to_string()
+                    ))?;
+
+                #bindings
+
+                std::result::Result::Ok(#value)
+            })()
+        };
     }
 
+    let InputType::Dynamic(path) = input.path() else {
+        unreachable!("static paths are handled above");
+    };
+
     let language = match input.language() {
         InputType::Static(language) => language
             .clone()
             .to_token_stream(),
-        InputType::Dynamic(language) => quote! {
-            translatable::shared::misc::language::Language::from(#language)
-        },
+        InputType::Dynamic(language) => runtime_locale(language),
     };
 
-    let translation_object = match input.path() {
-        InputType::Static(path) => {
-            let path_segments = path.segments();
-            let static_path_display = path_segments.join("::");
+    let mut runtime_check = TokenStream2::new();
+    if config.runtime_validation() == RuntimeValidation::Strict {
+        let argument_keys = sorted_argument_keys(&input);
 
-            let translation_object = translations
-                .find_path(path_segments)
-                .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()));
+        runtime_check = quote! {
+            if let std::option::Option::Some(error) = translation
+                .check_arguments(&[#(#argument_keys),*])
+                .into_iter()
+                .next()
+            {
+                return std::result::Result::Err(
+                    translatable::Error::InvalidArgument(error, language, path.join("::"))
+                );
+            }
+        };
+    }
 
-            let translations_tokens = map_to_tokens(handle_macro_result!(translation_object));
+    let translations_static = translations_static();
+
+    let translation_lookup = quote! {
+        #[doc(hidden)]
+        let path: Vec<_> = #path;
+
+        #[doc(hidden)]
+        let translations = &*crate::#translations_static;
+
+        #[doc(hidden)]
+        let translation_object = translations
+            .find_path(&path)
+            .ok_or_else(|| translatable::Error::PathNotFound(path.join("::")))?;
+    };
+
+    let (bindings, values) = replacement_bindings(&input, output);
+    let (imports, arguments) = format_arguments(&translations, values);
+
+    let format_check = quote! {
+        if let std::option::Option::Some(error) = translation.check_formats(#arguments) {
+            return std::result::Result::Err(
+                translatable::Error::InvalidArgument(error, language, path.join("::"))
+            );
+        }
+    };
+
+    let value = match output {
+        TranslationOutput::Rendered => quote! {
+            {
+                #imports
+                #bindings
+                #format_check
 
-            quote! {
                 #[doc(hidden)]
-                let path: Vec<_> = vec![#(#path_segments.to_string()),*];
+                let mut output = String::new();
 
-                #translations_tokens
+                // writing to a string can't fail, and plural
+                // translations always have an `other` variant.
+                let _ = translation.write_to(&mut output, #arguments);
+
+                output
+            }
+        },
+
+        TranslationOutput::Lazy => quote! {
+            {
+                #imports
+                #bindings
+                #format_check
+
+                translatable::shared::translations::node::LazyTranslation::new(
+                    move |formatter| translation.write_to(formatter, #arguments)
+                )
             }
         },
+    };
+
+    quote! {
+        (|| -> Result<_, translatable::Error> {
+            #[doc(hidden)]
+            let language = #language;
+
+            #translation_lookup
+
+            #[doc(hidden)]
+            let translation = translation_object
+                .get_or_fallback(&language, &[#(#fallback),*])
+                .ok_or_else(|| translatable::Error::LanguageNotAvailable(language.clone(), path.join("::")))?;
+
+            #runtime_check
+
+            std::result::Result::Ok(#value)
+        })()
+    }
+}
+
+/// [`translation_str!()`] macro output generation.
+///
+/// Resolves a translation with a static language and path
+/// in compile time and expands into its text as a string
+/// literal, so it can be used in `const` and `static`
+/// items without any allocation.
+///
+/// Only template-free singular translations can be
+/// expanded, any other translation is reported as a
+/// `MacroCompileError`, as are dynamic parameters and
+/// arguments.
+///
+/// **Arguments**
+/// * `input` — The macro arguments obtained from [`macro_input::translation`].
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `&'static str`.
+///
+/// [`translation_str!()`]: crate::translation_str
+/// [`macro_input::translation`]: super::super::macro_input::translation
+pub fn translation_str_macro(input: TranslationMacroArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
+
+    let fallback = input
+        .fallback()
+        .unwrap_or(config.fallback());
+
+    let language = match input.language() {
+        InputType::Static(language) => language,
+        InputType::Dynamic(language) => {
+            return error_block(MacroCompileError::LiteralNotStatic.to_syn_error(language));
+        },
+    };
 
+    let path = match input.path() {
+        InputType::Static(path) => path,
         InputType::Dynamic(path) => {
-            let translations_tokens = translations.to_token_stream();
+            return error_block(MacroCompileError::LiteralNotStatic.to_syn_error(path));
+        },
+    };
+
+    if let Some(key) = input
+        .replacements()
+        .keys()
+        .min_by_key(|key| key.to_string())
+    {
+        return error_block(MacroCompileError::LiteralArguments.to_syn_error(key));
+    }
+
+    let path_segments = path.segments();
+    let static_path_display = path_segments.join("::");
+
+    let translation_object = handle_macro_result!(
+        translations
+            .find_path(path_segments)
+            .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()))
+    );
+
+    handle_macro_result!(check_fallback(translation_object, fallback, &static_path_display));
+
+    let (locale, translation) = handle_macro_result!(
+        translation_object
+            .resolve_or_fallback(language, fallback)
+            .ok_or_else(|| {
+                MacroCompileError::LanguageNotAvailable(
+                    language.clone(),
+                    static_path_display.clone(),
+                )
+            })
+    );
+
+    let literal = handle_macro_result!(
+        translation
+            .literal()
+            .ok_or_else(|| {
+                MacroCompileError::LiteralNotTemplateFree(
+                    locale.clone(),
+                    static_path_display.clone(),
+                )
+            })
+    );
+
+    quote! {
+        {
+            #literal
+        }
+    }
+}
+
+/// Replacement bindings.
+///
+/// The replacements are evaluated once and borrowed
+/// by hygienic variables, so the rendering code
+/// generated by [`Translation::render_tokens`] may use
+/// them more than once, with their own type so format
+/// specifiers apply to them as in `format!`.
+///
+/// Lazy translations bind the values as they are, so
+/// they are moved into the translation and only rendered
+/// when it's displayed.
+///
+/// **Arguments**
+/// * `input` — The macro arguments to take the replacements from.
+/// * `output` — Whether the translation is rendered or displayed lazily.
+///
+/// **Returns**
+/// The statement declaring the bindings, along with the
+/// binding expression of each replacement key.
+fn replacement_bindings(
+    input: &TranslationMacroArgs,
+    output: TranslationOutput,
+) -> (TokenStream2, HashMap<String, TokenStream2>) {
+    let (bindings, values): (Vec<_>, Vec<_>) = input
+        .replacements()
+        .iter()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            let binding = Ident::new(&format!("replacement_{i}"), Span::mixed_site());
+            ((key.to_string(), binding), value)
+        })
+        .unzip();
+
+    let binding_idents = bindings
+        .iter()
+        .map(|(_, binding)| binding);
+
+    let statement = match output {
+        TranslationOutput::Rendered => quote! {
+            let (#(#binding_idents,)*) = (#(&(#values),)*);
+        },
+        TranslationOutput::Lazy => quote! {
+            let (#(#binding_idents,)*) = (#(#values,)*);
+        },
+    };
+
+    (
+        statement,
+        bindings
+            .into_iter()
+            .map(|(key, binding)| (key, binding.to_token_stream()))
+            .collect(),
+    )
+}
+
+/// Runtime language conversion.
+///
+/// Any value converting into a `Locale` is accepted as
+/// the language, such as a `Language` or a `Locale`.
+/// Expressions whose type is inferred from their usage,
+/// such as `"es".parse()`, must name the type they
+/// evaluate to, as the conversion doesn't infer it.
+///
+/// **Arguments**
+/// * `language` — The language expression.
+///
+/// **Returns**
+/// An expression evaluating to the requested `Locale`.
+fn runtime_locale(language: &TokenStream2) -> TokenStream2 {
+    quote! {
+        translatable::shared::misc::locale::Locale::from(#language)
+    }
+}
+
+/// Runtime template arguments generation.
+///
+/// Translations resolved at runtime are written with
+/// [`FormatArgument`] values, which erase each value to the
+/// formatting traits its type implements with [`FormatProbe`].
+/// Only the types used for a key by any loaded translation
+/// are probed, and as probing doesn't bound the values, a
+/// type that doesn't implement the trait of the resolved
+/// translation is only reported at runtime.
+///
+/// **Arguments**
+/// * `translations` — Every loaded translation.
+/// * `values` — The binding expression of each replacement key.
+///
+/// **Returns**
+/// The imports the probes need in scope and an expression
+/// evaluating to the arguments slice.
+///
+/// [`FormatArgument`]: translatable_shared::misc::templating::FormatArgument
+/// [`FormatProbe`]: translatable_shared::misc::format_spec::FormatProbe
+fn format_arguments(
+    translations: &TranslationNodeCollection,
+    values: HashMap<String, TokenStream2>,
+) -> (TokenStream2, TokenStream2) {
+    let mut formats = HashMap::<String, BTreeSet<FormatType>>::new();
+    for (_, translation_object) in translations.objects() {
+        for (_, translation) in translation_object.translations() {
+            for (key, kind) in translation.formats() {
+                if kind != FormatType::Display {
+                    formats
+                        .entry(key.to_string())
+                        .or_default()
+                        .insert(kind);
+                }
+            }
+        }
+    }
+
+    let mut values = values
+        .into_iter()
+        .collect::<Vec<_>>();
+    values.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut probed = BTreeSet::new();
+    let arguments = values
+        .iter()
+        .map(|(key, value)| {
+            let probes = formats
+                .get(key)
+                .into_iter()
+                .flatten()
+                .filter_map(|kind| {
+                    probed.insert(*kind);
+
+                    let (_, _, method) = format_probe(*kind)?;
+                    Some(quote! { probe.#method() })
+                })
+                .collect::<Vec<_>>();
+
+            let formats = match probes.is_empty() {
+                true => quote! { [] },
+                false => quote! {
+                    {
+                        // the reference makes the fallback traits apply.
+                        #[doc(hidden)]
+                        let probe = &translatable::shared::misc::format_spec::FormatProbe(&#value);
+                        [#(#probes),*]
+                    }
+                },
+            };
 
             quote! {
-                #[doc(hidden)]
-                let path: Vec<_> = #path;
+                (
+                    #key,
+                    translatable::shared::misc::templating::FormatArgument::new(&#value, &#formats)
+                )
+            }
+        })
+        .collect::<Vec<_>>();
 
-                #translations_tokens
-                    .find_path(&path)
-                    .ok_or_else(|| translatable::Error::PathNotFound(path.join("::")))?
+    let imports = probed
+        .into_iter()
+        .filter_map(format_probe)
+        .map(|(probe, fallback, _)| {
+            quote! {
+                #[allow(unused_imports)]
+                use translatable::shared::misc::format_spec::{#probe as _, #fallback as _};
             }
+        });
+
+    (
+        quote! { #(#imports)* },
+        quote! {
+            &[#(#arguments),*] as &[(&str, translatable::shared::misc::templating::FormatArgument<'_>)]
         },
+    )
+}
+
+/// Formatting trait probe names.
+///
+/// **Arguments**
+/// * `kind` — The format specifier type.
+///
+/// **Returns**
+/// The probe trait, its fallback trait and their method for
+/// the type, `None` for [`FormatType::Display`], which every
+/// argument implements.
+fn format_probe(kind: FormatType) -> Option<(Ident, Ident, Ident)> {
+    let (probe, fallback, method) = match kind {
+        FormatType::Display => return None,
+        FormatType::Debug => ("DebugProbe", "DebugFallback", "debug"),
+        FormatType::LowerHex => ("LowerHexProbe", "LowerHexFallback", "lower_hex"),
+        FormatType::UpperHex => ("UpperHexProbe", "UpperHexFallback", "upper_hex"),
+        FormatType::Octal => ("OctalProbe", "OctalFallback", "octal"),
+        FormatType::Binary => ("BinaryProbe", "BinaryFallback", "binary"),
+        FormatType::LowerExp => ("LowerExpProbe", "LowerExpFallback", "lower_exp"),
+        FormatType::UpperExp => ("UpperExpProbe", "UpperExpFallback", "upper_exp"),
     };
 
-    quote! {
-        (|| -> Result<String, translatable::Error> {
-            std::result::Result::Ok({
-                #[doc(hidden)]
-                let language = #language;
+    let ident = |name| Ident::new(name, Span::call_site());
+    Some((ident(probe), ident(fallback), ident(method)))
+}
+
+/// Static language and path output generation.
+///
+/// The translation is resolved in compile time and its
+/// template compiled into code that renders it, read
+/// [`Translation::render_tokens`].
+///
+/// **Arguments**
+/// * `input` — The macro arguments.
+/// * `output` — Whether the translation is rendered or displayed lazily.
+/// * `config` — The loaded macro configuration.
+/// * `translation_object` — The translation object the path resolved to.
+/// * `fallback` — The effective fallback chain.
+/// * `path_display` — The translation path, displayed in `::` notation.
+///
+/// **Returns**
+/// A block expression evaluating to the rendered [`String`]
+/// or to the lazy translation.
+fn static_translation(
+    input: &TranslationMacroArgs,
+    output: TranslationOutput,
+    config: &MacroConfig,
+    translation_object: &TranslationObject,
+    fallback: &[Locale],
+    path_display: &str,
+) -> TokenStream2 {
+    let InputType::Static(language) = input.language() else {
+        unreachable!("dynamic languages are handled by the caller");
+    };
 
-                #translation_object
-                    .get(&language)
-                    .ok_or_else(|| translatable::Error::LanguageNotAvailable(language, path.join("::")))?
-                    .replace_with(&#template_replacements)
+    let translation = handle_macro_result!(
+        translation_object
+            .get_or_fallback(language, fallback)
+            .ok_or_else(|| {
+                MacroCompileError::LanguageNotAvailable(language.clone(), path_display.to_string())
             })
-        })()
+    );
+
+    let warnings = match report_arguments(
+        check_arguments(input, [(language, translation)], path_display),
+        config.argument_validation(),
+    ) {
+        Ok(warnings) => warnings,
+        Err(error) => return error_block(error),
+    };
+
+    let (bindings, values) = replacement_bindings(input, output);
+
+    let rendered = match output {
+        TranslationOutput::Rendered => translation.render_tokens(&values),
+        TranslationOutput::Lazy => {
+            let written = translation.write_tokens(&values);
+
+            quote! {
+                translatable::shared::translations::node::LazyTranslation::new(
+                    move |formatter| #written
+                )
+            }
+        },
+    };
+
+    quote! {
+        {
+            #warnings
+            #bindings
+            #rendered
+        }
+    }
+}
+
+/// Fallback chain compile-time validation.
+///
+/// When a path is static, the last language of the fallback
+/// chain must be available for it, as that guarantees that
+/// any lookup following the chain finds a translation.
+///
+/// **Arguments**
+/// * `translation_object` — The translation object the path resolved to.
+/// * `fallback` — The effective fallback chain.
+/// * `path_display` — The translation path, displayed in `::` notation.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(())` — The chain is empty or it's last language is available.
+/// * `Err(MacroCompileError::FallbackNotAvailable)` — Otherwise.
+fn check_fallback(
+    translation_object: &TranslationObject,
+    fallback: &[Locale],
+    path_display: &str,
+) -> Result<(), MacroCompileError> {
+    match fallback.last() {
+        Some(last)
+            if translation_object
+                .get(last)
+                .is_none() =>
+        {
+            Err(MacroCompileError::FallbackNotAvailable(last.clone(), path_display.to_string()))
+        },
+
+        _ => Ok(()),
+    }
+}
+
+/// Plural categories compile-time validation.
+///
+/// Every plural translation must define the categories
+/// its language plural rule requires, so every count
+/// has a variant.
+///
+/// Static paths check the object they resolve to, while
+/// the objects dynamic paths may resolve to are checked
+/// once by [`init!()`], which builds their table.
+///
+/// **Arguments**
+/// * `translation_object` — The translation object to check.
+/// * `path_display` — The translation path, displayed in `::` notation.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(())` — All the plural translations define the required categories.
+/// * `Err(MacroCompileError::MissingPluralCategories)` — Otherwise.
+///
+/// [`init!()`]: crate::init
+pub fn check_plurals(
+    translation_object: &TranslationObject,
+    path_display: &str,
+) -> Result<(), MacroCompileError> {
+    match translation_object.missing_plural_categories() {
+        Some((locale, categories)) => Err(MacroCompileError::MissingPluralCategories(
+            locale.clone(),
+            path_display.to_string(),
+            categories
+                .iter()
+                .map(|category| format!("'{category}'"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )),
+
+        None => Ok(()),
+    }
+}
+
+/// Plural selector compile-time validation.
+///
+/// A static path with plural translations requires
+/// the [`PluralTranslation::SELECTOR`] argument, otherwise
+/// the `other` variant would always be selected.
+///
+/// **Arguments**
+/// * `input` — The macro arguments to look for the selector in.
+/// * `translation_object` — The translation object the path resolved to.
+/// * `path_display` — The translation path, displayed in `::` notation.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(())` — There are no plural translations or the selector was provided.
+/// * `Err(MacroCompileError::PluralCountMissing)` — Otherwise.
+fn check_count(
+    input: &TranslationMacroArgs,
+    translation_object: &TranslationObject,
+    path_display: &str,
+) -> Result<(), MacroCompileError> {
+    let has_selector = input
+        .replacements()
+        .keys()
+        .any(|key| key == PluralTranslation::SELECTOR);
+
+    if translation_object.is_plural() && !has_selector {
+        Err(MacroCompileError::PluralCountMissing(path_display.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Provided argument keys.
+///
+/// **Arguments**
+/// * `input` — The macro arguments to take the keys from.
+///
+/// **Returns**
+/// The argument keys sorted alphabetically, so
+/// the reported mismatches are deterministic.
+fn sorted_argument_keys(input: &TranslationMacroArgs) -> Vec<String> {
+    let mut keys = input
+        .replacements()
+        .keys()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>();
+
+    keys.sort();
+    keys
+}
+
+/// Arguments compile-time validation.
+///
+/// Every provided argument must be used by a placeholder
+/// and every placeholder must have an argument, otherwise
+/// the placeholder would be kept as written.
+///
+/// **Arguments**
+/// * `input` — The macro arguments to validate.
+/// * `translations` — The translations the arguments may be rendered with,
+///   keyed by the locale displayed in the errors.
+/// * `path_display` — The translation path, displayed in `::` notation.
+///
+/// **Returns**
+/// Every mismatch along with the span to report it at, unknown
+/// arguments point to the argument while missing placeholders
+/// point to the invocation.
+fn check_arguments<'a>(
+    input: &TranslationMacroArgs,
+    translations: impl IntoIterator<Item = (&'a Locale, &'a Translation)>,
+    path_display: &str,
+) -> Vec<(MacroCompileError, Span)> {
+    let argument_keys = sorted_argument_keys(input);
+    let arguments = argument_keys
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+
+    translations
+        .into_iter()
+        .flat_map(|(locale, translation)| {
+            translation
+                .check_arguments(&arguments)
+                .into_iter()
+                .map(move |error| {
+                    let span = match &error {
+                        ArgumentError::Unknown(key) | ArgumentError::UnsupportedFormat(key, _) => {
+                            input
+                                .replacements()
+                                .keys()
+                                .find(|ident| *ident == key)
+                                .map(Ident::span)
+                                .unwrap_or_else(Span::call_site)
+                        },
+                        ArgumentError::Missing(_) => Span::call_site(),
+                    };
+
+                    (
+                        MacroCompileError::InvalidArgument(
+                            error,
+                            locale.clone(),
+                            path_display.to_string(),
+                        ),
+                        span,
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Combined errors output.
+///
+/// A combined [`SynError`] expands to one [`compile_error!`]
+/// per error, so they are wrapped in a block to be valid
+/// in expression context.
+///
+/// **Arguments**
+/// * `error` — The errors to output.
+///
+/// **Returns**
+/// A block containing every [`compile_error!`] invocation.
+fn error_block(error: SynError) -> TokenStream2 {
+    let errors = error.to_compile_error();
+    quote! { { #errors } }
+}
+
+/// Argument mismatches reporting.
+///
+/// **Arguments**
+/// * `errors` — The mismatches obtained from [`check_arguments`].
+/// * `level` — The configured `argument_validation` severity.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(TokenStream2)` — Items emitting a warning for each mismatch, empty if
+///   there are none or the validation is off.
+/// * `Err(SynError)` — Every mismatch combined if the severity is `Error`.
+fn report_arguments(
+    errors: Vec<(MacroCompileError, Span)>,
+    level: ValidationLevel,
+) -> Result<TokenStream2, SynError> {
+    match level {
+        ValidationLevel::Warning => Ok(errors
+            .iter()
+            .map(|(error, span)| error.to_compile_warning(*span))
+            .collect()),
+
+        ValidationLevel::Off => Ok(TokenStream2::new()),
+
+        ValidationLevel::Error => match errors
+            .into_iter()
+            .map(|(error, span)| SynError::new(span, error.to_string()))
+            .reduce(|mut combined, error| {
+                combined.combine(error);
+                combined
+            }) {
+            Some(error) => Err(error),
+            None => Ok(TokenStream2::new()),
+        },
     }
 }
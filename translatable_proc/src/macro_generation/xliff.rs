@@ -0,0 +1,52 @@
+//! [`xliff_document!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`xliff_document!()`]
+//! macro tokens with intrinsics from [`macro_input::xliff`].
+//!
+//! [`xliff_document!()`]: crate::xliff_document
+//! [`macro_input::xliff`]: super::super::macro_input::xliff
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use translatable_shared::handle_macro_result;
+
+use crate::data::formats::xliff::write_document;
+use crate::data::translations::load_translations;
+use crate::macro_input::xliff::XliffDocumentArgs;
+
+/// [`xliff_document!()`] macro output generation.
+///
+/// Expands into a `&'static str` containing the loaded
+/// translations as an XLIFF 2.0 document from the source
+/// to the target language.
+///
+/// If a path was provided, expands into an expression
+/// writing the document to that file instead, read
+/// [`ExportPath::write_tokens`].
+///
+/// **Arguments**
+/// * `input` — The languages and export path obtained from [`macro_input::xliff`].
+///
+/// **Returns**
+/// Generated `TokenStream2` representing the document string
+/// or the `std::io::Result<()>` of writing it.
+///
+/// [`ExportPath::write_tokens`]: crate::macro_input::utils::export_path::ExportPath::write_tokens
+/// [`xliff_document!()`]: crate::xliff_document
+/// [`macro_input::xliff`]: super::super::macro_input::xliff
+pub fn xliff_document_macro(input: XliffDocumentArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+
+    let mut document =
+        write_document(&translations, input.source(), input.target()).to_token_stream();
+    if let Some(path) = input.path() {
+        document = path.write_tokens(document);
+    }
+
+    quote! {
+        {
+            #document
+        }
+    }
+}
@@ -12,23 +12,12 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::{
-    Error as SynError,
-    Expr,
-    ExprLit,
-    Field,
-    Ident,
-    ItemStruct,
-    Lit,
-    MetaNameValue,
-    Result as SynResult,
-    Token,
-    Type,
-    Visibility,
-    parse2,
+    Error as SynError, Expr, ExprLit, Field, Ident, ItemStruct, Lit, MetaNameValue,
+    Result as SynResult, Token, Type, Visibility, parse2,
 };
 use thiserror::Error;
 use translatable_shared::macros::errors::IntoCompileError;
-use translatable_shared::misc::language::Language;
+use translatable_shared::misc::locale::Locale;
 
 use super::utils::translation_path::TranslationPath;
 
@@ -89,13 +78,17 @@ pub struct ContextMacroArgs {
     /// field paths.
     base_path: TranslationPath,
 
-    /// Context fallback language.
+    /// Context fallback chain.
     ///
-    /// The fallback should be available
+    /// The last fallback should be available
     /// in all the specified paths, removes
     /// the need to handle errors if a language
     /// is not available for a specific translation.
-    fallback_language: Option<Language>,
+    ///
+    /// Specified as a single language literal
+    /// or an array of language literals tried
+    /// in order.
+    fallback_language: Option<Vec<Locale>>,
 }
 
 /// A field inside a translation context struct.
@@ -161,15 +154,15 @@ impl ContextMacroArgs {
         &self.base_path
     }
 
-    /// Fallback language getter.
+    /// Fallback chain getter.
     ///
     /// **Returns**
-    /// A reference o the `fallback_language`.
+    /// A slice of the `fallback_language` chain if it was specified.
     #[inline]
     #[allow(unused)]
-    pub fn fallback_language(&self) -> Option<Language> {
+    pub fn fallback_language(&self) -> Option<&[Locale]> {
         self.fallback_language
-            .clone()
+            .as_deref()
     }
 }
 
@@ -200,20 +193,21 @@ impl Parse for ContextMacroArgs {
                 },
 
                 "fallback_language" => {
-                    if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = kvp.value {
-                        fallback_language = Some(
-                            Language::from_str(
-                                lit.value()
-                                    .as_str(),
-                            )
-                            .map_err(|_| {
-                                MacroArgsError::InvalidLanguageLiteral(lit.value())
-                                    .to_syn_error(lit)
-                            })?,
-                        );
-                    } else {
-                        return Err(MacroArgsError::OnlyLangLiteralAllowed.to_syn_error(kvp.value));
+                    let chain = match kvp.value {
+                        Expr::Array(array) => array
+                            .elems
+                            .into_iter()
+                            .map(parse_language_literal)
+                            .collect::<SynResult<Vec<_>>>()?,
+
+                        other => vec![parse_language_literal(other)?],
+                    };
+
+                    if chain.is_empty() {
+                        return Err(MacroArgsError::OnlyLangLiteralAllowed.to_syn_error(kvp.path));
                     }
+
+                    fallback_language = Some(chain);
                 },
 
                 key => {
@@ -222,12 +216,34 @@ impl Parse for ContextMacroArgs {
             }
         }
 
-        let base_path = base_path.unwrap_or_else(|| TranslationPath::default());
+        let base_path = base_path.unwrap_or_default();
 
         Ok(Self { base_path, fallback_language })
     }
 }
 
+/// Language literal parsing.
+///
+/// Parses a fallback language, which must be a string
+/// literal containing a valid BCP 47 tag.
+///
+/// **Arguments**
+/// * `expr` - The expression to parse.
+///
+/// **Returns**
+/// The parsed [`Locale`] or a spanned error.
+fn parse_language_literal(expr: Expr) -> SynResult<Locale> {
+    if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = expr {
+        Locale::from_str(
+            lit.value()
+                .as_str(),
+        )
+        .map_err(|_| MacroArgsError::InvalidLanguageLiteral(lit.value()).to_syn_error(lit))
+    } else {
+        Err(MacroArgsError::OnlyLangLiteralAllowed.to_syn_error(expr))
+    }
+}
+
 impl ContextMacroField {
     /// Path getter.
     ///
@@ -385,7 +401,7 @@ impl Parse for ContextMacroStruct {
         let fields = structure
             .fields
             .into_iter()
-            .map(|field| ContextMacroField::try_from(field))
+            .map(ContextMacroField::try_from)
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Self { visibility: is_pub, ident, fields })
@@ -0,0 +1,130 @@
+//! [`gettext_catalog!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`gettext_catalog!()`]: crate::gettext_catalog
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Result as SynResult, Token};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::locale::Locale;
+
+use super::utils::export_path::ExportPath;
+
+/// Parse error for [`GettextCatalogArgs`].
+///
+/// Represents errors that can occur while parsing the
+/// [`gettext_catalog!()`] macro input.
+///
+/// [`gettext_catalog!()`]: crate::gettext_catalog
+#[derive(Error, Debug)]
+enum MacroArgsError {
+    /// Invalid BCP 47 language literal.
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid language literal.
+    #[error("Invalid language literal '{0}' is not a valid BCP 47 tag")]
+    InvalidLanguageLiteral(String),
+}
+
+/// [`gettext_catalog!()`] macro input arguments.
+///
+/// The macro takes the source language, which provides
+/// the message ids, optionally the target language,
+/// which provides the translations, and optionally
+/// the path the catalog is written to.
+///
+/// [`gettext_catalog!()`]: crate::gettext_catalog
+pub struct GettextCatalogArgs {
+    /// The language the message ids are taken from.
+    source: Locale,
+
+    /// The translated language, if `None` a `.pot`
+    /// template is generated.
+    target: Option<Locale>,
+
+    /// The file the catalog is written to, if `None`
+    /// the catalog is returned as a string.
+    path: Option<ExportPath>,
+}
+
+/// [`gettext_catalog!()`] macro args parsing implementation.
+///
+/// Expects one or two comma separated language
+/// literals, such as `"en", "es"`, optionally
+/// followed by a `path = <expr>` parameter.
+///
+/// [`gettext_catalog!()`]: crate::gettext_catalog
+impl Parse for GettextCatalogArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let source = parse_language_literal(input)?;
+        let mut separated = input
+            .parse::<Option<Token![,]>>()?
+            .is_some();
+
+        let mut target = None;
+        if separated && input.peek(LitStr) {
+            target = Some(parse_language_literal(input)?);
+            separated = input
+                .parse::<Option<Token![,]>>()?
+                .is_some();
+        }
+
+        let mut path = None;
+        if separated && !input.is_empty() {
+            path = Some(input.parse::<ExportPath>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(Self { source, target, path })
+    }
+}
+
+/// Language literal parsing.
+///
+/// **Arguments**
+/// * `input` - The macro input stream.
+///
+/// **Returns**
+/// The parsed [`Locale`] or a spanned error.
+fn parse_language_literal(input: ParseStream) -> SynResult<Locale> {
+    let literal = input.parse::<LitStr>()?;
+
+    literal
+        .value()
+        .parse::<Locale>()
+        .map_err(|_| MacroArgsError::InvalidLanguageLiteral(literal.value()).to_syn_error(literal))
+}
+
+impl GettextCatalogArgs {
+    /// Source language getter.
+    ///
+    /// **Returns**
+    /// The language the message ids are taken from.
+    #[inline]
+    pub fn source(&self) -> &Locale {
+        &self.source
+    }
+
+    /// Target language getter.
+    ///
+    /// **Returns**
+    /// The translated language, if any.
+    #[inline]
+    pub fn target(&self) -> Option<&Locale> {
+        self.target
+            .as_ref()
+    }
+    /// Export path getter.
+    ///
+    /// **Returns**
+    /// The file the catalog is written to, if any.
+    #[inline]
+    pub fn path(&self) -> Option<&ExportPath> {
+        self.path
+            .as_ref()
+    }
+}
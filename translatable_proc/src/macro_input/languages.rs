@@ -0,0 +1,56 @@
+//! [`available_languages!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`available_languages!()`]: crate::available_languages
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::Result as SynResult;
+use syn::parse::{Parse, ParseStream};
+
+use super::utils::translation_path::TranslationPath;
+
+/// [`available_languages!()`] macro input arguments.
+///
+/// The macro optionally takes a translation path,
+/// in which case only the languages available for
+/// that translation are listed.
+///
+/// [`available_languages!()`]: crate::available_languages
+pub struct AvailableLanguagesArgs {
+    /// The optional translation path to list the
+    /// languages for, otherwise all the languages
+    /// found in the translation files are listed.
+    path: Option<TranslationPath>,
+}
+
+/// [`available_languages!()`] macro args parsing implementation.
+///
+/// An empty input lists every language, otherwise
+/// a translation path such as `greetings::formal`
+/// is expected.
+///
+/// [`available_languages!()`]: crate::available_languages
+impl Parse for AvailableLanguagesArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let path = match input.is_empty() {
+            true => None,
+            false => Some(input.parse::<TranslationPath>()?),
+        };
+
+        Ok(Self { path })
+    }
+}
+
+impl AvailableLanguagesArgs {
+    /// Translation path getter.
+    ///
+    /// **Returns**
+    /// The translation path to list the languages for, if any.
+    #[inline]
+    pub fn path(&self) -> Option<&TranslationPath> {
+        self.path
+            .as_ref()
+    }
+}
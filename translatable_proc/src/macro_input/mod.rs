@@ -11,5 +11,8 @@
 //! [`macro_generation`]: crate::macro_generation
 
 pub mod context;
+pub mod gettext;
+pub mod languages;
 pub mod translation;
 pub mod utils;
+pub mod xliff;
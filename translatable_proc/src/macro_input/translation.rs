@@ -15,7 +15,7 @@ use syn::token::Static;
 use syn::{Expr, ExprLit, Ident, Lit, Result as SynResult, Token};
 use thiserror::Error;
 use translatable_shared::macros::errors::IntoCompileError;
-use translatable_shared::misc::language::Language;
+use translatable_shared::misc::locale::Locale;
 
 use super::utils::input_type::InputType;
 use super::utils::translation_path::TranslationPath;
@@ -33,6 +33,19 @@ enum MacroArgsError {
     /// was found.
     #[error("The literal '{0}' is an invalid ISO 639-1 string, and cannot be parsed")]
     InvalidIsoLiteral(String),
+
+    /// A fallback chain was specified without any
+    /// language.
+    #[error("A language chain must contain at least the requested language")]
+    EmptyLanguageChain,
+
+    /// A fallback language in the chain was not
+    /// a string literal.
+    ///
+    /// Fallback languages must be known at compile
+    /// time so their availability can be checked.
+    #[error("Only language literals are allowed as fallback languages")]
+    OnlyLangLiteralAllowed,
 }
 
 /// [`translation!()`] macro input arguments.
@@ -48,10 +61,17 @@ enum MacroArgsError {
 pub struct TranslationMacroArgs {
     /// Represents the user specified language
     /// which may be static if the specified language
-    /// is a string literal or a `Language` enum tagged
-    /// union instance, otherwise dynamic and represented
-    /// as a `TokenStream`.
-    language: InputType<Language>,
+    /// is a BCP 47 string literal, otherwise dynamic
+    /// and represented as a `TokenStream` that resolves
+    /// to anything convertible to a `Locale`.
+    language: InputType<Locale>,
+
+    /// Per invocation fallback chain.
+    ///
+    /// If the language is specified as an array such as
+    /// `[language, "es", "en"]` the elements after the first
+    /// one override the configured fallback chain.
+    fallback: Option<Vec<Locale>>,
 
     /// Represents a toml path to find the translation
     /// object in the previously parsed TOML from the
@@ -75,22 +95,33 @@ pub struct TranslationMacroArgs {
 /// with the [`parse_macro_input`] macro.
 impl Parse for TranslationMacroArgs {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let parsed_language_arg =
-            match input.parse::<Expr>()? {
-                Expr::Lit(ExprLit { lit: Lit::Str(literal), .. }) => {
-                    match literal
-                        .value()
-                        .parse::<Language>()
-                    {
-                        Ok(language) => InputType::Static(language),
-
-                        Err(_) => Err(MacroArgsError::InvalidIsoLiteral(literal.value())
-                            .to_syn_error(literal))?,
-                    }
-                },
-
-                other => InputType::Dynamic(other.into_token_stream()),
-            };
+        let (parsed_language_arg, fallback) = match input.parse::<Expr>()? {
+            Expr::Array(array) => {
+                let span = array.to_token_stream();
+                let mut elements = array
+                    .elems
+                    .into_iter();
+
+                let language = parse_language(
+                    elements
+                        .next()
+                        .ok_or_else(|| MacroArgsError::EmptyLanguageChain.to_syn_error(span))?,
+                )?;
+
+                let fallback = elements
+                    .map(|element| match parse_language(element)? {
+                        InputType::Static(language) => Ok(language),
+                        InputType::Dynamic(element) => {
+                            Err(MacroArgsError::OnlyLangLiteralAllowed.to_syn_error(element))
+                        },
+                    })
+                    .collect::<SynResult<Vec<_>>>()?;
+
+                (language, Some(fallback))
+            },
+
+            other => (parse_language(other)?, None),
+        };
 
         input.parse::<Token![,]>()?;
 
@@ -130,23 +161,67 @@ impl Parse for TranslationMacroArgs {
 
         Ok(Self {
             language: parsed_language_arg,
+            fallback,
             path: parsed_path_arg,
             replacements,
         })
     }
 }
 
+/// Language argument parsing.
+///
+/// String literals are parsed as a static [`Locale`]
+/// while any other expression is kept as is to be
+/// converted in runtime.
+///
+/// **Arguments**
+/// * `expr` - The language expression.
+///
+/// **Returns**
+/// The language as an [`InputType<Locale>`] or an error if it's an invalid
+/// literal.
+fn parse_language(expr: Expr) -> SynResult<InputType<Locale>> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(literal), .. }) => {
+            match literal
+                .value()
+                .parse::<Locale>()
+            {
+                Ok(language) => Ok(InputType::Static(language)),
+
+                Err(_) => {
+                    Err(MacroArgsError::InvalidIsoLiteral(literal.value()).to_syn_error(literal))
+                },
+            }
+        },
+
+        other => Ok(InputType::Dynamic(other.into_token_stream())),
+    }
+}
+
 impl TranslationMacroArgs {
     /// `self.language` reference getter.
     ///
     /// **Returns**
-    /// A reference to `self.language` as [`InputType<Language>`].
+    /// A reference to `self.language` as [`InputType<Locale>`].
     #[inline]
     #[allow(unused)]
-    pub fn language(&self) -> &InputType<Language> {
+    pub fn language(&self) -> &InputType<Locale> {
         &self.language
     }
 
+    /// `self.fallback` getter.
+    ///
+    /// **Returns**
+    /// The per invocation fallback chain if the language was specified
+    /// as an array.
+    #[inline]
+    #[allow(unused)]
+    pub fn fallback(&self) -> Option<&[Locale]> {
+        self.fallback
+            .as_deref()
+    }
+
     /// `self.path` reference getter.
     ///
     /// **Returns**
@@ -0,0 +1,91 @@
+//! [`ExportPath`] module.
+//!
+//! This module declares an abstraction to parse
+//! the `path = <expr>` parameter of the export
+//! macros, such as [`gettext_catalog!()`], and to
+//! generate the code writing the exported file.
+//!
+//! [`gettext_catalog!()`]: crate::gettext_catalog
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, Result as SynResult, Token};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+
+/// Parse error for [`ExportPath`].
+///
+/// Represents errors that can occur while parsing
+/// the export path parameter.
+#[derive(Error, Debug)]
+enum ExportPathError {
+    /// Unknown parameter name.
+    ///
+    /// **Parameters**
+    /// * `0` - The parameter name.
+    #[error("Unknown parameter '{0}', only 'path' can be provided after the languages")]
+    UnknownParameter(String),
+}
+
+/// Export file path parser.
+///
+/// Holds the expression the exported file is written to,
+/// which may be any value implementing `AsRef<Path>`, such
+/// as a string literal or a `PathBuf` built from `OUT_DIR`.
+pub struct ExportPath {
+    /// The path expression.
+    path: Expr,
+}
+
+/// [`ExportPath`] macro parsing implementation.
+///
+/// Expects a `path = <expr>` key-value pair.
+impl Parse for ExportPath {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let key = input.parse::<Ident>()?;
+        if key != "path" {
+            return Err(ExportPathError::UnknownParameter(key.to_string()).to_syn_error(key));
+        }
+
+        input.parse::<Token![=]>()?;
+        let path = input.parse::<Expr>()?;
+
+        Ok(Self { path })
+    }
+}
+
+impl ExportPath {
+    /// Export file writing generation.
+    ///
+    /// Relative paths are resolved against the current
+    /// directory, which is the package directory when
+    /// the code runs in a build script. Missing parent
+    /// directories are created.
+    ///
+    /// **Arguments**
+    /// * `contents` — The exported document.
+    ///
+    /// **Returns**
+    /// An expression writing the document to the path,
+    /// evaluating to a `std::io::Result<()>`.
+    pub fn write_tokens(&self, contents: impl ToTokens) -> TokenStream2 {
+        let path = &self.path;
+
+        quote! {
+            (|| -> std::io::Result<()> {
+                #[doc(hidden)]
+                let path = #path;
+
+                #[doc(hidden)]
+                let path = std::convert::AsRef::<std::path::Path>::as_ref(&path);
+
+                if let std::option::Option::Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+
+                std::fs::write(path, #contents)
+            })()
+        }
+    }
+}
@@ -1,2 +1,3 @@
+pub mod export_path;
 pub mod input_type;
 pub mod translation_path;
@@ -94,7 +94,7 @@ impl TranslationPath {
     /// **Arguments**
     /// * `segments` - The segments this path is made of x::y -> vec!["x", "y"].
     /// * `span` - The original location or where this path should return errors
-    /// if it may.
+    ///   if it may.
     ///
     /// **Returns**
     /// A constructed instance of [`TranslationPath`].
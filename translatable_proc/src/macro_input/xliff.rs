@@ -0,0 +1,123 @@
+//! [`xliff_document!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`xliff_document!()`]: crate::xliff_document
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Result as SynResult, Token};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::locale::Locale;
+
+use super::utils::export_path::ExportPath;
+
+/// Parse error for [`XliffDocumentArgs`].
+///
+/// Represents errors that can occur while parsing the
+/// [`xliff_document!()`] macro input.
+///
+/// [`xliff_document!()`]: crate::xliff_document
+#[derive(Error, Debug)]
+enum MacroArgsError {
+    /// Invalid BCP 47 language literal.
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid language literal.
+    #[error("Invalid language literal '{0}' is not a valid BCP 47 tag")]
+    InvalidLanguageLiteral(String),
+}
+
+/// [`xliff_document!()`] macro input arguments.
+///
+/// The macro takes the source language, which provides
+/// the source text, the target language, which provides
+/// the translations, and optionally the path the
+/// document is written to.
+///
+/// [`xliff_document!()`]: crate::xliff_document
+pub struct XliffDocumentArgs {
+    /// The language the source text is taken from.
+    source: Locale,
+
+    /// The translated language.
+    target: Locale,
+
+    /// The file the document is written to, if `None`
+    /// the document is returned as a string.
+    path: Option<ExportPath>,
+}
+
+/// [`xliff_document!()`] macro args parsing implementation.
+///
+/// Expects two comma separated language
+/// literals, such as `"en", "es"`, optionally
+/// followed by a `path = <expr>` parameter.
+///
+/// [`xliff_document!()`]: crate::xliff_document
+impl Parse for XliffDocumentArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let source = parse_language_literal(input)?;
+        input.parse::<Token![,]>()?;
+        let target = parse_language_literal(input)?;
+
+        let mut path = None;
+        if input
+            .parse::<Option<Token![,]>>()?
+            .is_some()
+            && !input.is_empty()
+        {
+            path = Some(input.parse::<ExportPath>()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(Self { source, target, path })
+    }
+}
+
+/// Language literal parsing.
+///
+/// **Arguments**
+/// * `input` - The macro input stream.
+///
+/// **Returns**
+/// The parsed [`Locale`] or a spanned error.
+fn parse_language_literal(input: ParseStream) -> SynResult<Locale> {
+    let literal = input.parse::<LitStr>()?;
+
+    literal
+        .value()
+        .parse::<Locale>()
+        .map_err(|_| MacroArgsError::InvalidLanguageLiteral(literal.value()).to_syn_error(literal))
+}
+
+impl XliffDocumentArgs {
+    /// Source language getter.
+    ///
+    /// **Returns**
+    /// The language the source text is taken from.
+    #[inline]
+    pub fn source(&self) -> &Locale {
+        &self.source
+    }
+
+    /// Target language getter.
+    ///
+    /// **Returns**
+    /// The translated language.
+    #[inline]
+    pub fn target(&self) -> &Locale {
+        &self.target
+    }
+    /// Export path getter.
+    ///
+    /// **Returns**
+    /// The file the document is written to, if any.
+    #[inline]
+    pub fn path(&self) -> Option<&ExportPath> {
+        self.path
+            .as_ref()
+    }
+}
@@ -57,7 +57,7 @@ pub fn map_to_tokens<K: ToTokens, V: ToTokens>(map: &HashMap<K, V>) -> TokenStre
 /// * `map` - The map to convert into tokens.
 /// * `predicate` - A predicate taking a key and a value that should return a
 ///   [`TokenStream2`]
-/// containing a tuple of the key and the value transformed in any way.
+///   containing a tuple of the key and the value transformed in any way.
 ///
 /// **Returns**
 /// The provided `map` parameter mutated with the `predicate` and converted to a
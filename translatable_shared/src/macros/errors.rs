@@ -6,8 +6,8 @@
 
 use std::fmt::Display;
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{ToTokens, quote};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::Error as SynError;
 
 /// Error implementations for macro outputs.
@@ -54,6 +54,35 @@ where
         quote! { fn __() { #invocation } }
     }
 
+    /// Convert error reference to a compile warning.
+    ///
+    /// Procedural macros can't emit warnings in stable
+    /// Rust, so this generates an item using a
+    /// `#[deprecated]` constant whose note is the value
+    /// as a string, which the compiler reports as a
+    /// deprecation warning at the provided span.
+    ///
+    /// The output is an anonymous constant item, so it
+    /// can be placed both in item and statement position.
+    ///
+    /// **Parameters**
+    /// * `span` - The span the warning points to.
+    ///
+    /// **Returns**
+    /// An item that triggers the warning.
+    #[cold]
+    fn to_compile_warning(&self, span: Span) -> TokenStream2 {
+        let message = self.to_string();
+        quote_spanned! { span =>
+            const _: () = {
+                #[deprecated(note = #message)]
+                #[allow(non_upper_case_globals)]
+                const translatable_warning: () = ();
+                translatable_warning
+            };
+        }
+    }
+
     /// Convert error reference to a spanned [`SynError`].
     ///
     /// Transforms the value into a string
@@ -0,0 +1,714 @@
+//! Format specifier module.
+//!
+//! This module declares [`FormatSpec`], the format
+//! specifier a template placeholder may be suffixed
+//! with, such as `{price:.2}`, `{count:>5}` or `{id:#x}`.
+//!
+//! The grammar is the one of the standard `format!`
+//! macro, `[[fill]align][sign]['#']['0'][width]['.' precision][type]`,
+//! except that width and precision must be literal
+//! numbers, as templates have no positional arguments.
+//!
+//! Static translations compile specifiers down to
+//! `format_args!`, while translations resolved at
+//! runtime are written with [`FormatSpec::write_display`]
+//! and the other writing methods, which produce the
+//! same output.
+//!
+//! As the translation of a dynamic path is only known
+//! at runtime, its arguments are erased to the formatting
+//! traits their type implements with [`FormatProbe`],
+//! so an unsupported specifier type is a runtime error
+//! rather than a bound on every argument.
+
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result as FmtResult, UpperExp,
+    UpperHex, Write as FmtWrite,
+};
+use std::str::FromStr;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, TokenStreamExt, quote};
+use thiserror::Error;
+
+/// Format specifier parsing error.
+///
+/// Returned when the specifier of a placeholder
+/// doesn't follow the `format!` grammar.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FormatSpecError {
+    /// Invalid specifier syntax.
+    ///
+    /// **Parameters**
+    /// * `0` - The specifier as written.
+    #[error(
+        "'{0}' is not a valid format specifier, expected \
+         '[[fill]align][sign][#][0][width][.precision][type]' with literal numbers"
+    )]
+    Invalid(String),
+
+    /// Unknown formatting trait.
+    ///
+    /// **Parameters**
+    /// * `0` - The type as written.
+    #[error(
+        "'{0}' is not a supported format type, expected one of '?', 'x', 'X', 'o', 'b', 'e' or 'E'"
+    )]
+    UnknownType(String),
+}
+
+/// Format specifier alignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatAlign {
+    /// `<`, the value is padded on the right.
+    Left,
+
+    /// `^`, the value is padded on both sides.
+    Center,
+
+    /// `>`, the value is padded on the left.
+    Right,
+}
+
+/// Format specifier type.
+///
+/// Decides the formatting trait the value
+/// is written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FormatType {
+    /// No type, [`Display`].
+    Display,
+
+    /// `?`, [`Debug`].
+    Debug,
+
+    /// `x`, [`LowerHex`].
+    LowerHex,
+
+    /// `X`, [`UpperHex`].
+    UpperHex,
+
+    /// `o`, [`Octal`].
+    Octal,
+
+    /// `b`, [`Binary`].
+    Binary,
+
+    /// `e`, [`LowerExp`].
+    LowerExp,
+
+    /// `E`, [`UpperExp`].
+    UpperExp,
+}
+
+/// Formatting trait erased value.
+///
+/// A borrowed value erased to the formatting trait
+/// of a specifier type other than [`Display`], read
+/// [`FormatProbe`].
+#[derive(Clone, Copy)]
+pub enum FormatValue<'a> {
+    /// A [`Debug`] value.
+    Debug(&'a dyn Debug),
+
+    /// A [`LowerHex`] value.
+    LowerHex(&'a dyn LowerHex),
+
+    /// An [`UpperHex`] value.
+    UpperHex(&'a dyn UpperHex),
+
+    /// An [`Octal`] value.
+    Octal(&'a dyn Octal),
+
+    /// A [`Binary`] value.
+    Binary(&'a dyn Binary),
+
+    /// A [`LowerExp`] value.
+    LowerExp(&'a dyn LowerExp),
+
+    /// An [`UpperExp`] value.
+    UpperExp(&'a dyn UpperExp),
+}
+
+/// Formatting trait probe.
+///
+/// Wraps a value to find out which formatting traits
+/// its concrete type implements. Each trait has a probe
+/// trait implemented for [`FormatProbe`] when the value
+/// implements it, and a fallback trait implemented for
+/// any `&FormatProbe`, as method resolution prefers the
+/// former `(&FormatProbe(&value)).lower_hex()` is only
+/// `Some` for [`LowerHex`] values.
+///
+/// This only works where the type of the value is
+/// concrete, such as macro generated code, both traits
+/// must be in scope.
+pub struct FormatProbe<'a, T>(pub &'a T);
+
+/// Placeholder format specifier.
+///
+/// A parsed format specifier, read the module
+/// documentation for the supported syntax.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatSpec {
+    /// The specifier as written, without the leading `:`.
+    original: String,
+
+    /// The padding character.
+    fill: char,
+
+    /// The explicit alignment, if any.
+    align: Option<FormatAlign>,
+
+    /// Whether the `+` flag was specified.
+    sign: bool,
+
+    /// Whether the `#` flag was specified.
+    alternate: bool,
+
+    /// Whether the `0` flag was specified.
+    zero: bool,
+
+    /// The minimum width, if any.
+    width: Option<usize>,
+
+    /// The precision, if any.
+    precision: Option<usize>,
+
+    /// The formatting trait.
+    kind: FormatType,
+}
+
+/// Writes a value with the specifier flags.
+///
+/// The flags must be literal in `format_args!`, so
+/// every combination is spelled out, the width and
+/// precision are passed as arguments and the fill
+/// character is applied by [`FormatSpec::write_padded`].
+macro_rules! write_flags {
+    ($output:expr, $spec:expr, $width:expr, $value:expr, $kind:literal) => {
+        match $spec.align {
+            None => write_flags!(@flags $output, $spec, $width, $value, "", $kind),
+            Some(FormatAlign::Left) => write_flags!(@flags $output, $spec, $width, $value, "<", $kind),
+            Some(FormatAlign::Center) => write_flags!(@flags $output, $spec, $width, $value, "^", $kind),
+            Some(FormatAlign::Right) => write_flags!(@flags $output, $spec, $width, $value, ">", $kind),
+        }
+    };
+
+    (@flags $output:expr, $spec:expr, $width:expr, $value:expr, $align:literal, $kind:literal) => {
+        match ($spec.sign, $spec.alternate, $spec.zero, $spec.precision) {
+            (false, false, false, None) => write!($output, concat!("{:", $align, "w$", $kind, "}"), $value, w = $width),
+            (false, false, false, Some(p)) => write!($output, concat!("{:", $align, "w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (false, false, true, None) => write!($output, concat!("{:", $align, "0w$", $kind, "}"), $value, w = $width),
+            (false, false, true, Some(p)) => write!($output, concat!("{:", $align, "0w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (false, true, false, None) => write!($output, concat!("{:", $align, "#w$", $kind, "}"), $value, w = $width),
+            (false, true, false, Some(p)) => write!($output, concat!("{:", $align, "#w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (false, true, true, None) => write!($output, concat!("{:", $align, "#0w$", $kind, "}"), $value, w = $width),
+            (false, true, true, Some(p)) => write!($output, concat!("{:", $align, "#0w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (true, false, false, None) => write!($output, concat!("{:", $align, "+w$", $kind, "}"), $value, w = $width),
+            (true, false, false, Some(p)) => write!($output, concat!("{:", $align, "+w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (true, false, true, None) => write!($output, concat!("{:", $align, "+0w$", $kind, "}"), $value, w = $width),
+            (true, false, true, Some(p)) => write!($output, concat!("{:", $align, "+0w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (true, true, false, None) => write!($output, concat!("{:", $align, "+#w$", $kind, "}"), $value, w = $width),
+            (true, true, false, Some(p)) => write!($output, concat!("{:", $align, "+#w$.p$", $kind, "}"), $value, w = $width, p = p),
+            (true, true, true, None) => write!($output, concat!("{:", $align, "+#0w$", $kind, "}"), $value, w = $width),
+            (true, true, true, Some(p)) => write!($output, concat!("{:", $align, "+#0w$.p$", $kind, "}"), $value, w = $width, p = p),
+        }
+    };
+}
+
+/// Declares the probe and fallback traits
+/// of each formatting trait, read [`FormatProbe`].
+macro_rules! format_probes {
+    ($($format:ident => $probe:ident, $fallback:ident, $method:ident;)*) => {
+        $(
+            #[doc = concat!("[`", stringify!($format), "`] probe, read [`FormatProbe`].")]
+            pub trait $probe<'a> {
+                #[doc = concat!("Erases the value to [`", stringify!($format), "`].")]
+                fn $method(&self) -> Option<FormatValue<'a>>;
+            }
+
+            impl<'a, T: $format> $probe<'a> for FormatProbe<'a, T> {
+                fn $method(&self) -> Option<FormatValue<'a>> {
+                    Some(FormatValue::$format(self.0))
+                }
+            }
+
+            #[doc = concat!("[`", stringify!($format), "`] probe fallback, read [`FormatProbe`].")]
+            pub trait $fallback<'a> {
+                #[doc = concat!("Values that don't implement [`", stringify!($format), "`].")]
+                fn $method(&self) -> Option<FormatValue<'a>>;
+            }
+
+            impl<'a, T> $fallback<'a> for &FormatProbe<'a, T> {
+                fn $method(&self) -> Option<FormatValue<'a>> {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+format_probes! {
+    Debug => DebugProbe, DebugFallback, debug;
+    LowerHex => LowerHexProbe, LowerHexFallback, lower_hex;
+    UpperHex => UpperHexProbe, UpperHexFallback, upper_hex;
+    Octal => OctalProbe, OctalFallback, octal;
+    Binary => BinaryProbe, BinaryFallback, binary;
+    LowerExp => LowerExpProbe, LowerExpFallback, lower_exp;
+    UpperExp => UpperExpProbe, UpperExpFallback, upper_exp;
+}
+
+impl FormatValue<'_> {
+    /// Formatting trait getter.
+    ///
+    /// **Returns**
+    /// The specifier type the value can be written with.
+    pub fn kind(&self) -> FormatType {
+        match self {
+            Self::Debug(_) => FormatType::Debug,
+            Self::LowerHex(_) => FormatType::LowerHex,
+            Self::UpperHex(_) => FormatType::UpperHex,
+            Self::Octal(_) => FormatType::Octal,
+            Self::Binary(_) => FormatType::Binary,
+            Self::LowerExp(_) => FormatType::LowerExp,
+            Self::UpperExp(_) => FormatType::UpperExp,
+        }
+    }
+}
+
+impl FormatType {
+    /// Type suffix.
+    ///
+    /// **Returns**
+    /// The type as written in a specifier.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Display => "",
+            Self::Debug => "?",
+            Self::LowerHex => "x",
+            Self::UpperHex => "X",
+            Self::Octal => "o",
+            Self::Binary => "b",
+            Self::LowerExp => "e",
+            Self::UpperExp => "E",
+        }
+    }
+}
+
+impl FormatSpec {
+    /// Formatting trait getter.
+    ///
+    /// **Returns**
+    /// The type the value must be written with.
+    pub fn kind(&self) -> FormatType {
+        self.kind
+    }
+
+    /// Format string for the specifier.
+    ///
+    /// **Returns**
+    /// A `format!` string writing a single argument with
+    /// this specifier, such as `{:>5}`.
+    pub fn format_string(&self) -> String {
+        format!("{{:{}}}", self.original)
+    }
+
+    /// Writes a value with its [`Display`] implementation.
+    ///
+    /// This is also how values that are only known
+    /// to implement [`Display`] are written, whatever
+    /// the specifier type is.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_display<T: Display + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, ""))
+    }
+
+    /// Writes a value with its [`Debug`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_debug<T: Debug + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "?"))
+    }
+
+    /// Writes a value with its [`LowerHex`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_lower_hex<T: LowerHex + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "x"))
+    }
+
+    /// Writes a value with its [`UpperHex`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_upper_hex<T: UpperHex + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "X"))
+    }
+
+    /// Writes a value with its [`Octal`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_octal<T: Octal + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "o"))
+    }
+
+    /// Writes a value with its [`Binary`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_binary<T: Binary + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "b"))
+    }
+
+    /// Writes a value with its [`LowerExp`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_lower_exp<T: LowerExp + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "e"))
+    }
+
+    /// Writes a value with its [`UpperExp`] implementation.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_upper_exp<T: UpperExp + ?Sized>(
+        &self,
+        value: &T,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        self.write_padded(output, |output, width| write_flags!(output, self, width, value, "E"))
+    }
+
+    /// Writes an erased value with its formatting trait.
+    ///
+    /// The value is written with the trait it was erased
+    /// to, which is expected to be the specifier type.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to write.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    pub fn write_value(
+        &self,
+        value: &FormatValue<'_>,
+        output: &mut (impl FmtWrite + ?Sized),
+    ) -> FmtResult {
+        match value {
+            FormatValue::Debug(value) => self.write_debug(*value, output),
+            FormatValue::LowerHex(value) => self.write_lower_hex(*value, output),
+            FormatValue::UpperHex(value) => self.write_upper_hex(*value, output),
+            FormatValue::Octal(value) => self.write_octal(*value, output),
+            FormatValue::Binary(value) => self.write_binary(*value, output),
+            FormatValue::LowerExp(value) => self.write_lower_exp(*value, output),
+            FormatValue::UpperExp(value) => self.write_upper_exp(*value, output),
+        }
+    }
+
+    /// Writes a value padded to the specifier width.
+    ///
+    /// The value is written with every flag but the fill
+    /// character, which can't be passed to `format_args!`,
+    /// so when a fill other than spaces is used the padding
+    /// is measured by writing the value with and without width,
+    /// and then the value is written padded with the fill
+    /// character. Values that ignore the width are not padded,
+    /// as in `format!`.
+    ///
+    /// **Parameters**
+    /// * `output` - The writer to write the padded value to.
+    /// * `render` - Writes the value to a writer with the given width.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    fn write_padded(
+        &self,
+        output: &mut (impl FmtWrite + ?Sized),
+        render: impl Fn(&mut dyn FmtWrite, usize) -> FmtResult,
+    ) -> FmtResult {
+        let width = self
+            .width
+            .unwrap_or_default();
+
+        // the `0` flag pads numbers with zeros regardless of the fill.
+        let Some(align) = self
+            .align
+            .filter(|_| self.fill != ' ' && !self.zero)
+        else {
+            return render(&mut &mut *output, width);
+        };
+
+        let mut padded = CharCount(0);
+        render(&mut padded, width)?;
+
+        let mut unpadded = CharCount(0);
+        render(&mut unpadded, 0)?;
+
+        let padding = padded
+            .0
+            .saturating_sub(unpadded.0);
+
+        let (before, after) = match align {
+            FormatAlign::Left => (0, padding),
+            FormatAlign::Center => (padding / 2, padding - padding / 2),
+            FormatAlign::Right => (padding, 0),
+        };
+
+        for _ in 0..before {
+            output.write_char(self.fill)?;
+        }
+
+        render(&mut &mut *output, 0)?;
+
+        for _ in 0..after {
+            output.write_char(self.fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Character counting writer.
+///
+/// Measures the characters a value is written
+/// with, without storing them.
+struct CharCount(usize);
+
+impl FmtWrite for CharCount {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.0 += s
+            .chars()
+            .count();
+
+        Ok(())
+    }
+}
+
+/// Literal number parsing.
+///
+/// **Arguments**
+/// * `chars` - The remaining specifier characters.
+///
+/// **Returns**
+/// The number at the start of the characters, if any.
+fn parse_number(chars: &mut &[char]) -> Option<usize> {
+    let digits = chars
+        .iter()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+
+    if digits == 0 {
+        return None;
+    }
+
+    let number = chars[..digits]
+        .iter()
+        .collect::<String>()
+        .parse()
+        .ok();
+
+    *chars = &chars[digits..];
+    number
+}
+
+/// Format specifier parsing.
+///
+/// Parses the specifier without the leading `:`,
+/// following the `format!` grammar.
+impl FromStr for FormatSpec {
+    type Err = FormatSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FormatSpecError::Invalid(s.to_string());
+        let characters = s
+            .chars()
+            .collect::<Vec<_>>();
+        let mut chars = characters.as_slice();
+
+        let alignment = |c: char| match c {
+            '<' => Some(FormatAlign::Left),
+            '^' => Some(FormatAlign::Center),
+            '>' => Some(FormatAlign::Right),
+            _ => None,
+        };
+
+        let (fill, align) = match chars {
+            [fill, align, ..] if alignment(*align).is_some() => {
+                if matches!(fill, '{' | '}') {
+                    return Err(invalid());
+                }
+
+                chars = &chars[2..];
+                (*fill, alignment(*align))
+            },
+
+            [align, ..] if alignment(*align).is_some() => {
+                chars = &chars[1..];
+                (' ', alignment(*align))
+            },
+
+            _ => (' ', None),
+        };
+
+        let mut flag = |expected: char| match chars.first() {
+            Some(c) if *c == expected => {
+                chars = &chars[1..];
+                true
+            },
+            _ => false,
+        };
+
+        let sign = flag('+');
+        if !sign {
+            // the `-` flag is accepted but unused, as in `format!`.
+            flag('-');
+        }
+        let alternate = flag('#');
+        let zero = flag('0');
+
+        let width = parse_number(&mut chars);
+
+        let precision = match chars.first() {
+            Some('.') => {
+                chars = &chars[1..];
+                Some(parse_number(&mut chars).ok_or_else(invalid)?)
+            },
+            _ => None,
+        };
+
+        let kind = match chars
+            .iter()
+            .collect::<String>()
+            .as_str()
+        {
+            "" => FormatType::Display,
+            "?" => FormatType::Debug,
+            "x" => FormatType::LowerHex,
+            "X" => FormatType::UpperHex,
+            "o" => FormatType::Octal,
+            "b" => FormatType::Binary,
+            "e" => FormatType::LowerExp,
+            "E" => FormatType::UpperExp,
+            other
+                if other
+                    .chars()
+                    .all(char::is_alphabetic) =>
+            {
+                return Err(FormatSpecError::UnknownType(other.to_string()));
+            },
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            original: s.to_string(),
+            fill,
+            align,
+            sign,
+            alternate,
+            zero,
+            width,
+            precision,
+            kind,
+        })
+    }
+}
+
+/// Format specifier display.
+///
+/// Displays the specifier as it was written,
+/// without the leading `:`.
+impl Display for FormatSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.original)
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// Generates the path to the variant.
+impl ToTokens for FormatType {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let variant = match self {
+            Self::Display => quote! { Display },
+            Self::Debug => quote! { Debug },
+            Self::LowerHex => quote! { LowerHex },
+            Self::UpperHex => quote! { UpperHex },
+            Self::Octal => quote! { Octal },
+            Self::Binary => quote! { Binary },
+            Self::LowerExp => quote! { LowerExp },
+            Self::UpperExp => quote! { UpperExp },
+        };
+
+        tokens.append_all(quote! {
+            translatable::shared::misc::format_spec::FormatType::#variant
+        });
+    }
+}
@@ -0,0 +1,349 @@
+//! Gettext plural forms module.
+//!
+//! This module parses and evaluates the `Plural-Forms`
+//! header of gettext catalogs, such as
+//! `nplurals=2; plural=(n != 1);`, whose C expression
+//! selects the index of the plural form for a number.
+//!
+//! The plural forms of each CLDR rule are obtained
+//! with [`PluralRule::plural_forms`].
+//!
+//! [`PluralRule::plural_forms`]: super::plural::PluralRule::plural_forms
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Plural forms parsing error.
+///
+/// Returned when a `Plural-Forms` header
+/// value can't be parsed.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PluralFormsError {
+    /// Missing field error.
+    ///
+    /// **Parameters**
+    /// * `0` - The missing field, `nplurals` or `plural`.
+    #[error("Missing the '{0}' field")]
+    MissingField(&'static str),
+
+    /// Invalid plural count error.
+    ///
+    /// The `nplurals` field must be a positive integer.
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid value.
+    #[error("Invalid plural count '{0}'")]
+    InvalidCount(String),
+
+    /// Invalid plural expression error.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index in the expression where the error was found.
+    #[error("Invalid plural expression at index {0}")]
+    InvalidExpression(usize),
+}
+
+/// Binary operators of a plural expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOperator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+/// Plural expression node.
+///
+/// A subset of C expressions over the
+/// unsigned `n` variable, as gettext defines.
+#[derive(Debug, Clone, PartialEq)]
+enum PluralExpression {
+    /// The `n` variable.
+    N,
+
+    /// A number literal.
+    Number(u64),
+
+    /// A logical negation.
+    Not(Box<PluralExpression>),
+
+    /// A binary operation.
+    Binary(BinaryOperator, Box<PluralExpression>, Box<PluralExpression>),
+
+    /// A `condition ? then : otherwise` expression.
+    Conditional(Box<PluralExpression>, Box<PluralExpression>, Box<PluralExpression>),
+}
+
+/// Gettext plural forms.
+///
+/// Holds the number of plural forms of a catalog
+/// and the expression that selects one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluralForms {
+    /// The `nplurals` field.
+    count: usize,
+
+    /// The parsed `plural` field.
+    expression: PluralExpression,
+}
+
+/// Plural expression parser.
+///
+/// Recursive descent parser following
+/// the C operator precedence.
+struct ExpressionParser<'a> {
+    /// The expression source.
+    source: &'a str,
+
+    /// The current byte index.
+    position: usize,
+}
+
+impl PluralForms {
+    /// Plural form count getter.
+    ///
+    /// **Returns**
+    /// The number of plural forms, the `nplurals` field.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Plural form selection.
+    ///
+    /// Evaluates the expression for a number, as
+    /// gettext does, with unsigned arithmetic.
+    ///
+    /// **Arguments**
+    /// * `n` - The number to select the form for.
+    ///
+    /// **Returns**
+    /// The index of the plural form, which may be out
+    /// of bounds if the expression is inconsistent with
+    /// the form count.
+    pub fn index(&self, n: u64) -> usize {
+        self.expression
+            .evaluate(n) as usize
+    }
+}
+
+impl PluralExpression {
+    /// Expression evaluation.
+    ///
+    /// Expressions have no side effects, so both
+    /// sides of logical operators are evaluated.
+    ///
+    /// **Arguments**
+    /// * `n` - The value of the `n` variable.
+    ///
+    /// **Returns**
+    /// The expression value, comparisons
+    /// and logical operators result in `0` or `1`.
+    fn evaluate(&self, n: u64) -> u64 {
+        use BinaryOperator::*;
+
+        match self {
+            Self::N => n,
+            Self::Number(value) => *value,
+            Self::Not(operand) => (operand.evaluate(n) == 0) as u64,
+
+            Self::Conditional(condition, then, otherwise) => match condition.evaluate(n) != 0 {
+                true => then.evaluate(n),
+                false => otherwise.evaluate(n),
+            },
+
+            Self::Binary(operator, left, right) => {
+                let (left, right) = (left.evaluate(n), right.evaluate(n));
+
+                match operator {
+                    Or => (left != 0 || right != 0) as u64,
+                    And => (left != 0 && right != 0) as u64,
+                    Equal => (left == right) as u64,
+                    NotEqual => (left != right) as u64,
+                    Less => (left < right) as u64,
+                    LessEqual => (left <= right) as u64,
+                    Greater => (left > right) as u64,
+                    GreaterEqual => (left >= right) as u64,
+                    Add => left.wrapping_add(right),
+                    Subtract => left.wrapping_sub(right),
+                    Multiply => left.wrapping_mul(right),
+                    Divide => left
+                        .checked_div(right)
+                        .unwrap_or_default(),
+                    Remainder => left
+                        .checked_rem(right)
+                        .unwrap_or_default(),
+                }
+            },
+        }
+    }
+}
+
+impl ExpressionParser<'_> {
+    /// Conditional expression parsing, the lowest precedence.
+    fn parse_conditional(&mut self) -> Result<PluralExpression, PluralFormsError> {
+        let condition = self.parse_binary(0)?;
+
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+
+        let then = self.parse_conditional()?;
+
+        if !self.eat(":") {
+            return Err(PluralFormsError::InvalidExpression(self.position));
+        }
+
+        let otherwise = self.parse_conditional()?;
+
+        Ok(PluralExpression::Conditional(Box::new(condition), Box::new(then), Box::new(otherwise)))
+    }
+
+    /// Binary expression parsing.
+    ///
+    /// **Arguments**
+    /// * `level` - The precedence level to parse, from `||` to the
+    ///   multiplicative operators.
+    fn parse_binary(&mut self, level: usize) -> Result<PluralExpression, PluralFormsError> {
+        use BinaryOperator::*;
+
+        // longer operators first, so `<=` isn't read as `<`.
+        const LEVELS: &[&[(&str, BinaryOperator)]] = &[
+            &[("||", Or)],
+            &[("&&", And)],
+            &[("==", Equal), ("!=", NotEqual)],
+            &[("<=", LessEqual), (">=", GreaterEqual), ("<", Less), (">", Greater)],
+            &[("+", Add), ("-", Subtract)],
+            &[("*", Multiply), ("/", Divide), ("%", Remainder)],
+        ];
+
+        let Some(operators) = LEVELS.get(level) else {
+            return self.parse_unary();
+        };
+
+        let mut left = self.parse_binary(level + 1)?;
+
+        while let Some(operator) = operators
+            .iter()
+            .find_map(|(token, operator)| {
+                self.eat(token)
+                    .then_some(*operator)
+            })
+        {
+            let right = self.parse_binary(level + 1)?;
+            left = PluralExpression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    /// Unary and primary expression parsing.
+    fn parse_unary(&mut self) -> Result<PluralExpression, PluralFormsError> {
+        if self.eat("!") {
+            return Ok(PluralExpression::Not(Box::new(self.parse_unary()?)));
+        }
+
+        if self.eat("(") {
+            let expression = self.parse_conditional()?;
+
+            return match self.eat(")") {
+                true => Ok(expression),
+                false => Err(PluralFormsError::InvalidExpression(self.position)),
+            };
+        }
+
+        if self.eat("n") {
+            return Ok(PluralExpression::N);
+        }
+
+        let digits = self.source[self.position..]
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(
+                self.source
+                    .len()
+                    - self.position,
+            );
+
+        let number = self.source[self.position..self.position + digits]
+            .parse()
+            .map_err(|_| PluralFormsError::InvalidExpression(self.position))?;
+
+        self.position += digits;
+        Ok(PluralExpression::Number(number))
+    }
+
+    /// Token consumption.
+    ///
+    /// Skips the whitespace before the token.
+    ///
+    /// **Arguments**
+    /// * `token` - The expected token.
+    ///
+    /// **Returns**
+    /// Whether the token was found and consumed.
+    fn eat(&mut self, token: &str) -> bool {
+        self.position = self
+            .source
+            .len()
+            - self.source[self.position..]
+                .trim_start()
+                .len();
+
+        let found = self.source[self.position..].starts_with(token)
+            // `!=` shouldn't be read as a negation.
+            && !(token == "!" && self.source[self.position..].starts_with("!="));
+
+        if found {
+            self.position += token.len();
+        }
+
+        found
+    }
+}
+
+/// `Plural-Forms` header value parsing.
+///
+/// Parses a value such as `nplurals=2; plural=(n != 1);`,
+/// the fields may be in any order.
+impl FromStr for PluralForms {
+    type Err = PluralFormsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let field = |name: &'static str| {
+            s.split(';')
+                .filter_map(|field| field.split_once('='))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| value.trim())
+                .ok_or(PluralFormsError::MissingField(name))
+        };
+
+        let count = field("nplurals")?;
+        let count = count
+            .parse::<usize>()
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(|| PluralFormsError::InvalidCount(count.to_string()))?;
+
+        let mut parser = ExpressionParser { source: field("plural")?, position: 0 };
+        let expression = parser.parse_conditional()?;
+
+        match parser.eat("")
+            && parser.position
+                == parser
+                    .source
+                    .len()
+        {
+            true => Ok(Self { count, expression }),
+            false => Err(PluralFormsError::InvalidExpression(parser.position)),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Glob pattern module.
+//!
+//! This module declares [`GlobPattern`], used to
+//! filter the files in the translations directory
+//! with the `include` and `exclude` configuration.
+//!
+//! Patterns are matched against paths relative to
+//! the translations directory, separated by `/`. A
+//! `*` matches any run of characters in a segment,
+//! a `?` matches a single one and a `**` segment
+//! matches any number of segments. A pattern without
+//! a `/` matches the last segment at any depth, so
+//! `*.md` matches `README.md` and `docs/guide.md`.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Glob pattern parsing error.
+///
+/// Returned when a glob pattern can't be parsed.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum GlobError {
+    /// Empty pattern or pattern segment.
+    ///
+    /// Patterns can't be empty nor contain empty
+    /// segments, such as `locales//*.toml`.
+    #[error("A glob pattern can't be empty nor contain empty segments")]
+    EmptySegment,
+
+    /// Misplaced recursive wildcard.
+    ///
+    /// A `**` must be a whole segment, such
+    /// as `**/*.toml`, not part of one.
+    ///
+    /// **Parameters**
+    /// * `0` - The segment containing the `**`.
+    #[error("'**' must be a whole path segment, found it in '{0}'")]
+    MisplacedRecursive(String),
+}
+
+/// Glob pattern segment.
+#[derive(Debug, Clone, PartialEq)]
+enum GlobSegment {
+    /// A `**` segment, matching any number of segments.
+    Recursive,
+
+    /// A segment which may contain `*` and `?` wildcards.
+    Name(Vec<char>),
+}
+
+/// Glob pattern.
+///
+/// A parsed glob pattern, read the module
+/// documentation for the supported syntax.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobPattern {
+    /// The pattern as written.
+    original: String,
+
+    /// The pattern segments.
+    segments: Vec<GlobSegment>,
+
+    /// Whether the pattern matches the whole path or
+    /// only its last segment, which is the case for
+    /// patterns without a `/`.
+    anchored: bool,
+}
+
+impl GlobPattern {
+    /// Path matching.
+    ///
+    /// **Arguments**
+    /// * `path` - The relative path to match, separated by `/`.
+    ///
+    /// **Returns**
+    /// Whether the pattern matches the path.
+    pub fn matches(&self, path: &str) -> bool {
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+
+        match self.anchored {
+            true => match_segments(&self.segments, &segments),
+            false => segments
+                .last()
+                .is_some_and(|last| match_segments(&self.segments, &[last])),
+        }
+    }
+}
+
+/// Segment list matching.
+///
+/// **Arguments**
+/// * `pattern` - The remaining pattern segments.
+/// * `path` - The remaining path segments.
+///
+/// **Returns**
+/// Whether the pattern segments match the path segments.
+fn match_segments(pattern: &[GlobSegment], path: &[&str]) -> bool {
+    let Some((first, rest)) = pattern.split_first() else {
+        return path.is_empty();
+    };
+
+    match first {
+        GlobSegment::Recursive => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        GlobSegment::Name(name) => path
+            .split_first()
+            .is_some_and(|(segment, path_rest)| {
+                match_name(
+                    name,
+                    &segment
+                        .chars()
+                        .collect::<Vec<_>>(),
+                ) && match_segments(rest, path_rest)
+            }),
+    }
+}
+
+/// Segment name matching.
+///
+/// **Arguments**
+/// * `pattern` - The remaining pattern characters.
+/// * `name` - The remaining segment characters.
+///
+/// **Returns**
+/// Whether the pattern characters match the segment characters.
+fn match_name(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| match_name(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && match_name(rest, &name[1..]),
+        Some((character, rest)) => name
+            .split_first()
+            .is_some_and(|(first, name_rest)| first == character && match_name(rest, name_rest)),
+    }
+}
+
+/// Glob pattern parsing.
+///
+/// Leading `./` and trailing `/` are ignored,
+/// so `./locales/` is the same as `locales`.
+impl FromStr for GlobPattern {
+    type Err = GlobError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s
+            .trim()
+            .trim_start_matches("./")
+            .trim_end_matches('/');
+
+        let segments = trimmed
+            .split('/')
+            .map(|segment| match segment {
+                "" => Err(GlobError::EmptySegment),
+                "**" => Ok(GlobSegment::Recursive),
+                segment if segment.contains("**") => {
+                    Err(GlobError::MisplacedRecursive(segment.to_string()))
+                },
+                segment => Ok(GlobSegment::Name(
+                    segment
+                        .chars()
+                        .collect(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            original: s.to_string(),
+            anchored: trimmed.contains('/'),
+            segments,
+        })
+    }
+}
+
+/// Glob pattern display.
+///
+/// Displays the pattern as it was written.
+impl Display for GlobPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.original)
+    }
+}
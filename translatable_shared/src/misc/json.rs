@@ -0,0 +1,592 @@
+//! JSON document module.
+//!
+//! This module parses JSON documents into a TOML [`Table`]
+//! for the JSON translation format, recording the line and
+//! column of every key so the errors found after parsing
+//! can point to the file, and writes JSON string literals
+//! for the generated documents.
+//!
+//! Duplicated keys and `null` values, which can't be
+//! represented in TOML, are rejected, and the nesting
+//! depth is limited to [`MAX_DEPTH`].
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+use toml_edit::{Array, Item, Table, Value};
+
+/// Maximum nesting depth of objects and arrays.
+pub const MAX_DEPTH: usize = 128;
+
+/// JSON parsing errors.
+///
+/// Every error carries the byte index in the
+/// file where it was found, read [`JsonError::position`].
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum JsonError {
+    /// Unexpected character error.
+    ///
+    /// **Parameters**
+    /// * `0` - What was expected.
+    /// * `1` - The byte index where it was expected.
+    #[error("Expected {0}")]
+    Expected(&'static str, usize),
+
+    /// Invalid string escape error.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the escape sequence.
+    #[error("Invalid escape sequence")]
+    InvalidEscape(usize),
+
+    /// Duplicated key error.
+    ///
+    /// JSON doesn't forbid duplicated keys, but
+    /// one of the values would be silently lost.
+    ///
+    /// **Parameters**
+    /// * `0` - The duplicated key.
+    /// * `1` - The byte index of the second occurrence.
+    #[error("The key '{0}' is defined more than once")]
+    DuplicatedKey(String, usize),
+
+    /// Null value error.
+    ///
+    /// TOML has no null value, so a `null` can't
+    /// be converted to the translation tree.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the value.
+    #[error("'null' is not a valid value")]
+    NullValue(usize),
+
+    /// Nesting depth error.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the object or array exceeding the depth.
+    #[error("The nesting exceeds the maximum depth of {MAX_DEPTH}")]
+    TooDeep(usize),
+}
+
+impl JsonError {
+    /// Error position getter.
+    ///
+    /// **Returns**
+    /// The byte index in the file where the error was found.
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Expected(_, position)
+            | Self::InvalidEscape(position)
+            | Self::DuplicatedKey(_, position)
+            | Self::NullValue(position)
+            | Self::TooDeep(position) => *position,
+        }
+    }
+}
+
+/// JSON document parser.
+///
+/// Builds a TOML [`Table`] from the document, recording
+/// the location of every key so the errors found while
+/// converting the table can point to the file.
+pub struct JsonParser<'a> {
+    /// The document source.
+    source: &'a str,
+
+    /// The current byte index.
+    position: usize,
+
+    /// The current one based line.
+    line: usize,
+
+    /// The byte index where the current line starts.
+    line_start: usize,
+
+    /// The number of objects and arrays the current value is nested in.
+    depth: usize,
+
+    /// The keys leading to the current value.
+    keys: Vec<String>,
+
+    /// The one based line and column of each key path.
+    locations: HashMap<Vec<String>, (usize, usize)>,
+}
+
+impl<'a> JsonParser<'a> {
+    /// Creates a parser at the start of the source.
+    ///
+    /// **Arguments**
+    /// * `source` - The file contents.
+    ///
+    /// **Returns**
+    /// A parser without parsed keys.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+            line: 1,
+            line_start: 0,
+            depth: 0,
+            keys: Vec::new(),
+            locations: HashMap::new(),
+        }
+    }
+
+    /// Current keys getter.
+    ///
+    /// **Returns**
+    /// The keys leading to the value being parsed, which
+    /// lead to the failing value after an error.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Key locations getter.
+    ///
+    /// **Returns**
+    /// The one based line and column of each key path parsed so far.
+    pub fn locations(&self) -> &HashMap<Vec<String>, (usize, usize)> {
+        &self.locations
+    }
+
+    /// Document parsing.
+    ///
+    /// **Returns**
+    /// The top level object as a table.
+    pub fn parse_document(&mut self) -> Result<Table, JsonError> {
+        self.skip_whitespace();
+
+        if self.peek() != Some('{') {
+            return Err(JsonError::Expected("an object", self.position));
+        }
+
+        let table = self.nested(Self::parse_object)?;
+
+        self.skip_whitespace();
+        match self.peek() {
+            None => Ok(table),
+            Some(_) => Err(JsonError::Expected("the end of the file", self.position)),
+        }
+    }
+
+    /// Value parsing.
+    ///
+    /// Objects map to tables and strings map to string values,
+    /// the other values map to their TOML counterparts so they
+    /// are rejected the same way, `null` is rejected as TOML
+    /// has no null value.
+    ///
+    /// **Returns**
+    /// The parsed value as a TOML item.
+    fn parse_value(&mut self) -> Result<Item, JsonError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('{') => Ok(Item::Table(self.nested(Self::parse_object)?)),
+            Some('[') => {
+                self.nested(Self::parse_array)?;
+                Ok(Item::Value(Value::Array(Array::new())))
+            },
+            Some('"') => Ok(Item::Value(Value::from(self.parse_string()?))),
+            Some('t') => self
+                .parse_literal("true")
+                .map(|_| Item::Value(Value::from(true))),
+            Some('f') => self
+                .parse_literal("false")
+                .map(|_| Item::Value(Value::from(false))),
+            Some('n') => {
+                let position = self.position;
+                self.parse_literal("null")?;
+                Err(JsonError::NullValue(position))
+            },
+            Some('-' | '0'..='9') => Ok(Item::Value(Value::from(self.parse_number()?))),
+            _ => Err(JsonError::Expected("a value", self.position)),
+        }
+    }
+
+    /// Nested value parsing.
+    ///
+    /// Keeps track of the nesting depth, so deeply nested
+    /// documents are rejected instead of overflowing the stack.
+    ///
+    /// **Arguments**
+    /// * `parse` - Parses the nested object or array.
+    ///
+    /// **Returns**
+    /// The parsed value or a [`JsonError::TooDeep`] error.
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, JsonError>,
+    ) -> Result<T, JsonError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(JsonError::TooDeep(self.position));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    /// Object parsing.
+    ///
+    /// **Returns**
+    /// The object as a table, preserving the key order.
+    fn parse_object(&mut self) -> Result<Table, JsonError> {
+        self.position += 1;
+        let mut table = Table::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(table);
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            let key_position = self.position;
+            if self.peek() != Some('"') {
+                return Err(JsonError::Expected("a string key", key_position));
+            }
+
+            let key_location = self.location(key_position);
+
+            let key = self.parse_string()?;
+            if table.contains_key(&key) {
+                return Err(JsonError::DuplicatedKey(key, key_position));
+            }
+
+            self.skip_whitespace();
+            if self.peek() != Some(':') {
+                return Err(JsonError::Expected("':'", self.position));
+            }
+            self.position += 1;
+
+            self.keys
+                .push(key.clone());
+            self.locations
+                .insert(
+                    self.keys
+                        .clone(),
+                    key_location,
+                );
+
+            let value = self.parse_value()?;
+            self.keys
+                .pop();
+            table.insert(&key, value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(table);
+                },
+                _ => return Err(JsonError::Expected("',' or '}'", self.position)),
+            }
+        }
+    }
+
+    /// Array parsing.
+    ///
+    /// Arrays are never valid translations, so the
+    /// elements are only parsed to validate the syntax.
+    fn parse_array(&mut self) -> Result<(), JsonError> {
+        self.position += 1;
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(());
+        }
+
+        loop {
+            self.parse_value()?;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(());
+                },
+                _ => return Err(JsonError::Expected("',' or ']'", self.position)),
+            }
+        }
+    }
+
+    /// String parsing.
+    ///
+    /// **Returns**
+    /// The string contents with the escape sequences resolved.
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.position += 1;
+        let mut result = String::new();
+
+        loop {
+            let Some(character) = self.peek() else {
+                return Err(JsonError::Expected("'\"'", self.position));
+            };
+
+            match character {
+                '"' => {
+                    self.position += 1;
+                    return Ok(result);
+                },
+
+                '\\' => {
+                    let escape_position = self.position;
+                    self.position += 1;
+
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.position += 1;
+                            result.push(self.parse_unicode_escape(escape_position)?);
+                            continue;
+                        },
+                        _ => return Err(JsonError::InvalidEscape(escape_position)),
+                    };
+
+                    self.position += 1;
+                    result.push(escaped);
+                },
+
+                character if character < ' ' => {
+                    return Err(JsonError::Expected("'\"'", self.position));
+                },
+
+                character => {
+                    self.position += character.len_utf8();
+                    result.push(character);
+                },
+            }
+        }
+    }
+
+    /// Unicode escape parsing.
+    ///
+    /// Parses the hexadecimal digits after `\u`, joining
+    /// UTF-16 surrogate pairs into a single character.
+    ///
+    /// **Arguments**
+    /// * `escape_position` - The byte index of the escape sequence.
+    ///
+    /// **Returns**
+    /// The escaped character.
+    fn parse_unicode_escape(&mut self, escape_position: usize) -> Result<char, JsonError> {
+        let high = self.parse_hex_digits(escape_position)?;
+
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or(JsonError::InvalidEscape(escape_position));
+        }
+
+        if !self.source[self.position..].starts_with("\\u") {
+            return Err(JsonError::InvalidEscape(escape_position));
+        }
+
+        self.position += 2;
+        let low = self.parse_hex_digits(escape_position)?;
+
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(JsonError::InvalidEscape(escape_position));
+        }
+
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or(JsonError::InvalidEscape(escape_position))
+    }
+
+    /// Hexadecimal escape digits parsing.
+    ///
+    /// **Arguments**
+    /// * `escape_position` - The byte index of the escape sequence.
+    ///
+    /// **Returns**
+    /// The value of the four hexadecimal digits.
+    fn parse_hex_digits(&mut self, escape_position: usize) -> Result<u32, JsonError> {
+        let digits = self
+            .source
+            .get(self.position..self.position + 4)
+            .filter(|digits| {
+                digits
+                    .chars()
+                    .all(|digit| digit.is_ascii_hexdigit())
+            })
+            .ok_or(JsonError::InvalidEscape(escape_position))?;
+
+        self.position += 4;
+        u32::from_str_radix(digits, 16).map_err(|_| JsonError::InvalidEscape(escape_position))
+    }
+
+    /// Number parsing.
+    ///
+    /// Follows the JSON number grammar, so leading zeros,
+    /// a leading `+` and empty fraction or exponent digits
+    /// are rejected even if Rust would parse them.
+    ///
+    /// **Returns**
+    /// The number as a float, its value is never used.
+    fn parse_number(&mut self) -> Result<f64, JsonError> {
+        let start = self.position;
+        let bytes = self
+            .source
+            .as_bytes();
+
+        let digits = |end: &mut usize| {
+            let from = *end;
+            while bytes
+                .get(*end)
+                .is_some_and(u8::is_ascii_digit)
+            {
+                *end += 1;
+            }
+            *end > from
+        };
+
+        let mut end = start;
+        if bytes.get(end) == Some(&b'-') {
+            end += 1;
+        }
+
+        let valid = match bytes.get(end) {
+            Some(b'0') => {
+                end += 1;
+                true
+            },
+            _ => digits(&mut end),
+        } && (bytes.get(end) != Some(&b'.') || {
+            end += 1;
+            digits(&mut end)
+        }) && (!matches!(bytes.get(end), Some(b'e' | b'E')) || {
+            end += 1;
+            if matches!(bytes.get(end), Some(b'+' | b'-')) {
+                end += 1;
+            }
+            digits(&mut end)
+        });
+
+        if !valid {
+            return Err(JsonError::Expected("a number", start));
+        }
+
+        self.position = end;
+        self.source[start..end]
+            .parse::<f64>()
+            .map_err(|_| JsonError::Expected("a number", start))
+    }
+
+    /// Literal parsing.
+    ///
+    /// **Arguments**
+    /// * `literal` - The expected literal, such as `true`.
+    fn parse_literal(&mut self, literal: &'static str) -> Result<(), JsonError> {
+        if !self.source[self.position..].starts_with(literal) {
+            return Err(JsonError::Expected("a value", self.position));
+        }
+
+        self.position += literal.len();
+        Ok(())
+    }
+
+    /// Skips whitespace, keeping track of the lines.
+    fn skip_whitespace(&mut self) {
+        while let Some(character) = self.peek() {
+            match character {
+                '\n' => {
+                    self.position += 1;
+                    self.line += 1;
+                    self.line_start = self.position;
+                },
+                ' ' | '\t' | '\r' => self.position += 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// Peeks the current character.
+    ///
+    /// **Returns**
+    /// The current character or `None` at the end of the file.
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..]
+            .chars()
+            .next()
+    }
+
+    /// Location of a byte index in the current line.
+    ///
+    /// **Arguments**
+    /// * `position` - A byte index in the current line, after the last skipped
+    ///   whitespace.
+    ///
+    /// **Returns**
+    /// The one based line and column of the index.
+    fn location(&self, position: usize) -> (usize, usize) {
+        let column = self.source[self.line_start..position]
+            .chars()
+            .count()
+            + 1;
+
+        (self.line, column)
+    }
+}
+
+/// JSON string literal generation.
+///
+/// Escapes the quotes, backslashes and control characters
+/// of the value, so reading the literal back with the JSON
+/// format yields the same value.
+///
+/// **Arguments**
+/// * `value` - The string to write as a literal.
+///
+/// **Returns**
+/// The quoted and escaped string literal.
+pub fn json_string(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            character if character.is_control() => {
+                literal.push_str(&format!("\\u{:04x}", character as u32))
+            },
+            character => literal.push(character),
+        }
+    }
+
+    literal.push('"');
+    literal
+}
+
+/// JSON object generation.
+///
+/// **Arguments**
+/// * `fields` - The keys and string values of the object, in order.
+///
+/// **Returns**
+/// A single line object with every key and value written
+/// with [`json_string`].
+pub fn json_object(fields: &[(&str, &str)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("{}: {}", json_string(key), json_string(value)))
+        .collect::<Vec<_>>();
+
+    format!("{{ {} }}", fields.join(", "))
+}
@@ -0,0 +1,378 @@
+//! [`Locale`] declaration module.
+//!
+//! This module declares a BCP 47 language tag
+//! abstraction built on top of [`Language`],
+//! supporting the optional script and region
+//! subtags, such as `zh-Hant` or `pt-BR`.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, TokenStreamExt, quote};
+use thiserror::Error;
+
+use super::language::Language;
+
+/// [`Locale`] parsing errors.
+///
+/// This error is returned when a BCP 47 tag
+/// couldn't be parsed into a [`Locale`], either
+/// because the primary language subtag is not
+/// ISO 639-1 or because one of the other subtags
+/// is not a supported script or region.
+#[derive(Error, Debug)]
+pub enum LocaleError {
+    /// Empty tag error.
+    ///
+    /// The tag didn't contain any subtag.
+    #[error("A language tag cannot be empty")]
+    Empty,
+
+    /// Invalid primary language subtag.
+    ///
+    /// **Parameters**
+    /// * `0` - The subtag that is not a valid ISO 639-1 language.
+    #[error("'{0}' is not a valid ISO 639-1 language")]
+    InvalidLanguage(String),
+
+    /// Invalid script or region subtag.
+    ///
+    /// Only 4 letter scripts (ISO 15924), 2 letter
+    /// regions (ISO 3166-1) and 3 digit regions (UN M.49)
+    /// are supported, in that order.
+    ///
+    /// **Parameters**
+    /// * `0` - The subtag that couldn't be parsed.
+    #[error("'{0}' is not a valid script or region subtag")]
+    InvalidSubtag(String),
+}
+
+/// ISO 15924 script subtag.
+///
+/// Stored as its four ASCII letters in title
+/// case, such as `Hant` or `Latn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Script([u8; 4]);
+
+/// ISO 3166-1 or UN M.49 region subtag.
+///
+/// Stored as either two uppercase ASCII letters
+/// followed by a zero byte, or three ASCII digits,
+/// such as `BR` or `419`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region([u8; 3]);
+
+/// BCP 47 language tag.
+///
+/// A [`Language`] optionally qualified by a [`Script`]
+/// and a [`Region`], this is what translation objects
+/// are keyed with.
+///
+/// Lookups fall back from the most specific tag to
+/// the less specific ones, read [`Locale::fallbacks`]
+/// for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The primary language subtag.
+    language: Language,
+
+    /// The optional script subtag.
+    script: Option<Script>,
+
+    /// The optional region subtag.
+    region: Option<Region>,
+}
+
+impl Script {
+    /// Compile-time to runtime constructor.
+    ///
+    /// The bytes are not validated, this is meant
+    /// to be called from macro generated code.
+    ///
+    /// **Parameters**
+    /// * `bytes` - The script letters in title case.
+    ///
+    /// **Returns**
+    /// The bytes wrapped in a [`Script`].
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self(bytes)
+    }
+
+    /// Script subtag as a string slice.
+    ///
+    /// **Returns**
+    /// The script subtag, such as `Hant`.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl Region {
+    /// Compile-time to runtime constructor.
+    ///
+    /// The bytes are not validated, this is meant
+    /// to be called from macro generated code.
+    ///
+    /// **Parameters**
+    /// * `bytes` - The region letters in uppercase followed by a zero byte, or
+    ///   the region digits.
+    ///
+    /// **Returns**
+    /// The bytes wrapped in a [`Region`].
+    pub const fn from_bytes(bytes: [u8; 3]) -> Self {
+        Self(bytes)
+    }
+
+    /// Region subtag as a string slice.
+    ///
+    /// **Returns**
+    /// The region subtag, such as `BR` or `419`.
+    pub fn as_str(&self) -> &str {
+        let length = if self.0[2] == 0 { 2 } else { 3 };
+
+        std::str::from_utf8(&self.0[..length]).unwrap_or_default()
+    }
+}
+
+impl Locale {
+    /// Compile-time to runtime constructor.
+    ///
+    /// **Parameters**
+    /// * `language` - The primary language subtag.
+    /// * `script` - The optional script subtag.
+    /// * `region` - The optional region subtag.
+    ///
+    /// **Returns**
+    /// A [`Locale`] made of the provided subtags.
+    pub const fn new(language: Language, script: Option<Script>, region: Option<Region>) -> Self {
+        Self { language, script, region }
+    }
+
+    /// Language subtag getter.
+    ///
+    /// **Returns**
+    /// A reference to the primary language subtag.
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Script subtag getter.
+    ///
+    /// **Returns**
+    /// The script subtag if any.
+    pub fn script(&self) -> Option<Script> {
+        self.script
+    }
+
+    /// Region subtag getter.
+    ///
+    /// **Returns**
+    /// The region subtag if any.
+    pub fn region(&self) -> Option<Region> {
+        self.region
+    }
+
+    /// Lookup fallback chain.
+    ///
+    /// Yields this locale and then the less specific
+    /// tags obtained by truncating it, so `zh-Hant-TW`
+    /// yields `zh-Hant-TW`, `zh-Hant` and `zh`.
+    ///
+    /// **Returns**
+    /// An iterator over the tags to try in order.
+    pub fn fallbacks(&self) -> impl Iterator<Item = Locale> {
+        let mut current = Some(self.clone());
+
+        std::iter::from_fn(move || {
+            let next = current.take()?;
+
+            current = if next
+                .region
+                .is_some()
+            {
+                Some(Self { region: None, ..next.clone() })
+            } else if next
+                .script
+                .is_some()
+            {
+                Some(Self { script: None, ..next.clone() })
+            } else {
+                None
+            };
+
+            Some(next)
+        })
+    }
+
+    /// Available locale lookup.
+    ///
+    /// Resolves this locale against a static table of the
+    /// locales a translation is available in, the same way
+    /// [`TranslationObject::get_or_fallback`] does, so macro
+    /// generated code can select a translation without building
+    /// a translation object.
+    ///
+    /// **Parameters**
+    /// * `available` - The locales the translation is available in.
+    /// * `fallbacks` - The locales to try in order if this one is not
+    ///   available.
+    ///
+    /// **Returns**
+    /// The index in `available` of the first locale found in the chain.
+    ///
+    /// [`TranslationObject::get_or_fallback`]: crate::translations::node::TranslationObject::get_or_fallback
+    pub fn lookup(&self, available: &[Locale], fallbacks: &[Locale]) -> Option<usize> {
+        std::iter::once(self)
+            .chain(fallbacks)
+            .find_map(|locale| {
+                locale
+                    .fallbacks()
+                    .find_map(|candidate| {
+                        available
+                            .iter()
+                            .position(|locale| *locale == candidate)
+                    })
+            })
+    }
+}
+
+/// [`Language`] to [`Locale`] conversion.
+///
+/// A bare language converts into a locale
+/// without script nor region subtags.
+impl From<Language> for Locale {
+    fn from(language: Language) -> Self {
+        Self::new(language, None, None)
+    }
+}
+
+/// BCP 47 tag parsing implementation.
+///
+/// Subtags may be separated by `-` or `_` and
+/// are case insensitive, the primary language
+/// subtag accepts the same values as [`Language`].
+impl FromStr for Locale {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut subtags = s
+            .trim()
+            .split(['-', '_']);
+
+        let language = match subtags.next() {
+            Some("") | None => return Err(LocaleError::Empty),
+            Some(language) => language
+                .parse::<Language>()
+                .map_err(|_| LocaleError::InvalidLanguage(language.into()))?,
+        };
+
+        let mut script = None;
+        let mut region = None;
+
+        for subtag in subtags {
+            let bytes = subtag.as_bytes();
+
+            match bytes.len() {
+                4 if script.is_none()
+                    && region.is_none()
+                    && bytes
+                        .iter()
+                        .all(u8::is_ascii_alphabetic) =>
+                {
+                    let mut letters = [0; 4];
+                    for (i, byte) in bytes
+                        .iter()
+                        .enumerate()
+                    {
+                        letters[i] = if i == 0 {
+                            byte.to_ascii_uppercase()
+                        } else {
+                            byte.to_ascii_lowercase()
+                        };
+                    }
+
+                    script = Some(Script(letters));
+                },
+
+                2 if region.is_none()
+                    && bytes
+                        .iter()
+                        .all(u8::is_ascii_alphabetic) =>
+                {
+                    region = Some(Region([
+                        bytes[0].to_ascii_uppercase(),
+                        bytes[1].to_ascii_uppercase(),
+                        0,
+                    ]));
+                },
+
+                3 if region.is_none()
+                    && bytes
+                        .iter()
+                        .all(u8::is_ascii_digit) =>
+                {
+                    region = Some(Region([bytes[0], bytes[1], bytes[2]]));
+                },
+
+                _ => return Err(LocaleError::InvalidSubtag(subtag.into())),
+            }
+        }
+
+        Ok(Self { language, script, region })
+    }
+}
+
+/// Canonical BCP 47 representation.
+///
+/// Displays the tag with a lowercase language,
+/// title case script and uppercase region, such
+/// as `zh-Hant-TW`.
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", format!("{:?}", self.language).to_lowercase())?;
+
+        if let Some(script) = self.script {
+            write!(f, "-{}", script.as_str())?;
+        }
+
+        if let Some(region) = self.region {
+            write!(f, "-{}", region.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// This implementation converts the structure
+/// to an equivalent call from the runtime context.
+///
+/// This is exclusively meant to be used from the
+/// macro generation context.
+impl ToTokens for Locale {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let language = &self.language;
+
+        let script = match self.script {
+            Some(Script(bytes)) => quote! {
+                std::option::Option::Some(
+                    translatable::shared::misc::locale::Script::from_bytes([#(#bytes),*])
+                )
+            },
+            None => quote! { std::option::Option::None },
+        };
+
+        let region = match self.region {
+            Some(Region(bytes)) => quote! {
+                std::option::Option::Some(
+                    translatable::shared::misc::locale::Region::from_bytes([#(#bytes),*])
+                )
+            },
+            None => quote! { std::option::Option::None },
+        };
+
+        tokens.append_all(quote! {
+            translatable::shared::misc::locale::Locale::new(#language, #script, #region)
+        });
+    }
+}
@@ -4,5 +4,11 @@
 //! or items that don't fit into an existing category — typically
 //! because there aren’t enough related modules to justify their own group.
 
+pub mod format_spec;
+pub mod gettext;
+pub mod json;
+pub mod glob;
 pub mod language;
+pub mod locale;
+pub mod plural;
 pub mod templating;
@@ -0,0 +1,987 @@
+//! Plural rules module.
+//!
+//! This module declares the CLDR plural categories
+//! and the cardinal plural rules for each [`Locale`],
+//! along with [`PluralTranslation`] which holds a
+//! translation variant for each category.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, TokenStreamExt, quote};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+use syn::Ident;
+
+use super::language::Language;
+use super::locale::Locale;
+use super::templating::FormatString;
+use crate::macros::collections::map_to_tokens;
+
+/// CLDR plural category.
+///
+/// Each language uses a subset of these categories,
+/// `Other` is used by all of them. The categories are
+/// written in lowercase in translation files.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, EnumIter, EnumString, Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum PluralCategory {
+    #[allow(missing_docs)]
+    Zero,
+    #[allow(missing_docs)]
+    One,
+    #[allow(missing_docs)]
+    Two,
+    #[allow(missing_docs)]
+    Few,
+    #[allow(missing_docs)]
+    Many,
+    #[allow(missing_docs)]
+    Other,
+}
+
+/// CLDR plural operands.
+///
+/// The operands are computed from the decimal
+/// representation of a number, so `1` and `1.0`
+/// may select different categories as CLDR defines.
+///
+/// Only the `i`, `v`, `f`, `t` and `e` operands are
+/// kept, `n` is derived from them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluralOperands {
+    /// Integer digits of the absolute value.
+    i: u64,
+
+    /// Number of visible fraction digits, with trailing zeros.
+    v: usize,
+
+    /// Visible fraction digits, with trailing zeros.
+    f: u64,
+
+    /// Visible fraction digits, without trailing zeros.
+    t: u64,
+
+    /// Compact decimal exponent, such as `6` for `1.2c6`.
+    e: u32,
+}
+
+/// CLDR cardinal plural rule.
+///
+/// Languages that share the same cardinal rule share
+/// a variant, which is named after the rule conditions
+/// or after a representative language when the rule is
+/// specific to a language family.
+///
+/// Languages without a specific CLDR rule use [`OneN`].
+///
+/// [`OneN`]: PluralRule::OneN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralRule {
+    /// No plural distinction, only `other`.
+    Other,
+
+    /// `one` for `n = 1`.
+    OneN,
+
+    /// `one` for `n = 1` and `many` for millions.
+    OneNMany,
+
+    /// `one` for `i = 1 and v = 0`.
+    OneInteger,
+
+    /// `one` for `i = 1 and v = 0` and `many` for millions.
+    OneIntegerMany,
+
+    /// `one` for `i = 0 or n = 1`.
+    OneZeroOrN,
+
+    /// `one` for `n = 0..1`.
+    OneZeroToOneN,
+
+    /// `one` for `i = 0,1`.
+    OneZeroToOneI,
+
+    /// `one` for `i = 0,1` and `many` for millions.
+    OneZeroToOneIMany,
+
+    /// `one` and `two` for `n = 1` and `n = 2`.
+    OneTwo,
+
+    /// Danish rule.
+    Danish,
+
+    /// Icelandic rule.
+    Icelandic,
+
+    /// Macedonian rule.
+    Macedonian,
+
+    /// Filipino rule.
+    Filipino,
+
+    /// Sinhala rule.
+    Sinhala,
+
+    /// Latvian rule, with `zero`.
+    Latvian,
+
+    /// Lithuanian rule, with `few` and `many`.
+    Lithuanian,
+
+    /// Russian, Ukrainian and Belarusian rule, with `few` and `many`.
+    EastSlavic,
+
+    /// Polish rule, with `few` and `many`.
+    Polish,
+
+    /// Czech and Slovak rule, with `few` and `many`.
+    CzechSlovak,
+
+    /// Bosnian, Croatian and Serbian rule, with `few`.
+    SerboCroatian,
+
+    /// Slovenian rule, with `two` and `few`.
+    Slovenian,
+
+    /// Romanian rule, with `few`.
+    Romanian,
+
+    /// Hebrew rule, with `two`.
+    Hebrew,
+
+    /// Arabic rule, with all the categories.
+    Arabic,
+
+    /// Irish rule, with `two`, `few` and `many`.
+    Irish,
+
+    /// Scottish Gaelic rule, with `two` and `few`.
+    ScottishGaelic,
+
+    /// Welsh rule, with all the categories.
+    Welsh,
+
+    /// Maltese rule, with `two`, `few` and `many`.
+    Maltese,
+
+    /// Breton rule, with `two`, `few` and `many`.
+    Breton,
+
+    /// Manx rule, with `two`, `few` and `many`.
+    Manx,
+
+    /// Cornish rule, with all the categories.
+    Cornish,
+}
+
+/// Plural translation structure.
+///
+/// Holds a translation variant for each plural
+/// category along with the rule that selects them,
+/// which is inferred from the translation language.
+///
+/// The `other` variant is always present, as it's
+/// the one used for the categories without a variant.
+pub struct PluralTranslation {
+    /// The rule that selects the variant.
+    rule: PluralRule,
+
+    /// The `other` variant.
+    other: FormatString,
+
+    /// The variants of the remaining categories.
+    variants: HashMap<PluralCategory, FormatString>,
+}
+
+impl PluralOperands {
+    /// Integer value.
+    ///
+    /// **Returns**
+    /// The value as an integer, if it has no visible non-zero
+    /// fraction digits, used for the `n` operand conditions.
+    fn n(&self) -> Option<u64> {
+        (self.t == 0).then_some(self.i)
+    }
+}
+
+/// Decimal string parsing implementation.
+///
+/// Parses strings such as `1`, `-12` or `1.50`
+/// which is what numeric types display as, along
+/// with the exponent notation of `1e6` and the CLDR
+/// compact notation of `1.2c6`. The sign is ignored
+/// as plural rules use the absolute value.
+impl FromStr for PluralOperands {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the shifted digits are bounded by what fits in a u64.
+        const MAX_EXPONENT: i32 = 20;
+
+        let s = s
+            .trim()
+            .trim_start_matches(['-', '+']);
+
+        let (mantissa, exponent) = match s.split_once(['e', 'E', 'c', 'C']) {
+            Some((mantissa, exponent)) => (
+                mantissa,
+                exponent
+                    .parse::<i32>()
+                    .map_err(|_| ())?,
+            ),
+            None => (s, 0),
+        };
+
+        if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return Err(());
+        }
+
+        let (integer, fraction) = mantissa
+            .split_once('.')
+            .unwrap_or((mantissa, ""));
+
+        let all_digits = |s: &str| {
+            s.bytes()
+                .all(|b| b.is_ascii_digit())
+        };
+
+        if integer.is_empty() || !all_digits(integer) || !all_digits(fraction) {
+            return Err(());
+        }
+
+        // the exponent moves the decimal point over the mantissa digits.
+        let digits = format!("{integer}{fraction}");
+        let point = integer.len() as i32 + exponent;
+        let (integer, fraction) = if point <= 0 {
+            ("0".to_string(), format!("{}{digits}", "0".repeat(point.unsigned_abs() as usize)))
+        } else if point as usize >= digits.len() {
+            (format!("{digits}{}", "0".repeat(point as usize - digits.len())), String::new())
+        } else {
+            let (integer, fraction) = digits.split_at(point as usize);
+            (integer.to_string(), fraction.to_string())
+        };
+
+        let trimmed = fraction.trim_end_matches('0');
+        let parse = |s: &str| {
+            if s.is_empty() {
+                Ok(0)
+            } else {
+                s.parse::<u64>()
+                    .map_err(|_| ())
+            }
+        };
+
+        Ok(Self {
+            i: parse(&integer)?,
+            v: fraction.len(),
+            f: parse(&fraction)?,
+            t: parse(trimmed)?,
+            e: exponent.max(0) as u32,
+        })
+    }
+}
+
+impl PluralRule {
+    /// Category selection.
+    ///
+    /// Applies the CLDR rule conditions to the operands.
+    ///
+    /// **Arguments**
+    /// * `operands` - The operands of the number to select the category for.
+    ///
+    /// **Returns**
+    /// The plural category the number belongs to.
+    pub fn select(&self, operands: &PluralOperands) -> PluralCategory {
+        use PluralCategory::*;
+
+        let PluralOperands { i, v, f, t, e } = *operands;
+        let n = operands.n();
+
+        // CLDR `many` for the Romance languages that inflect millions.
+        let millions = (e == 0 && i != 0 && i % 1_000_000 == 0 && v == 0) || e > 5;
+
+        let n_is = |values: &[u64]| n.is_some_and(|n| values.contains(&n));
+        let n_in = |start: u64, end: u64| n.is_some_and(|n| (start..=end).contains(&n));
+        let n_mod_is = |m: u64, values: &[u64]| n.is_some_and(|n| values.contains(&(n % m)));
+        let n_mod_in =
+            |m: u64, start: u64, end: u64| n.is_some_and(|n| (start..=end).contains(&(n % m)));
+
+        let category = match self {
+            Self::Other => None,
+
+            Self::OneN => n_is(&[1]).then_some(One),
+
+            Self::OneNMany => {
+                if n_is(&[1]) {
+                    Some(One)
+                } else if millions {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::OneInteger => (i == 1 && v == 0).then_some(One),
+
+            Self::OneIntegerMany => {
+                if i == 1 && v == 0 {
+                    Some(One)
+                } else if millions {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::OneZeroOrN => (i == 0 || n_is(&[1])).then_some(One),
+
+            Self::OneZeroToOneN => n_in(0, 1).then_some(One),
+
+            Self::OneZeroToOneI => (i <= 1).then_some(One),
+
+            Self::OneZeroToOneIMany => {
+                if i <= 1 {
+                    Some(One)
+                } else if millions {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::OneTwo => {
+                if n_is(&[1]) {
+                    Some(One)
+                } else if n_is(&[2]) {
+                    Some(Two)
+                } else {
+                    None
+                }
+            },
+
+            Self::Danish => (n_is(&[1]) || (t != 0 && i <= 1)).then_some(One),
+
+            Self::Icelandic => ((t == 0 && i % 10 == 1 && i % 100 != 11)
+                || (t % 10 == 1 && t % 100 != 11))
+                .then_some(One),
+
+            Self::Macedonian => ((v == 0 && i % 10 == 1 && i % 100 != 11)
+                || (f % 10 == 1 && f % 100 != 11))
+                .then_some(One),
+
+            Self::Filipino => ((v == 0 && matches!(i, 1..=3))
+                || (v == 0 && !matches!(i % 10, 4 | 6 | 9))
+                || (v != 0 && !matches!(f % 10, 4 | 6 | 9)))
+            .then_some(One),
+
+            Self::Sinhala => (n_is(&[0, 1]) || (i == 0 && f == 1)).then_some(One),
+
+            Self::Latvian => {
+                if n_mod_is(10, &[0])
+                    || n_mod_in(100, 11, 19)
+                    || (v == 2 && (11..=19).contains(&(f % 100)))
+                {
+                    Some(Zero)
+                } else if (n_mod_is(10, &[1]) && !n_mod_is(100, &[11]))
+                    || (v == 2 && f % 10 == 1 && f % 100 != 11)
+                    || (v != 2 && f % 10 == 1)
+                {
+                    Some(One)
+                } else {
+                    None
+                }
+            },
+
+            Self::Lithuanian => {
+                if n_mod_is(10, &[1]) && !n_mod_in(100, 11, 19) {
+                    Some(One)
+                } else if n_mod_in(10, 2, 9) && !n_mod_in(100, 11, 19) {
+                    Some(Few)
+                } else if f != 0 {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::EastSlavic => {
+                if v != 0 {
+                    None
+                } else if i % 10 == 1 && i % 100 != 11 {
+                    Some(One)
+                } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                    Some(Few)
+                } else {
+                    Some(Many)
+                }
+            },
+
+            Self::Polish => {
+                if v != 0 {
+                    None
+                } else if i == 1 {
+                    Some(One)
+                } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                    Some(Few)
+                } else {
+                    Some(Many)
+                }
+            },
+
+            Self::CzechSlovak => {
+                if v != 0 {
+                    Some(Many)
+                } else if i == 1 {
+                    Some(One)
+                } else if (2..=4).contains(&i) {
+                    Some(Few)
+                } else {
+                    None
+                }
+            },
+
+            Self::SerboCroatian => {
+                if (v == 0 && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                    Some(One)
+                } else if (v == 0 && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)))
+                    || ((2..=4).contains(&(f % 10)) && !(12..=14).contains(&(f % 100)))
+                {
+                    Some(Few)
+                } else {
+                    None
+                }
+            },
+
+            Self::Slovenian => {
+                if v != 0 {
+                    Some(Few)
+                } else {
+                    match i % 100 {
+                        1 => Some(One),
+                        2 => Some(Two),
+                        3 | 4 => Some(Few),
+                        _ => None,
+                    }
+                }
+            },
+
+            Self::Romanian => {
+                if i == 1 && v == 0 {
+                    Some(One)
+                } else if v != 0 || n_is(&[0]) || n_mod_in(100, 1, 19) {
+                    Some(Few)
+                } else {
+                    None
+                }
+            },
+
+            Self::Hebrew => {
+                if (i == 1 && v == 0) || (i == 0 && v != 0) {
+                    Some(One)
+                } else if i == 2 && v == 0 {
+                    Some(Two)
+                } else {
+                    None
+                }
+            },
+
+            Self::Arabic => {
+                if n_is(&[0]) {
+                    Some(Zero)
+                } else if n_is(&[1]) {
+                    Some(One)
+                } else if n_is(&[2]) {
+                    Some(Two)
+                } else if n_mod_in(100, 3, 10) {
+                    Some(Few)
+                } else if n_mod_in(100, 11, 99) {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::Irish => {
+                if n_is(&[1]) {
+                    Some(One)
+                } else if n_is(&[2]) {
+                    Some(Two)
+                } else if n_in(3, 6) {
+                    Some(Few)
+                } else if n_in(7, 10) {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::ScottishGaelic => {
+                if n_is(&[1, 11]) {
+                    Some(One)
+                } else if n_is(&[2, 12]) {
+                    Some(Two)
+                } else if n_in(3, 10) || n_in(13, 19) {
+                    Some(Few)
+                } else {
+                    None
+                }
+            },
+
+            Self::Welsh => match n {
+                Some(0) => Some(Zero),
+                Some(1) => Some(One),
+                Some(2) => Some(Two),
+                Some(3) => Some(Few),
+                Some(6) => Some(Many),
+                _ => None,
+            },
+
+            Self::Maltese => {
+                if n_is(&[1]) {
+                    Some(One)
+                } else if n_is(&[2]) {
+                    Some(Two)
+                } else if n_is(&[0]) || n_mod_in(100, 3, 10) {
+                    Some(Few)
+                } else if n_mod_in(100, 11, 19) {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::Breton => {
+                if n_mod_is(10, &[1]) && !n_mod_is(100, &[11, 71, 91]) {
+                    Some(One)
+                } else if n_mod_is(10, &[2]) && !n_mod_is(100, &[12, 72, 92]) {
+                    Some(Two)
+                } else if n_mod_is(10, &[3, 4, 9])
+                    && !n_mod_in(100, 10, 19)
+                    && !n_mod_in(100, 70, 79)
+                    && !n_mod_in(100, 90, 99)
+                {
+                    Some(Few)
+                } else if n.is_some_and(|n| n != 0) && n_mod_is(1_000_000, &[0]) {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+
+            Self::Manx => {
+                if v != 0 {
+                    Some(Many)
+                } else if i % 10 == 1 {
+                    Some(One)
+                } else if i % 10 == 2 {
+                    Some(Two)
+                } else if matches!(i % 100, 0 | 20 | 40 | 60 | 80) {
+                    Some(Few)
+                } else {
+                    None
+                }
+            },
+
+            Self::Cornish => {
+                if n_is(&[0]) {
+                    Some(Zero)
+                } else if n_is(&[1]) {
+                    Some(One)
+                } else if n_mod_is(100, &[2, 22, 42, 62, 82])
+                    || (n_mod_is(1000, &[0])
+                        && (n_mod_in(100_000, 1000, 20_000)
+                            || n_mod_is(100_000, &[40_000, 60_000, 80_000])))
+                    || (n.is_some_and(|n| n != 0) && n_mod_is(1_000_000, &[100_000]))
+                {
+                    Some(Two)
+                } else if n_mod_is(100, &[3, 23, 43, 63, 83]) {
+                    Some(Few)
+                } else if !n_is(&[1]) && n_mod_is(100, &[1, 21, 41, 61, 81]) {
+                    Some(Many)
+                } else {
+                    None
+                }
+            },
+        };
+
+        category.unwrap_or(Other)
+    }
+
+    /// Categories used by the rule.
+    ///
+    /// These are the categories that a translation
+    /// must define for every number to have a variant.
+    ///
+    /// **Returns**
+    /// The categories the rule may select, always including `other`.
+    pub fn categories(&self) -> &'static [PluralCategory] {
+        use PluralCategory::*;
+
+        match self {
+            Self::Other => &[Other],
+            Self::OneN
+            | Self::OneInteger
+            | Self::OneZeroOrN
+            | Self::OneZeroToOneN
+            | Self::OneZeroToOneI
+            | Self::Danish
+            | Self::Icelandic
+            | Self::Macedonian
+            | Self::Filipino
+            | Self::Sinhala => &[One, Other],
+            Self::OneNMany | Self::OneIntegerMany | Self::OneZeroToOneIMany => &[One, Many, Other],
+            Self::OneTwo | Self::Hebrew => &[One, Two, Other],
+            Self::Latvian => &[Zero, One, Other],
+            Self::SerboCroatian | Self::Romanian => &[One, Few, Other],
+            Self::Lithuanian | Self::EastSlavic | Self::Polish | Self::CzechSlovak => {
+                &[One, Few, Many, Other]
+            },
+            Self::Slovenian | Self::ScottishGaelic => &[One, Two, Few, Other],
+            Self::Irish | Self::Maltese | Self::Breton | Self::Manx => {
+                &[One, Two, Few, Many, Other]
+            },
+            Self::Arabic | Self::Welsh | Self::Cornish => &[Zero, One, Two, Few, Many, Other],
+        }
+    }
+    /// Gettext plural forms.
+    ///
+    /// Gettext selects the plural form of integers with a
+    /// C expression, these expressions select the index of
+    /// the CLDR category in [`categories`] for every integer.
+    /// The categories only used by decimals are still counted,
+    /// so the forms map back to the same categories.
+    ///
+    /// **Returns**
+    /// A `Plural-Forms` header value, such as
+    /// `nplurals=2; plural=(n != 1);`.
+    ///
+    /// [`categories`]: PluralRule::categories
+    pub fn plural_forms(&self) -> &'static str {
+        match self {
+            Self::Other => "nplurals=1; plural=0;",
+
+            Self::OneN | Self::OneInteger | Self::Danish => "nplurals=2; plural=(n != 1);",
+
+            Self::OneZeroOrN | Self::OneZeroToOneN | Self::OneZeroToOneI | Self::Sinhala => {
+                "nplurals=2; plural=(n > 1);"
+            },
+
+            Self::Icelandic | Self::Macedonian => "nplurals=2; plural=(n%10 != 1 || n%100 == 11);",
+
+            Self::Filipino => {
+                "nplurals=2; plural=(n != 1 && n != 2 && n != 3 && (n%10 == 4 || n%10 == 6 || \
+                 n%10 == 9));"
+            },
+
+            Self::OneNMany | Self::OneIntegerMany => {
+                "nplurals=3; plural=(n == 1 ? 0 : n != 0 && n%1000000 == 0 ? 1 : 2);"
+            },
+
+            Self::OneZeroToOneIMany => {
+                "nplurals=3; plural=(n == 0 || n == 1 ? 0 : n%1000000 == 0 ? 1 : 2);"
+            },
+
+            Self::OneTwo | Self::Hebrew => "nplurals=3; plural=(n == 1 ? 0 : n == 2 ? 1 : 2);",
+
+            Self::Latvian => {
+                "nplurals=3; plural=(n%10 == 0 || (n%100 >= 11 && n%100 <= 19) ? 0 : n%10 == 1 \
+                 && n%100 != 11 ? 1 : 2);"
+            },
+
+            Self::SerboCroatian => {
+                "nplurals=3; plural=(n%10 == 1 && n%100 != 11 ? 0 : n%10 >= 2 && n%10 <= 4 && \
+                 (n%100 < 12 || n%100 > 14) ? 1 : 2);"
+            },
+
+            Self::Romanian => {
+                "nplurals=3; plural=(n == 1 ? 0 : n == 0 || (n%100 >= 1 && n%100 <= 19) ? 1 : 2);"
+            },
+
+            Self::Lithuanian => {
+                "nplurals=4; plural=(n%10 == 1 && (n%100 < 11 || n%100 > 19) ? 0 : n%10 >= 2 && \
+                 (n%100 < 11 || n%100 > 19) ? 1 : 3);"
+            },
+
+            Self::EastSlavic => {
+                "nplurals=4; plural=(n%10 == 1 && n%100 != 11 ? 0 : n%10 >= 2 && n%10 <= 4 && \
+                 (n%100 < 12 || n%100 > 14) ? 1 : 2);"
+            },
+
+            Self::Polish => {
+                "nplurals=4; plural=(n == 1 ? 0 : n%10 >= 2 && n%10 <= 4 && (n%100 < 12 || \
+                 n%100 > 14) ? 1 : 2);"
+            },
+
+            Self::CzechSlovak => "nplurals=4; plural=(n == 1 ? 0 : n >= 2 && n <= 4 ? 1 : 3);",
+
+            Self::Slovenian => {
+                "nplurals=4; plural=(n%100 == 1 ? 0 : n%100 == 2 ? 1 : n%100 == 3 || n%100 == 4 \
+                 ? 2 : 3);"
+            },
+
+            Self::ScottishGaelic => {
+                "nplurals=4; plural=(n == 1 || n == 11 ? 0 : n == 2 || n == 12 ? 1 : (n >= 3 && \
+                 n <= 10) || (n >= 13 && n <= 19) ? 2 : 3);"
+            },
+
+            Self::Irish => {
+                "nplurals=5; plural=(n == 1 ? 0 : n == 2 ? 1 : n >= 3 && n <= 6 ? 2 : n >= 7 && n \
+                 <= 10 ? 3 : 4);"
+            },
+
+            Self::Maltese => {
+                "nplurals=5; plural=(n == 1 ? 0 : n == 2 ? 1 : n == 0 || (n%100 >= 3 && n%100 <= \
+                 10) ? 2 : n%100 >= 11 && n%100 <= 19 ? 3 : 4);"
+            },
+
+            Self::Breton => {
+                "nplurals=5; plural=(n%10 == 1 && n%100 != 11 && n%100 != 71 && n%100 != 91 ? 0 \
+                 : n%10 == 2 && n%100 != 12 && n%100 != 72 && n%100 != 92 ? 1 : (n%10 == 3 || \
+                 n%10 == 4 || n%10 == 9) && (n%100 < 10 || n%100 > 19) && (n%100 < 70 || n%100 \
+                 > 79) && (n%100 < 90 || n%100 > 99) ? 2 : n != 0 && n%1000000 == 0 ? 3 : 4);"
+            },
+
+            Self::Manx => "nplurals=5; plural=(n%10 == 1 ? 0 : n%10 == 2 ? 1 : n%20 == 0 ? 2 : 4);",
+
+            Self::Arabic => {
+                "nplurals=6; plural=(n == 0 ? 0 : n == 1 ? 1 : n == 2 ? 2 : n%100 >= 3 && n%100 \
+                 <= 10 ? 3 : n%100 >= 11 ? 4 : 5);"
+            },
+
+            Self::Welsh => {
+                "nplurals=6; plural=(n == 0 ? 0 : n == 1 ? 1 : n == 2 ? 2 : n == 3 ? 3 : n == 6 ? \
+                 4 : 5);"
+            },
+
+            Self::Cornish => {
+                "nplurals=6; plural=(n == 0 ? 0 : n == 1 ? 1 : n%100 == 2 || n%100 == 22 || \
+                 n%100 == 42 || n%100 == 62 || n%100 == 82 || (n%1000 == 0 && ((n%100000 >= \
+                 1000 && n%100000 <= 20000) || n%100000 == 40000 || n%100000 == 60000 || \
+                 n%100000 == 80000)) || (n != 0 && n%1000000 == 100000) ? 2 : n%100 == 3 || \
+                 n%100 == 23 || n%100 == 43 || n%100 == 63 || n%100 == 83 ? 3 : n%100 == 1 || \
+                 n%100 == 21 || n%100 == 41 || n%100 == 61 || n%100 == 81 ? 4 : 5);"
+            },
+        }
+    }
+}
+
+/// [`Language`] to [`PluralRule`] conversion.
+///
+/// Maps each language to its CLDR cardinal rule,
+/// languages without a CLDR rule use [`PluralRule::OneN`].
+impl From<&Language> for PluralRule {
+    fn from(language: &Language) -> Self {
+        use Language::*;
+
+        match language {
+            BM | BO | DZ | ID | IG | II | JA | JV | KM | KO | LO | MS | MY | SG | SU | TH | TO
+            | VI | WO | YO | ZH => Self::Other,
+
+            DE | EN | ET | FI | FY | GL | IA | IO | NL | SC | SV | SW | UR | YI => Self::OneInteger,
+            CA | IT => Self::OneIntegerMany,
+            ES => Self::OneNMany,
+
+            AM | AS | BN | FA | GU | HI | KN | ZU => Self::OneZeroOrN,
+            AK | LN | MG | PA | TI | WA => Self::OneZeroToOneN,
+            FF | HY => Self::OneZeroToOneI,
+            FR | PT => Self::OneZeroToOneIMany,
+            IU | SE => Self::OneTwo,
+
+            DA => Self::Danish,
+            IS => Self::Icelandic,
+            MK => Self::Macedonian,
+            TL => Self::Filipino,
+            SI => Self::Sinhala,
+            LV => Self::Latvian,
+            LT => Self::Lithuanian,
+            BE | RU | UK => Self::EastSlavic,
+            PL => Self::Polish,
+            CS | SK => Self::CzechSlovak,
+            BS | HR | SR => Self::SerboCroatian,
+            SL => Self::Slovenian,
+            RO => Self::Romanian,
+            HE => Self::Hebrew,
+            AR => Self::Arabic,
+            GA => Self::Irish,
+            GD => Self::ScottishGaelic,
+            CY => Self::Welsh,
+            MT => Self::Maltese,
+            BR => Self::Breton,
+            GV => Self::Manx,
+            KW => Self::Cornish,
+
+            _ => Self::OneN,
+        }
+    }
+}
+
+/// [`Locale`] to [`PluralRule`] conversion.
+///
+/// Uses the rule of the locale language, except for
+/// the regional variants with their own CLDR rule, such
+/// as European Portuguese, which `pt-AO` or `pt-MZ` also
+/// follow, unlike `pt` and `pt-BR`.
+impl From<&Locale> for PluralRule {
+    fn from(locale: &Locale) -> Self {
+        match (locale.language(), locale.region()) {
+            (Language::PT, Some(region)) if region.as_str() != "BR" => Self::OneIntegerMany,
+            (language, _) => Self::from(language),
+        }
+    }
+}
+
+impl PluralTranslation {
+    /// The replacement argument that selects the variant.
+    ///
+    /// Its value is parsed as [`PluralOperands`], if it's
+    /// missing or not a number the `other` variant is used.
+    pub const SELECTOR: &str = "count";
+
+    /// Create a new [`PluralTranslation`].
+    ///
+    /// The variants are not validated against the rule
+    /// categories, this is usually called from a `to-runtime`
+    /// implementation.
+    ///
+    /// **Arguments**
+    /// * `rule` - The rule that selects the variant.
+    /// * `other` - The `other` variant, which replaces any `other`
+    ///   entry of the variants.
+    /// * `variants` - The remaining variants keyed by category.
+    ///
+    /// **Returns**
+    /// The provided variants wrapped in a [`PluralTranslation`].
+    pub fn new(
+        rule: PluralRule,
+        other: FormatString,
+        mut variants: HashMap<PluralCategory, FormatString>,
+    ) -> Self {
+        variants.remove(&PluralCategory::Other);
+
+        Self { rule, other, variants }
+    }
+
+    /// Rule getter.
+    ///
+    /// **Returns**
+    /// The rule that selects the variant.
+    pub fn rule(&self) -> PluralRule {
+        self.rule
+    }
+
+    /// Variant selection.
+    ///
+    /// **Arguments**
+    /// * `count` - The selector value, such as `3` or `1.5`.
+    ///
+    /// **Returns**
+    /// The variant for the category the value belongs to,
+    /// or the `other` variant if that category is not defined.
+    pub fn select(&self, count: Option<&str>) -> &FormatString {
+        let category = count
+            .and_then(|count| {
+                count
+                    .parse::<PluralOperands>()
+                    .ok()
+            })
+            .map(|operands| {
+                self.rule
+                    .select(&operands)
+            })
+            .unwrap_or(PluralCategory::Other);
+
+        self.variant(category)
+            .unwrap_or(&self.other)
+    }
+
+    /// Variant getter.
+    ///
+    /// Unlike [`select`], doesn't fall back to `other`.
+    ///
+    /// **Arguments**
+    /// * `category` - The category of the variant.
+    ///
+    /// **Returns**
+    /// The variant for the category, if it's defined.
+    ///
+    /// [`select`]: PluralTranslation::select
+    pub fn variant(&self, category: PluralCategory) -> Option<&FormatString> {
+        match category {
+            PluralCategory::Other => Some(&self.other),
+            category => self
+                .variants
+                .get(&category),
+        }
+    }
+
+    /// Variants iterator.
+    ///
+    /// **Returns**
+    /// An iterator over the defined categories and their variants,
+    /// including the `other` one.
+    pub fn variants(&self) -> impl Iterator<Item = (&PluralCategory, &FormatString)> {
+        self.variants
+            .iter()
+            .chain([(&PluralCategory::Other, &self.other)])
+    }
+
+    /// Missing categories check.
+    ///
+    /// **Returns**
+    /// The categories the rule uses that don't have a variant,
+    /// sorted from `zero` to `other`.
+    pub fn missing_categories(&self) -> Vec<PluralCategory> {
+        PluralCategory::iter()
+            .filter(|category| {
+                self.rule
+                    .categories()
+                    .contains(category)
+                    && self
+                        .variant(*category)
+                        .is_none()
+            })
+            .collect()
+    }
+}
+
+/// This implementation converts the category
+/// to an equivalent path from the runtime context.
+impl ToTokens for PluralCategory {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = Ident::new(&format!("{self:?}"), Span::call_site());
+
+        tokens.append_all(quote! { translatable::shared::misc::plural::PluralCategory::#ident })
+    }
+}
+
+/// This implementation converts the rule
+/// to an equivalent path from the runtime context.
+impl ToTokens for PluralRule {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = Ident::new(&format!("{self:?}"), Span::call_site());
+
+        tokens.append_all(quote! { translatable::shared::misc::plural::PluralRule::#ident })
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the call to [`new`] on
+/// [`PluralTranslation`] with the data from the current
+/// instance.
+///
+/// [`new`]: PluralTranslation::new
+impl ToTokens for PluralTranslation {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let rule = &self.rule;
+        let other = &self.other;
+        let map = map_to_tokens(&self.variants);
+
+        tokens.append_all(quote! {
+            translatable::shared::misc::plural::PluralTranslation::new(#rule, #other, #map)
+        });
+    }
+}
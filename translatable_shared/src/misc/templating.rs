@@ -4,8 +4,19 @@
 //! which is a structure to parse templates
 //! and generate strings of them with replaced
 //! parameters.
+//!
+//! The template grammar is a subset of ICU MessageFormat,
+//! supporting plain placeholders such as `{name}` and
+//! select arguments such as
+//! `{gender, select, male {He} female {She} other {They}}`
+//! whose branches may contain nested arguments.
+//!
+//! Placeholders may be suffixed with a format specifier
+//! such as `{price:.2}`, read [`FormatSpec`].
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
 use std::ops::Range;
 use std::str::FromStr;
 
@@ -14,11 +25,16 @@ use quote::{ToTokens, TokenStreamExt, quote};
 use syn::{Ident, parse_str};
 use thiserror::Error;
 
+use super::format_spec::{FormatSpec, FormatSpecError, FormatType, FormatValue};
+
 /// Template parsing errors.
 ///
 /// This error is used within [`FormatString`]
 /// to represent parsing errors such as unclosed
 /// unescaped tags or invalid identifiers.
+///
+/// Every error carries the byte span of the offending
+/// part of the template, read [`TemplateError::span`].
 #[derive(Error, Debug)]
 pub enum TemplateError {
     /// Unclosed brace error.
@@ -27,6 +43,9 @@ pub enum TemplateError {
     /// that was considered unescaped
     /// was not closed after reaching the
     /// last character of the string.
+    ///
+    /// **Parameters**
+    /// * `0` - The byte index of the unclosed brace.
     #[error("Found unclosed brace at index {0}")]
     Unclosed(usize),
 
@@ -36,38 +55,689 @@ pub enum TemplateError {
     /// inside the braces couldn't be parsed
     /// as an [`Ident`], invalid identifiers
     /// are checked because of macro parsing.
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid key.
+    /// * `1` - The byte span of the key.
     #[error("Found template with key '{0}' which is an invalid identifier")]
-    InvalidIdent(String),
+    InvalidIdent(String, Range<usize>),
+
+    /// Unknown argument type error.
+    ///
+    /// This error is returned when an argument
+    /// such as `{key, type, ...}` has a type
+    /// other than `select`.
+    ///
+    /// **Parameters**
+    /// * `0` - The unknown argument type.
+    /// * `1` - The byte span of the argument type.
+    #[error("Found unknown argument type '{0}', only 'select' is supported")]
+    UnknownArgumentType(String, Range<usize>),
+
+    /// Unexpected character error.
+    ///
+    /// This error is returned when the template
+    /// syntax was broken, such as a select branch
+    /// without braces.
+    ///
+    /// **Parameters**
+    /// * `0` - What was expected.
+    /// * `1` - The byte index where it was expected.
+    #[error("Expected {0} at index {1}")]
+    Expected(&'static str, usize),
+
+    /// Duplicated select branch error.
+    ///
+    /// **Parameters**
+    /// * `0` - The duplicated branch key.
+    /// * `1` - The byte span of the branch key.
+    #[error("Found duplicated select branch '{0}'")]
+    DuplicatedBranch(String, Range<usize>),
+
+    /// Missing `other` select branch error.
+    ///
+    /// Every select argument must have an `other`
+    /// branch, as it's used for unmatched values.
+    ///
+    /// **Parameters**
+    /// * `0` - The select argument key.
+    /// * `1` - The byte span of the select argument.
+    #[error("The select argument '{0}' must have an 'other' branch")]
+    MissingOtherBranch(String, Range<usize>),
+
+    /// Invalid format specifier error.
+    ///
+    /// This error is returned when the specifier
+    /// of a placeholder such as `{price:.2}` doesn't
+    /// follow the `format!` grammar.
+    ///
+    /// **Parameters**
+    /// * `0` - The specifier parsing error.
+    /// * `1` - The byte span of the specifier.
+    #[error("{0}")]
+    InvalidFormatSpec(FormatSpecError, Range<usize>),
+}
+
+/// Template argument errors.
+///
+/// These errors are not related to parsing, they are
+/// found when the arguments provided to render a template
+/// don't match its placeholders, read [`Translation::check_arguments`].
+///
+/// [`Translation::check_arguments`]: crate::translations::node::Translation::check_arguments
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ArgumentError {
+    /// Unknown argument error.
+    ///
+    /// An argument was provided but no placeholder
+    /// uses it, usually a typo in the argument name.
+    ///
+    /// **Parameters**
+    /// * `0` - The unknown argument key.
+    #[error("The argument '{0}' doesn't match any placeholder")]
+    Unknown(String),
+
+    /// Missing argument error.
+    ///
+    /// A placeholder has no matching argument, thus
+    /// it would be kept as written in the output.
+    ///
+    /// **Parameters**
+    /// * `0` - The placeholder key.
+    #[error("The placeholder '{0}' has no matching argument")]
+    Missing(String),
+
+    /// Unsupported format specifier error.
+    ///
+    /// A placeholder has a format specifier type whose
+    /// formatting trait its argument doesn't implement,
+    /// only found at runtime for dynamic paths.
+    ///
+    /// **Parameters**
+    /// * `0` - The argument key.
+    /// * `1` - The specifier type of the placeholder.
+    #[error(
+        "The argument '{0}' doesn't implement the {1:?} trait its placeholder is formatted with"
+    )]
+    UnsupportedFormat(String, FormatType),
+}
+
+/// Template arguments.
+///
+/// The values a template is rendered with, either
+/// pre-rendered as strings, such as the values of a
+/// [`HashMap<String, String>`], or any [`Display`]
+/// implementation rendered while the template is
+/// written, such as a `&[(&str, &dyn Display)]`.
+pub trait TemplateArguments {
+    /// Argument value getter.
+    ///
+    /// **Parameters**
+    /// * `key` - The argument key.
+    ///
+    /// **Returns**
+    /// The value of the argument, if it was provided.
+    fn argument(&self, key: &str) -> Option<&dyn Display>;
+
+    /// Argument text getter.
+    ///
+    /// Select arguments and plural selectors compare the
+    /// text of their value, this renders it unless the
+    /// value is already a string.
+    ///
+    /// **Parameters**
+    /// * `key` - The argument key.
+    ///
+    /// **Returns**
+    /// The rendered value of the argument, if it was provided.
+    fn argument_text(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.argument(key)
+            .map(|value| Cow::Owned(value.to_string()))
+    }
+
+    /// Formatted argument writer.
+    ///
+    /// Writes the value of a placeholder with a format
+    /// specifier, values only known to implement [`Display`]
+    /// are written with [`FormatSpec::write_display`], which
+    /// applies the flags but not the specifier type.
+    ///
+    /// **Parameters**
+    /// * `key` - The argument key.
+    /// * `spec` - The placeholder format specifier.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output, if the argument was provided.
+    fn write_argument(
+        &self,
+        key: &str,
+        spec: &FormatSpec,
+        output: &mut dyn FmtWrite,
+    ) -> Option<FmtResult> {
+        self.argument(key)
+            .map(|value| spec.write_display(value, output))
+    }
+
+    /// Formatting trait support check.
+    ///
+    /// Values only known to implement [`Display`] are
+    /// written with it whatever the specifier type is,
+    /// so they support all of them.
+    ///
+    /// **Parameters**
+    /// * `key` - The argument key.
+    /// * `kind` - The placeholder specifier type.
+    ///
+    /// **Returns**
+    /// Whether the argument can be written with the specifier
+    /// type, `true` if the argument was not provided.
+    fn supports(&self, key: &str, kind: FormatType) -> bool {
+        let _ = (key, kind);
+        true
+    }
+}
+
+/// Formattable template argument.
+///
+/// A borrowed value along with the formatting traits
+/// its concrete type implements, erased with
+/// [`FormatProbe`], so placeholders with a format
+/// specifier are written as `format!` would.
+///
+/// This is what macro generated code passes to translations
+/// resolved at runtime, so `{id:#x}` writes an hexadecimal
+/// number as `format!` would, while a type that doesn't
+/// implement the trait of a specifier is reported before
+/// the translation is written.
+///
+/// [`FormatProbe`]: super::format_spec::FormatProbe
+pub struct FormatArgument<'a> {
+    /// The value, for placeholders without specifier type.
+    value: &'a dyn Display,
+
+    /// The value erased to the other formatting traits it implements.
+    formats: &'a [Option<FormatValue<'a>>],
+}
+
+impl<'a> FormatArgument<'a> {
+    /// Argument constructor.
+    ///
+    /// **Parameters**
+    /// * `value` - The argument value.
+    /// * `formats` - The value erased to the formatting traits it implements,
+    ///   `None` for the traits it doesn't.
+    ///
+    /// **Returns**
+    /// The argument, to be paired with its key.
+    pub fn new(value: &'a dyn Display, formats: &'a [Option<FormatValue<'a>>]) -> Self {
+        Self { value, formats }
+    }
+
+    /// Erased value getter.
+    ///
+    /// **Parameters**
+    /// * `kind` - The specifier type.
+    ///
+    /// **Returns**
+    /// The value erased to the formatting trait of the type,
+    /// if the value implements it.
+    fn format(&self, kind: FormatType) -> Option<&FormatValue<'a>> {
+        self.formats
+            .iter()
+            .flatten()
+            .find(|format| format.kind() == kind)
+    }
+
+    /// Writes the value with a format specifier.
+    ///
+    /// **Parameters**
+    /// * `spec` - The placeholder format specifier.
+    /// * `output` - The writer to write the formatted value to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output, an error if the value
+    /// doesn't implement the formatting trait of the specifier type.
+    fn write(&self, spec: &FormatSpec, output: &mut dyn FmtWrite) -> FmtResult {
+        match spec.kind() {
+            FormatType::Display => spec.write_display(self.value, output),
+            kind => match self.format(kind) {
+                Some(format) => spec.write_value(format, output),
+                None => Err(std::fmt::Error),
+            },
+        }
+    }
+
+    /// Formatting trait support check.
+    ///
+    /// **Parameters**
+    /// * `kind` - The specifier type.
+    ///
+    /// **Returns**
+    /// Whether the value implements the formatting trait of the type.
+    fn supports(&self, kind: FormatType) -> bool {
+        kind == FormatType::Display
+            || self
+                .format(kind)
+                .is_some()
+    }
+}
+
+/// Pre-rendered arguments implementation.
+///
+/// The values are borrowed, so selecting on them
+/// doesn't render them again.
+impl TemplateArguments for HashMap<String, String> {
+    fn argument(&self, key: &str) -> Option<&dyn Display> {
+        self.get(key)
+            .map(|value| value as &dyn Display)
+    }
+
+    fn argument_text(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.get(key)
+            .map(|value| Cow::Borrowed(value.as_str()))
+    }
+}
+
+/// Borrowed arguments implementation.
+///
+/// The values are rendered while writing the template,
+/// the first value found for a key is used.
+impl TemplateArguments for [(&str, &dyn Display)] {
+    fn argument(&self, key: &str) -> Option<&dyn Display> {
+        self.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Borrowed arguments array implementation.
+///
+/// Delegates to the slice implementation.
+impl<const N: usize> TemplateArguments for [(&str, &dyn Display); N] {
+    fn argument(&self, key: &str) -> Option<&dyn Display> {
+        self.as_slice()
+            .argument(key)
+    }
+}
+
+/// Formattable arguments implementation.
+///
+/// Placeholders with a format specifier are written
+/// with the argument writer, the first value found
+/// for a key is used.
+impl TemplateArguments for [(&str, FormatArgument<'_>)] {
+    fn argument(&self, key: &str) -> Option<&dyn Display> {
+        self.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, argument)| argument.value)
+    }
+
+    fn write_argument(
+        &self,
+        key: &str,
+        spec: &FormatSpec,
+        output: &mut dyn FmtWrite,
+    ) -> Option<FmtResult> {
+        self.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, argument)| argument.write(spec, output))
+    }
+
+    fn supports(&self, key: &str, kind: FormatType) -> bool {
+        self.iter()
+            .find(|(name, _)| *name == key)
+            .is_none_or(|(_, argument)| argument.supports(kind))
+    }
+}
+
+/// Formattable arguments array implementation.
+///
+/// Delegates to the slice implementation.
+impl<const N: usize> TemplateArguments for [(&str, FormatArgument<'_>); N] {
+    fn argument(&self, key: &str) -> Option<&dyn Display> {
+        self.as_slice()
+            .argument(key)
+    }
+
+    fn write_argument(
+        &self,
+        key: &str,
+        spec: &FormatSpec,
+        output: &mut dyn FmtWrite,
+    ) -> Option<FmtResult> {
+        self.as_slice()
+            .write_argument(key, spec, output)
+    }
+
+    fn supports(&self, key: &str, kind: FormatType) -> bool {
+        self.as_slice()
+            .supports(key, kind)
+    }
+}
+
+/// Compile-time rendering target.
+///
+/// The generated rendering code either pushes to
+/// an `output` [`String`] or writes to a `formatter`
+/// [`Formatter`], read [`FormatString::render_tokens`]
+/// and [`FormatString::write_tokens`].
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum RenderTarget {
+    /// Push to an `output` [`String`].
+    Output,
+
+    /// Write to a `formatter`.
+    Formatter,
+}
+
+impl RenderTarget {
+    /// Text rendering statement.
+    ///
+    /// **Arguments**
+    /// * `text` - The text to render.
+    ///
+    /// **Returns**
+    /// The statement rendering the text to the target.
+    pub(crate) fn text(self, text: &str) -> TokenStream2 {
+        match self {
+            Self::Output => quote! { output.push_str(#text); },
+            Self::Formatter => quote! { formatter.write_str(#text)?; },
+        }
+    }
+
+    /// Value rendering statement.
+    ///
+    /// The value is written with `format_args!`, so
+    /// the format specifier is checked against its type
+    /// by the compiler.
+    ///
+    /// **Arguments**
+    /// * `value` - The expression evaluating to the value.
+    /// * `spec` - The placeholder format specifier, if any.
+    ///
+    /// **Returns**
+    /// The statement rendering the value to the target.
+    pub(crate) fn value(self, value: &TokenStream2, spec: Option<&FormatSpec>) -> TokenStream2 {
+        let format = spec
+            .map(FormatSpec::format_string)
+            .unwrap_or_else(|| "{}".into());
+
+        match self {
+            Self::Output => quote! {
+                let _ = std::fmt::Write::write_fmt(&mut output, std::format_args!(#format, #value));
+            },
+            Self::Formatter => quote! {
+                formatter.write_fmt(std::format_args!(#format, #value))?;
+            },
+        }
+    }
+
+    /// Value text expression.
+    ///
+    /// Used to compare the value with select branches
+    /// and to parse plural selectors, the value is
+    /// rendered to be compared.
+    ///
+    /// **Arguments**
+    /// * `value` - The expression evaluating to the value.
+    ///
+    /// **Returns**
+    /// An expression evaluating to the value as a `&str`.
+    pub(crate) fn value_text(self, value: &TokenStream2) -> TokenStream2 {
+        quote! { std::string::ToString::to_string(&#value).as_str() }
+    }
+}
+
+/// Template AST node.
+///
+/// A parsed template is a sequence of these
+/// nodes, select arguments contain a sequence
+/// of nodes for each branch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplateNode {
+    /// Literal text, kept as written including escaped braces.
+    ///
+    /// **Parameters**
+    /// * `0` - The text.
+    Text(String),
+
+    /// A placeholder such as `{name}` or `{price:.2}`.
+    ///
+    /// **Parameters**
+    /// * `0` - The placeholder key.
+    /// * `1` - The byte span of the placeholder in the original string, used
+    ///   to keep it untouched when there is no replacement.
+    /// * `2` - The format specifier, if any.
+    Placeholder(String, Range<usize>, Option<FormatSpec>),
+
+    /// A select argument such as `{gender, select, male {He} other {They}}`.
+    ///
+    /// **Parameters**
+    /// * `0` - The select argument key.
+    /// * `1` - The branches in declaration order, keyed by the value that
+    ///   selects them, the `other` branch is always present.
+    Select(String, Vec<(String, Vec<TemplateNode>)>),
 }
 
 /// Format string wrapper struct.
 ///
-/// This struct wraps a string and has
-/// a counter of each template it has
-/// with each respective position for
-/// the sake of replacing these positions
-/// with read data.
+/// This struct wraps a string and its parsed
+/// template nodes, for the sake of replacing
+/// the templates with read data.
 pub struct FormatString {
     /// Original templated string.
     ///
     /// This field contains the original
-    /// string that aligns it's keyed templates
-    /// with `self.spans`.
+    /// string that aligns it's placeholder
+    /// spans with `self.nodes`.
     ///
     /// This should never be mutated for the sake
-    /// of keeping the alignment with `self.spans`.
+    /// of keeping the alignment with `self.nodes`.
     original: String,
 
-    /// Template spans.
+    /// Parsed template nodes.
+    ///
+    /// The same key may be found in multiple
+    /// nodes, as templates may repeat keys.
+    nodes: Vec<TemplateNode>,
+}
+
+/// Template parser state.
+///
+/// A recursive descent parser over the
+/// template string which tracks the current
+/// byte position for error reporting.
+struct TemplateParser<'a> {
+    /// The template being parsed.
+    source: &'a str,
+
+    /// The current byte position.
+    position: usize,
+}
+
+impl TemplateError {
+    /// Error span getter.
+    ///
+    /// **Returns**
+    /// The byte span of the offending part of the template.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Self::Unclosed(index) | Self::Expected(_, index) => *index..*index + 1,
+            Self::InvalidIdent(_, span)
+            | Self::UnknownArgumentType(_, span)
+            | Self::DuplicatedBranch(_, span)
+            | Self::MissingOtherBranch(_, span)
+            | Self::InvalidFormatSpec(_, span) => span.clone(),
+        }
+    }
+}
+
+impl TemplateNode {
+    /// Writes the rendered node to the output.
+    ///
+    /// **Arguments**
+    /// * `original` - The original string the spans refer to.
+    /// * `values` - The values to replace the templates with.
+    /// * `output` - The writer to write the rendered node to.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    fn write<W: FmtWrite>(
+        &self,
+        original: &str,
+        values: &(impl TemplateArguments + ?Sized),
+        output: &mut W,
+    ) -> FmtResult {
+        match self {
+            Self::Text(text) => output.write_str(text),
+
+            Self::Placeholder(key, span, None) => match values.argument(key) {
+                Some(value) => write!(output, "{value}"),
+                None => output.write_str(&original[span.clone()]),
+            },
+
+            Self::Placeholder(key, span, Some(spec)) => values
+                .write_argument(key, spec, output)
+                .unwrap_or_else(|| output.write_str(&original[span.clone()])),
+
+            Self::Select(key, branches) => {
+                let value = values.argument_text(key);
+
+                let branch = branches
+                    .iter()
+                    .find(|(branch, _)| Some(branch.as_str()) == value.as_deref())
+                    .or_else(|| {
+                        branches
+                            .iter()
+                            .find(|(branch, _)| branch == "other")
+                    });
+
+                for node in branch
+                    .into_iter()
+                    .flat_map(|(_, nodes)| nodes)
+                {
+                    node.write(original, values, output)?;
+                }
+
+                Ok(())
+            },
+        }
+    }
+
+    /// Compile-time rendering code generation.
+    ///
+    /// Generates the statements that render this node
+    /// to the target, the equivalent to [`write`] but
+    /// resolved at compile time.
+    ///
+    /// **Arguments**
+    /// * `original` - The original string the spans refer to.
+    /// * `values` - Expressions evaluating to the value of each key.
+    /// * `target` - What the statements render to.
+    ///
+    /// **Returns**
+    /// The statements rendering the node.
+    ///
+    /// [`write`]: TemplateNode::write
+    fn target_tokens(
+        &self,
+        original: &str,
+        values: &HashMap<String, TokenStream2>,
+        target: RenderTarget,
+    ) -> TokenStream2 {
+        match self {
+            Self::Text(text) => target.text(text),
+
+            Self::Placeholder(key, span, spec) => match values.get(key) {
+                Some(value) => target.value(value, spec.as_ref()),
+                None => target.text(&original[span.clone()]),
+            },
+
+            Self::Select(key, branches) => {
+                let render_branch = |nodes: &Vec<TemplateNode>| {
+                    let statements = nodes
+                        .iter()
+                        .map(|node| node.target_tokens(original, values, target));
+
+                    quote! { #(#statements)* }
+                };
+
+                let other = branches
+                    .iter()
+                    .find(|(branch, _)| branch == "other")
+                    .map(|(_, nodes)| render_branch(nodes))
+                    .unwrap_or_default();
+
+                match values.get(key) {
+                    Some(value) => {
+                        let arms = branches
+                            .iter()
+                            .filter(|(branch, _)| branch != "other")
+                            .map(|(branch, nodes)| {
+                                let statements = render_branch(nodes);
+                                quote! { #branch => { #statements } }
+                            });
+
+                        let text = target.value_text(value);
+
+                        quote! {
+                            match #text {
+                                #(#arms)*
+                                _ => { #other }
+                            }
+                        }
+                    },
+
+                    None => other,
+                }
+            },
+        }
+    }
+
+    /// Collects the argument keys used by the node.
     ///
-    /// This vector contains the spans
-    /// of all the ranges containing a template
-    /// in the original string.
+    /// **Arguments**
+    /// * `keys` - The vector to push the keys to.
+    fn collect_keys<'a>(&'a self, keys: &mut Vec<&'a str>) {
+        match self {
+            Self::Text(_) => {},
+            Self::Placeholder(key, ..) => keys.push(key),
+            Self::Select(key, branches) => {
+                keys.push(key);
+
+                for node in branches
+                    .iter()
+                    .flat_map(|(_, nodes)| nodes)
+                {
+                    node.collect_keys(keys);
+                }
+            },
+        }
+    }
+
+    /// Collects the format types used by the node.
     ///
-    /// This is stored in a vector because we
-    /// want to allow multiple templates with
-    /// the same key.
-    spans: Vec<(String, Range<usize>)>,
+    /// **Arguments**
+    /// * `formats` - The vector to push the keys and types to.
+    fn collect_formats<'a>(&'a self, formats: &mut Vec<(&'a str, FormatType)>) {
+        match self {
+            Self::Text(_) => {},
+            Self::Placeholder(key, _, spec) => formats.push((
+                key,
+                spec.as_ref()
+                    .map_or(FormatType::Display, FormatSpec::kind),
+            )),
+            Self::Select(_, branches) => {
+                for node in branches
+                    .iter()
+                    .flat_map(|(_, nodes)| nodes)
+                {
+                    node.collect_formats(formats);
+                }
+            },
+        }
+    }
 }
 
 impl FormatString {
@@ -85,19 +755,23 @@ impl FormatString {
     ///
     /// **Parameters**
     /// * `original` - What belongs to the `original` field.
-    /// * `spans` - What belongs to the `spans` field.
+    /// * `nodes` - What belongs to the `nodes` field.
     ///
     /// **Returns**
     /// An instance of self based on the provided parameters.
-    pub fn from_data(original: &str, spans: Vec<(String, Range<usize>)>) -> Self {
-        Self { original: original.to_string(), spans }
+    pub fn from_data(original: &str, nodes: Vec<TemplateNode>) -> Self {
+        Self { original: original.to_string(), nodes }
     }
 
     /// Creates replaced original string copy.
     ///
-    /// This method takes the original string, and replaces
-    /// it's templates with the values of the values provided
-    /// as a hashmap.
+    /// This method renders the template nodes, replacing
+    /// placeholders with the values provided as a hashmap
+    /// and choosing select branches by those values.
+    ///
+    /// Placeholders without a value are kept as written,
+    /// and select arguments without a matching value use
+    /// their `other` branch.
     ///
     /// **Parameters**
     /// * `values` - The values to replace the templates with.
@@ -105,29 +779,128 @@ impl FormatString {
     /// **Returns**
     /// A copy of the original string with it's templates replaced.
     pub fn replace_with(&self, values: &HashMap<String, String>) -> String {
-        let mut original = self
-            .original
-            .clone();
+        let mut output = String::with_capacity(
+            self.original
+                .len(),
+        );
+
+        // writing to a string can't fail.
+        let _ = self.write_to(&mut output, values);
 
-        let mut spans = self
-            .spans
-            .clone();
-        spans.sort_by_key(|(_key, range)| range.start);
+        output
+    }
 
-        let mut offset = 0isize;
+    /// Writes the rendered template.
+    ///
+    /// Renders the template the same way [`replace_with`]
+    /// does, but straight to the output and with values
+    /// of any [`Display`] type, which are rendered as they
+    /// are written, so nothing is allocated except for the
+    /// values select arguments are compared with and the
+    /// values written with a format specifier.
+    ///
+    /// **Parameters**
+    /// * `output` - The writer to write the rendered template to, such as a
+    ///   [`String`] or a [`Formatter`].
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn write_to(
+        &self,
+        output: &mut impl FmtWrite,
+        values: &(impl TemplateArguments + ?Sized),
+    ) -> FmtResult {
+        for node in &self.nodes {
+            node.write(&self.original, values, output)?;
+        }
 
-        for (key, range) in spans {
-            if let Some(value) = values.get(&key) {
-                let start = (range.start as isize + offset) as usize;
-                let end = (range.end as isize + offset) as usize;
+        Ok(())
+    }
 
-                original.replace_range(start..end, value);
+    /// Compile-time rendering code generation.
+    ///
+    /// Generates an expression that evaluates to the same
+    /// [`String`] as [`replace_with`] would with the values
+    /// of the provided expressions, without parsing nor
+    /// walking the template nodes at runtime.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key, of any
+    ///   type implementing the formatting traits its placeholders use, these
+    ///   may be evaluated more than once so they should be bindings.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to the rendered [`String`].
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn render_tokens(&self, values: &HashMap<String, TokenStream2>) -> TokenStream2 {
+        self.target_tokens(values, RenderTarget::Output)
+    }
 
-                offset += value.len() as isize - (range.end - range.start) as isize;
-            }
-        }
+    /// Compile-time writing code generation.
+    ///
+    /// Generates an expression that writes the same as
+    /// [`write_to`] would to a `formatter` binding, which
+    /// must be a `&mut Formatter`.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key, of any
+    ///   type implementing the formatting traits its placeholders use, these
+    ///   may be evaluated more than once so they should be bindings.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to a [`FmtResult`].
+    ///
+    /// [`write_to`]: FormatString::write_to
+    pub fn write_tokens(&self, values: &HashMap<String, TokenStream2>) -> TokenStream2 {
+        self.target_tokens(values, RenderTarget::Formatter)
+    }
+
+    /// Compile-time rendering code generation.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key.
+    /// * `target` - What the generated code renders to.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to the rendered [`String`]
+    /// or to the [`FmtResult`] of writing it, depending on the
+    /// target.
+    pub(crate) fn target_tokens(
+        &self,
+        values: &HashMap<String, TokenStream2>,
+        target: RenderTarget,
+    ) -> TokenStream2 {
+        let statements = self
+            .nodes
+            .iter()
+            .map(|node| node.target_tokens(&self.original, values, target));
+
+        match target {
+            RenderTarget::Output => {
+                let capacity = self
+                    .original
+                    .len();
 
-        original
+                quote! {
+                    {
+                        let mut output = String::with_capacity(#capacity);
+                        #(#statements)*
+                        output
+                    }
+                }
+            },
+
+            RenderTarget::Formatter => quote! {
+                {
+                    #(#statements)*
+                    std::fmt::Result::Ok(())
+                }
+            },
+        }
     }
 
     /// Original string getter.
@@ -137,6 +910,288 @@ impl FormatString {
     pub fn original(&self) -> &str {
         &self.original
     }
+
+    /// Template nodes getter.
+    ///
+    /// **Returns**
+    /// The parsed template nodes.
+    pub fn nodes(&self) -> &[TemplateNode] {
+        &self.nodes
+    }
+
+    /// Template-free text getter.
+    ///
+    /// A format string without placeholders nor select
+    /// arguments renders to its original string, so it
+    /// can be used as is without rendering it.
+    ///
+    /// **Returns**
+    /// The original string if the template is made of
+    /// text only, otherwise `None`.
+    pub fn literal(&self) -> Option<&str> {
+        self.nodes
+            .iter()
+            .all(|node| matches!(node, TemplateNode::Text(_)))
+            .then_some(&self.original)
+    }
+
+    /// Argument keys getter.
+    ///
+    /// **Returns**
+    /// Every argument key used in the template, including the ones
+    /// nested in select branches, in order of appearance without
+    /// duplicates.
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys = Vec::new();
+
+        for node in &self.nodes {
+            node.collect_keys(&mut keys);
+        }
+
+        let mut seen = Vec::with_capacity(keys.len());
+        keys.retain(|key| {
+            let first = !seen.contains(key);
+            seen.push(key);
+            first
+        });
+
+        keys
+    }
+
+    /// Placeholder format types getter.
+    ///
+    /// **Returns**
+    /// The key and the type of the format specifier of every
+    /// placeholder, including the ones nested in select branches,
+    /// placeholders without specifier use [`FormatType::Display`].
+    pub fn formats(&self) -> Vec<(&str, FormatType)> {
+        let mut formats = Vec::new();
+
+        for node in &self.nodes {
+            node.collect_formats(&mut formats);
+        }
+
+        formats
+    }
+}
+
+impl<'a> TemplateParser<'a> {
+    /// Peeks the current character.
+    ///
+    /// **Returns**
+    /// The character at the current position if any.
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..]
+            .chars()
+            .next()
+    }
+
+    /// Skips whitespace characters.
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek()
+            && c.is_whitespace()
+        {
+            self.position += c.len_utf8();
+        }
+    }
+
+    /// Consumes characters until any of the delimiters.
+    ///
+    /// **Arguments**
+    /// * `delimiters` - The characters that end the consumption.
+    ///
+    /// **Returns**
+    /// The consumed slice along its byte span.
+    fn take_until(&mut self, delimiters: &[char]) -> (&'a str, Range<usize>) {
+        let start = self.position;
+
+        while let Some(c) = self.peek()
+            && !delimiters.contains(&c)
+        {
+            self.position += c.len_utf8();
+        }
+
+        (&self.source[start..self.position], start..self.position)
+    }
+
+    /// Expects a character at the current position.
+    ///
+    /// **Arguments**
+    /// * `expected` - The expected character.
+    /// * `description` - The expected character description for the error.
+    /// * `open` - The index of the brace that opened the argument, used to
+    ///   report unclosed arguments.
+    fn expect(
+        &mut self,
+        expected: char,
+        description: &'static str,
+        open: usize,
+    ) -> Result<(), TemplateError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.position += c.len_utf8();
+                Ok(())
+            },
+            Some(_) => Err(TemplateError::Expected(description, self.position)),
+            None => Err(TemplateError::Unclosed(open)),
+        }
+    }
+
+    /// Parses a sequence of text and arguments.
+    ///
+    /// **Arguments**
+    /// * `nested` - Whether the message is a select branch, in which case a
+    ///   closing brace ends it instead of being taken as text.
+    ///
+    /// **Returns**
+    /// The parsed nodes, the closing brace of a nested message is not
+    /// consumed.
+    fn parse_message(&mut self, nested: bool) -> Result<Vec<TemplateNode>, TemplateError> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' if self.source[self.position + 1..].starts_with('{') => {
+                    text.push_str("{{");
+                    self.position += 2;
+                },
+
+                '{' => {
+                    if !text.is_empty() {
+                        nodes.push(TemplateNode::Text(std::mem::take(&mut text)));
+                    }
+
+                    nodes.push(self.parse_argument()?);
+                },
+
+                '}' if nested => break,
+
+                c => {
+                    text.push(c);
+                    self.position += c.len_utf8();
+                },
+            }
+        }
+
+        if !text.is_empty() {
+            nodes.push(TemplateNode::Text(text));
+        }
+
+        Ok(nodes)
+    }
+
+    /// Parses an argument starting at an opening brace.
+    ///
+    /// **Returns**
+    /// A placeholder or a select node.
+    fn parse_argument(&mut self) -> Result<TemplateNode, TemplateError> {
+        let open = self.position;
+        self.position += 1;
+
+        let (raw_key, key_span) = self.take_until(&[',', '}', '{', ':']);
+        let key = parse_str::<Ident>(raw_key)
+            .map_err(|_| TemplateError::InvalidIdent(raw_key.to_string(), key_span))?
+            .to_string();
+
+        match self.peek() {
+            Some('}') => {
+                self.position += 1;
+                Ok(TemplateNode::Placeholder(key, open..self.position, None))
+            },
+
+            Some(':') => {
+                self.position += 1;
+
+                let (raw_spec, spec_span) = self.take_until(&['}', '{']);
+                let spec = raw_spec
+                    .parse::<FormatSpec>()
+                    .map_err(|error| TemplateError::InvalidFormatSpec(error, spec_span))?;
+
+                self.expect('}', "'}' after the format specifier", open)?;
+                Ok(TemplateNode::Placeholder(key, open..self.position, Some(spec)))
+            },
+
+            Some(',') => {
+                self.position += 1;
+                self.skip_whitespace();
+
+                let (argument_type, type_span) = self.take_until(&[',', '}', '{']);
+                if argument_type.trim_end() != "select" {
+                    return Err(TemplateError::UnknownArgumentType(
+                        argument_type
+                            .trim_end()
+                            .to_string(),
+                        type_span,
+                    ));
+                }
+
+                self.expect(',', "',' after 'select'", open)?;
+
+                let branches = self.parse_branches(open)?;
+
+                if !branches
+                    .iter()
+                    .any(|(branch, _)| branch == "other")
+                {
+                    return Err(TemplateError::MissingOtherBranch(key, open..self.position));
+                }
+
+                Ok(TemplateNode::Select(key, branches))
+            },
+
+            Some(_) => Err(TemplateError::Expected("'}', ':' or ','", self.position)),
+            None => Err(TemplateError::Unclosed(open)),
+        }
+    }
+
+    /// Parses select branches until the argument closing brace.
+    ///
+    /// **Arguments**
+    /// * `open` - The index of the brace that opened the argument.
+    ///
+    /// **Returns**
+    /// The branches in declaration order.
+    fn parse_branches(
+        &mut self,
+        open: usize,
+    ) -> Result<Vec<(String, Vec<TemplateNode>)>, TemplateError> {
+        let mut branches = Vec::<(String, Vec<TemplateNode>)>::new();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(branches);
+                },
+                None => return Err(TemplateError::Unclosed(open)),
+                Some(_) => {},
+            }
+
+            let (branch, branch_span) = self.take_until(&['{', '}', ',']);
+            let branch = branch.trim_end();
+
+            if branch.is_empty() || branch.contains(char::is_whitespace) {
+                return Err(TemplateError::Expected("a select branch key", branch_span.start));
+            }
+
+            if branches
+                .iter()
+                .any(|(key, _)| key == branch)
+            {
+                return Err(TemplateError::DuplicatedBranch(branch.to_string(), branch_span));
+            }
+
+            let branch_open = self.position;
+            self.expect('{', "'{' after the select branch key", open)?;
+            let nodes = self.parse_message(true)?;
+            self.expect('}', "'}' closing the select branch", branch_open)?;
+
+            branches.push((branch.to_string(), nodes));
+        }
+    }
 }
 
 /// Parse method implementation.
@@ -150,58 +1205,96 @@ impl FromStr for FormatString {
     type Err = TemplateError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let original = s.to_string();
-        let mut spans = Vec::new();
+        let nodes = TemplateParser { source: s, position: 0 }.parse_message(false)?;
 
-        let char_to_byte = s
-            .char_indices()
-            .map(|(i, _)| i)
-            .collect::<Vec<usize>>();
+        Ok(FormatString { original: s.to_string(), nodes })
+    }
+}
 
-        let mut last_bracket_idx = None;
-        let mut current_tmpl_key = String::new();
-        for (char_idx, c) in original
-            .chars()
-            .enumerate()
-        {
-            match (c, last_bracket_idx) {
-                // if last template index is the last character
-                // ignore current as is escaped.
-                ('{', Some(prev)) if prev == char_idx.saturating_sub(1) => last_bracket_idx = None,
-                // if last template index is anything but the last character
-                // set it as last index.
-                ('{', _) => last_bracket_idx = Some(char_idx),
-
-                // if last template index is not 0 and we find
-                // a closing bracket complete a range.
-                ('}', Some(open_idx)) => {
-                    let key = current_tmpl_key.clone();
-
-                    spans.push((
-                        parse_str::<Ident>(&key)
-                            .map_err(|_| TemplateError::InvalidIdent(key))?
-                            .to_string(),
-                        char_to_byte[open_idx]
-                            ..char_to_byte
-                                .get(char_idx + 1)
-                                .copied()
-                                .unwrap_or_else(|| s.len()),
-                    ));
+/// Template syntax display implementation.
+///
+/// Writes the node back in template syntax, text is
+/// kept as written so the output parses back to the
+/// same node, as long as the node was parsed from a
+/// template.
+impl Display for TemplateNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
 
-                    last_bracket_idx = None;
-                    current_tmpl_key.clear();
-                },
+            Self::Placeholder(key, _, None) => write!(f, "{{{key}}}"),
 
-                (c, Some(_)) => current_tmpl_key.push(c),
+            Self::Placeholder(key, _, Some(spec)) => write!(f, "{{{key}:{spec}}}"),
 
-                _ => {},
-            }
+            Self::Select(key, branches) => {
+                write!(f, "{{{key}, select,")?;
+
+                for (branch, nodes) in branches {
+                    write!(f, " {branch} {{")?;
+
+                    for node in nodes {
+                        write!(f, "{node}")?;
+                    }
+
+                    write!(f, "}}")?;
+                }
+
+                write!(f, "}}")
+            },
         }
+    }
+}
 
-        if let Some(lbi) = last_bracket_idx {
-            Err(TemplateError::Unclosed(lbi))
-        } else {
-            Ok(FormatString { original, spans })
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the equivalent
+/// [`TemplateNode`] variant, recursively for
+/// select branches.
+impl ToTokens for TemplateNode {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Text(text) => tokens.append_all(quote! {
+                translatable::shared::misc::templating::TemplateNode::Text(#text.to_string())
+            }),
+
+            Self::Placeholder(key, span, spec) => {
+                let start = span.start;
+                let end = span.end;
+
+                // the specifier was validated while loading.
+                let spec = match spec {
+                    Some(spec) => {
+                        let spec = spec.to_string();
+
+                        quote! {
+                            <translatable::shared::misc::format_spec::FormatSpec
+                                as std::str::FromStr>::from_str(#spec).ok()
+                        }
+                    },
+                    None => quote! { std::option::Option::None },
+                };
+
+                tokens.append_all(quote! {
+                    translatable::shared::misc::templating::TemplateNode::Placeholder(
+                        #key.to_string(),
+                        #start..#end,
+                        #spec
+                    )
+                });
+            },
+
+            Self::Select(key, branches) => {
+                let branches = branches
+                    .iter()
+                    .map(|(branch, nodes)| quote! { (#branch.to_string(), vec![#(#nodes),*]) });
+
+                tokens.append_all(quote! {
+                    translatable::shared::misc::templating::TemplateNode::Select(
+                        #key.to_string(),
+                        vec![#(#branches),*]
+                    )
+                });
+            },
         }
     }
 }
@@ -215,21 +1308,12 @@ impl FromStr for FormatString {
 impl ToTokens for FormatString {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let original = &self.original;
-
-        let span_map = self
-            .spans
-            .iter()
-            .map(|(key, range)| {
-                let start = range.start;
-                let end = range.end;
-
-                quote! { (#key.to_string(), #start..#end) }
-            });
+        let nodes = &self.nodes;
 
         tokens.append_all(quote! {
             translatable::shared::misc::templating::FormatString::from_data(
                 #original,
-                vec![#(#span_map),*]
+                vec![#(#nodes),*]
             )
         });
     }
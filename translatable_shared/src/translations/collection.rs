@@ -1,65 +1,92 @@
 //! Translation file collection module.
 //!
 //! This module declares [`TranslationNodeCollection`]
-//! a representation of each file found in the translations
-//! folder defined in the configuration file.
+//! a representation of every file found in the translations
+//! folder defined in the configuration file merged together.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, TokenStreamExt, quote};
 
-use super::node::{TranslationNode, TranslationObject};
-use crate::macros::collections::map_transform_to_tokens;
+use super::node::{TranslationNode, TranslationNodeError, TranslationObject};
+use crate::misc::locale::Locale;
 
 /// Translation file collection.
 ///
-/// This tuple struct wraps a hashmap implementing
-/// a lookup trough all the files in ascending order.
+/// This tuple struct wraps the translation tree
+/// resulting from deep merging every file found in
+/// the translations folder, so a translation path
+/// resolves to a single translation object whose
+/// languages may come from different files.
 ///
-/// The internal hashmap contains the original file
-/// paths along all the unmerged [`TranslationNode`]
-/// found in each file.
-pub struct TranslationNodeCollection(HashMap<String, TranslationNode>);
+/// The merge order is decided while loading the
+/// translations, read [`TranslationNode::merge`].
+pub struct TranslationNodeCollection(TranslationNode);
 
 impl TranslationNodeCollection {
     /// Create a new [`TranslationNodeCollection`].
     ///
-    /// By providing a populated hashmap, create a new
-    /// [`TranslationNodeCollection`] structure.
+    /// By providing an already merged translation tree,
+    /// create a new [`TranslationNodeCollection`] structure.
     ///
-    /// The file paths in the hashmap key aren't validated. This
-    /// is usually called from a `to-runtime` implementation, if
-    /// you want to obtain all the translation files use
+    /// This is usually called from a `to-runtime` implementation,
+    /// if you want to merge translation files use [`merged`]
+    /// instead.
     ///
     /// **Arguments**
-    /// * `collection` - An already populated collection for lookup.
+    /// * `root` - The merged translation tree for lookup.
     ///
     /// **Returns**
-    /// The provided collection wrapped in a [`TranslationNodeCollection`].
-    pub fn new(collection: HashMap<String, TranslationNode>) -> Self {
-        Self(collection)
+    /// The provided tree wrapped in a [`TranslationNodeCollection`].
+    ///
+    /// [`merged`]: TranslationNodeCollection::merged
+    pub fn new(root: TranslationNode) -> Self {
+        Self(root)
     }
 
-    /// Get a node from a file path.
+    /// Merge translation files into a [`TranslationNodeCollection`].
     ///
-    /// This method may be used to load a translation
-    /// independently, if you are looking for an independent
-    /// translation you may want to call find_path instead.
+    /// Deep merges the translation files in iteration
+    /// order, the files found first take priority for the
+    /// languages of the translations more than one file
+    /// defines, read [`TranslationNode::merge`].
     ///
     /// **Arguments**
-    /// * `path` - The OS path where the file was originally found.
+    /// * `files` - The translation files along their file path.
     ///
     /// **Returns**
-    /// A top level translation node, containing all the translations
-    /// in that specific file.
-    #[allow(unused)]
-    pub fn get_node(&self, path: &str) -> Option<&TranslationNode> {
-        self.0
-            .get(path)
+    /// The merged collection or, along with the file path and
+    /// the conflicting key path, a [`TranslationNodeError::MixedValues`]
+    /// if a path is a nesting in one file and a translation object
+    /// in another.
+    pub fn merged(
+        files: impl IntoIterator<Item = (String, TranslationNode)>,
+    ) -> Result<Self, (TranslationNodeError, String, Vec<String>)> {
+        let mut root = None::<TranslationNode>;
+
+        for (path, node) in files {
+            match &mut root {
+                Some(root) => root
+                    .merge_traced(node)
+                    .map_err(|(error, keys)| (error, path, keys))?,
+                None => root = Some(node),
+            }
+        }
+
+        Ok(Self(root.unwrap_or_else(|| TranslationNode::Nesting(HashMap::new()))))
+    }
+
+    /// Get the merged translation tree.
+    ///
+    /// **Returns**
+    /// The top level translation node, containing all the
+    /// translations of every file.
+    pub fn root(&self) -> &TranslationNode {
+        &self.0
     }
 
-    /// Search a path trough all the nodes.
+    /// Search a path in the merged translations.
     ///
     /// This method is used to load a specific translation
     /// file agnostic from a "translation path" which consists
@@ -68,28 +95,38 @@ impl TranslationNodeCollection {
     ///
     /// **Arguments**
     /// * `path` - The sections of the TOML path in order to access
-    /// the desired translation object.
+    ///   the desired translation object.
     ///
     /// **Returns**
     /// A translation object containing a specific translation
     /// in all it's available languages.
+    // A `&Vec` keeps `collect()` calls inferable at the call site.
+    #[allow(clippy::ptr_arg)]
     pub fn find_path<I: ToString>(&self, path: &Vec<I>) -> Option<&TranslationObject> {
         self.0
-            .values()
-            .find_map(|node| node.find_path(path))
+            .find_path(path)
     }
-}
 
-/// Hashmap wrapper implementation.
-///
-/// Abstraction to easily collect a [`HashMap<String, TranslationNode>`] and
-/// wrap it in a [`TranslationNodeCollection`].
-impl FromIterator<(String, TranslationNode)> for TranslationNodeCollection {
-    fn from_iter<T: IntoIterator<Item = (String, TranslationNode)>>(iter: T) -> Self {
-        Self(
-            iter.into_iter()
-                .collect(),
-        )
+    /// Collects every translation object in the collection.
+    ///
+    /// **Returns**
+    /// The translation objects of the merged translations along
+    /// with their translation path, displayed in `::` notation.
+    pub fn objects(&self) -> Vec<(String, &TranslationObject)> {
+        self.0
+            .objects()
+    }
+
+    /// Collects every locale used in the collection.
+    ///
+    /// This is the union of the locales found
+    /// in every translation file.
+    ///
+    /// **Returns**
+    /// All the locales that have at least one translation.
+    pub fn locales(&self) -> HashSet<&Locale> {
+        self.0
+            .locales()
     }
 }
 
@@ -102,12 +139,11 @@ impl FromIterator<(String, TranslationNode)> for TranslationNodeCollection {
 /// [`new`]: TranslationNodeCollection::new
 impl ToTokens for TranslationNodeCollection {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let map =
-            map_transform_to_tokens(&self.0, |key, value| quote! { (#key.to_string(), #value) });
+        let root = &self.0;
 
         tokens.append_all(quote! {
             translatable::shared::translations::collection::TranslationNodeCollection::new(
-                #map
+                #root
             )
         });
     }
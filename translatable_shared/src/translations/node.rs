@@ -5,17 +5,23 @@
 //! for which each branch contains paths that might
 //! lead to translation objects or other paths.
 
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
+use std::ops::Range;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, TokenStreamExt, quote};
-use strum::ParseError;
 use thiserror::Error;
-use toml_edit::{Item, Table, Value};
+use toml_edit::{Formatted, Item, Table, Value};
 
 use crate::macros::collections::{map_to_tokens, map_transform_to_tokens};
-use crate::misc::language::Language;
-use crate::misc::templating::{FormatString, TemplateError};
+use crate::misc::format_spec::FormatType;
+use crate::misc::locale::{Locale, LocaleError};
+use crate::misc::plural::{PluralCategory, PluralRule, PluralTranslation};
+use crate::misc::templating::{
+    ArgumentError, FormatString, RenderTarget, TemplateArguments, TemplateError,
+};
 
 /// [`TranslationNode`] errors.
 ///
@@ -36,8 +42,16 @@ pub enum TranslationNodeError {
     /// This means there was an error while validating
     /// a translation templates, such as an invalid
     /// ident for its keys or unclosed templates.
+    ///
+    /// **Parameters**
+    /// * `0` - The template error.
+    /// * `1` - The byte span of the error in the translation file, if the
+    ///   table was parsed keeping spans, used to point the error to the
+    ///   file line and column. Read [`locate`] for more information.
+    ///
+    /// [`locate`]: TranslationNodeError::locate
     #[error("Template validation failed: {0:#}")]
-    TemplateValidation(#[from] TemplateError),
+    TemplateValidation(TemplateError, Option<Range<usize>>),
 
     /// Invalid value found inside a nesting.
     ///
@@ -49,14 +63,52 @@ pub enum TranslationNodeError {
     )]
     MixedValues,
 
-    /// Invalid ISO-639-1 translation key.
+    /// Invalid BCP 47 translation key.
     ///
     /// This error signals that an invalid key was found for a
     /// translation inside a translation object.
     ///
-    /// Translation keys must follow the ISO-639-1 standard.
-    #[error("Couldn't parse ISO 639-1 string for translation key")]
-    LanguageParsing(#[from] ParseError),
+    /// Translation keys must be BCP 47 tags whose primary
+    /// language follows the ISO-639-1 standard.
+    #[error("Couldn't parse BCP 47 tag for translation key: {0:#}")]
+    LanguageParsing(#[from] LocaleError),
+
+    /// Invalid plural category.
+    ///
+    /// This error signals that a plural translation
+    /// contains a key that is not a CLDR plural category.
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid category key.
+    #[error(
+        "'{0}' is not a plural category, expected one of 'zero', 'one', 'two', 'few', 'many' or \
+         'other'"
+    )]
+    InvalidPluralCategory(String),
+
+    /// Missing `other` plural category.
+    ///
+    /// Every CLDR plural rule uses the `other` category,
+    /// thus every plural translation must define it.
+    ///
+    /// **Parameters**
+    /// * `0` - The locale of the plural translation.
+    #[error("The plural translation for '{0}' must define the 'other' category")]
+    MissingOtherCategory(Locale),
+
+    /// Duplicated translation locale.
+    ///
+    /// This error signals that two keys of a translation
+    /// object are different spellings of the same locale,
+    /// such as `pt_BR` and `pt-BR`, which would otherwise
+    /// overwrite each other.
+    ///
+    /// **Parameters**
+    /// * `0` - The locale both keys normalize to.
+    /// * `1` - The first key declaring the locale.
+    /// * `2` - The key declaring the locale again.
+    #[error("The keys '{1}' and '{2}' are both the locale '{0}'")]
+    DuplicatedLocale(Locale, String, String),
 
     /// Empty translation file.
     ///
@@ -66,6 +118,60 @@ pub enum TranslationNodeError {
     EmptyTable,
 }
 
+impl TranslationNodeError {
+    /// Locates a template error in the translation file.
+    ///
+    /// While parsing, a template error only knows the span
+    /// of the whole TOML string. Given the file source, this
+    /// method narrows it to the offending part of the template
+    /// if the string has no escape sequences, as otherwise the
+    /// template offsets don't match the file offsets.
+    ///
+    /// This must be called once, with the source the
+    /// table was parsed from.
+    ///
+    /// **Arguments**
+    /// * `source` - The translation file source.
+    ///
+    /// **Returns**
+    /// The same error with the narrowed span if it could be located.
+    pub fn locate(self, source: &str) -> Self {
+        let Self::TemplateValidation(error, Some(value_span)) = self else {
+            return self;
+        };
+
+        let located = source
+            .get(value_span.clone())
+            .and_then(|raw| {
+                let quote_len = match raw {
+                    raw if raw.starts_with("\"\"\"") || raw.starts_with("'''") => 3,
+                    raw if raw.starts_with('"') || raw.starts_with('\'') => 1,
+                    _ => return None,
+                };
+
+                let content = raw.get(quote_len..raw.len() - quote_len)?;
+                if raw.starts_with('"') && content.contains('\\') {
+                    return None;
+                }
+
+                // multiline strings trim the newline after the quotes.
+                let trimmed = match quote_len {
+                    3 if content.starts_with("\r\n") => 2,
+                    3 if content.starts_with('\n') => 1,
+                    _ => 0,
+                };
+
+                let start = value_span.start + quote_len + trimmed;
+                let template_span = error.span();
+
+                Some(start + template_span.start..start + template_span.end)
+            })
+            .unwrap_or(value_span);
+
+        Self::TemplateValidation(error, Some(located))
+    }
+}
+
 /// Nesting type alias.
 ///
 /// This is one of the valid objects that might be found
@@ -73,12 +179,33 @@ pub enum TranslationNodeError {
 /// or another nesting.
 pub type TranslationNesting = HashMap<String, TranslationNode>;
 
-/// Object type alias.
+/// Translation object structure.
 ///
 /// This is one of the valid objects that might be found
 /// on a translation file, this object contains only translations
-/// keyed with their respective languages.
-pub type TranslationObject = HashMap<Language, FormatString>;
+/// keyed with their respective locales.
+///
+/// Lookups fall back from the most specific locale to the
+/// less specific ones, so a `pt-BR` lookup finds a `pt`
+/// translation if there is no `pt-BR` one.
+///
+/// The comments of each translation, such as gettext
+/// translator comments, are kept for the catalog exports
+/// only, they are not part of the runtime translations.
+pub struct TranslationObject(HashMap<Locale, Translation>, HashMap<Locale, Vec<String>>);
+
+/// Translation value enum.
+///
+/// A translation may be a single template or a set of
+/// plural variants selected by CLDR plural rules, read
+/// [`PluralTranslation`] for more information.
+pub enum Translation {
+    /// A single template for every count.
+    Singular(FormatString),
+
+    /// Plural variants keyed by plural category.
+    Plural(PluralTranslation),
+}
 
 /// Translation node structure.
 ///
@@ -100,7 +227,694 @@ pub enum TranslationNode {
     Translation(TranslationObject),
 }
 
+impl TranslationObject {
+    /// Create a new [`TranslationObject`].
+    ///
+    /// By providing a populated hashmap, create a new
+    /// [`TranslationObject`] structure. This is usually
+    /// called from a `to-runtime` implementation.
+    ///
+    /// **Arguments**
+    /// * `translations` - The translations keyed by their locale.
+    ///
+    /// **Returns**
+    /// The provided translations wrapped in a [`TranslationObject`].
+    pub fn new(translations: HashMap<Locale, Translation>) -> Self {
+        Self(translations, HashMap::new())
+    }
+
+    /// Get a translation for a locale.
+    ///
+    /// The lookup walks the [`Locale::fallbacks`] chain,
+    /// returning the most specific translation available.
+    ///
+    /// **Arguments**
+    /// * `locale` - A [`Locale`] or anything that converts into it, such as
+    ///   a [`Language`].
+    ///
+    /// **Returns**
+    /// The most specific translation available for the locale.
+    ///
+    /// [`Language`]: crate::misc::language::Language
+    pub fn get<L: Clone + Into<Locale>>(&self, locale: &L) -> Option<&Translation> {
+        locale
+            .clone()
+            .into()
+            .fallbacks()
+            .find_map(|locale| self.get_exact(&locale))
+    }
+
+    /// Get a translation following a fallback chain.
+    ///
+    /// Tries the requested locale first and then each
+    /// of the fallback locales in order, every one of them
+    /// resolved as in [`get`].
+    ///
+    /// **Arguments**
+    /// * `locale` - The requested locale.
+    /// * `fallbacks` - The locales to try in order if the requested one is not
+    ///   available.
+    ///
+    /// **Returns**
+    /// The first translation found in the chain.
+    ///
+    /// [`get`]: TranslationObject::get
+    pub fn get_or_fallback<L: Clone + Into<Locale>>(
+        &self,
+        locale: &L,
+        fallbacks: &[Locale],
+    ) -> Option<&Translation> {
+        self.resolve_or_fallback(locale, fallbacks)
+            .map(|(_, translation)| translation)
+    }
+
+    /// Resolve a translation following a fallback chain.
+    ///
+    /// Follows the same chain as [`get_or_fallback`],
+    /// also returning the locale the translation was
+    /// found for, which may be less specific than the
+    /// requested one or any of the fallback locales.
+    ///
+    /// **Arguments**
+    /// * `locale` - The requested locale.
+    /// * `fallbacks` - The locales to try in order if the requested one is not
+    ///   available.
+    ///
+    /// **Returns**
+    /// The first translation found in the chain along its locale.
+    ///
+    /// [`get_or_fallback`]: TranslationObject::get_or_fallback
+    pub fn resolve_or_fallback<L: Clone + Into<Locale>>(
+        &self,
+        locale: &L,
+        fallbacks: &[Locale],
+    ) -> Option<(&Locale, &Translation)> {
+        std::iter::once(
+            locale
+                .clone()
+                .into(),
+        )
+        .chain(
+            fallbacks
+                .iter()
+                .cloned(),
+        )
+        .find_map(|locale| {
+            locale
+                .fallbacks()
+                .find_map(|candidate| {
+                    self.0
+                        .get_key_value(&candidate)
+                })
+        })
+    }
+
+    /// Get a translation for an exact locale.
+    ///
+    /// Unlike [`get`], this doesn't fall back to
+    /// less specific locales.
+    ///
+    /// **Arguments**
+    /// * `locale` - The exact locale to look for.
+    ///
+    /// **Returns**
+    /// The translation for that exact locale if any.
+    ///
+    /// [`get`]: TranslationObject::get
+    pub fn get_exact(&self, locale: &Locale) -> Option<&Translation> {
+        self.0
+            .get(locale)
+    }
+
+    /// Available locales getter.
+    ///
+    /// **Returns**
+    /// An iterator over all the locales this object has a translation for.
+    pub fn locales(&self) -> impl Iterator<Item = &Locale> {
+        self.0
+            .keys()
+    }
+
+    /// Translations iterator.
+    ///
+    /// **Returns**
+    /// An iterator over all the translations keyed by their locale.
+    pub fn translations(&self) -> impl Iterator<Item = (&Locale, &Translation)> {
+        self.0
+            .iter()
+    }
+
+    /// Translation comments getter.
+    ///
+    /// **Arguments**
+    /// * `locale` - The exact locale of the translation.
+    ///
+    /// **Returns**
+    /// The comment lines of the translation, as written in
+    /// the file it was read from, empty if it has none.
+    pub fn comments(&self, locale: &Locale) -> &[String] {
+        self.1
+            .get(locale)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Translation comments setter.
+    ///
+    /// **Arguments**
+    /// * `locale` - The exact locale of the translation.
+    /// * `comments` - The comment lines of the translation.
+    pub fn set_comments(&mut self, locale: Locale, comments: Vec<String>) {
+        if comments.is_empty() {
+            self.1
+                .remove(&locale);
+        } else {
+            self.1
+                .insert(locale, comments);
+        }
+    }
+
+    /// Missing plural categories check.
+    ///
+    /// Every plural translation must define the categories
+    /// used by the plural rule of its language.
+    ///
+    /// **Returns**
+    /// The first locale, in BCP 47 order, whose plural translation
+    /// lacks categories, along with the missing categories.
+    pub fn missing_plural_categories(&self) -> Option<(&Locale, Vec<PluralCategory>)> {
+        let mut missing = self
+            .0
+            .iter()
+            .filter_map(|(locale, translation)| match translation {
+                Translation::Plural(plural) => Some((locale, plural.missing_categories())),
+                Translation::Singular(_) => None,
+            })
+            .filter(|(_, categories)| !categories.is_empty())
+            .collect::<Vec<_>>();
+
+        missing.sort_by_key(|(locale, _)| locale.to_string());
+        missing
+            .into_iter()
+            .next()
+    }
+
+    /// Placeholder consistency check.
+    ///
+    /// Every language of a translation object is expected
+    /// to use the same set of placeholders, as the arguments
+    /// of an invocation are shared by all of them.
+    ///
+    /// **Returns**
+    /// Each locale, in BCP 47 order, that lacks placeholders used by
+    /// other languages, along with those placeholders, empty if every
+    /// language agrees.
+    pub fn placeholder_mismatches(&self) -> Vec<(&Locale, Vec<&str>)> {
+        let mut translations = self
+            .0
+            .iter()
+            .map(|(locale, translation)| (locale, translation.keys()))
+            .collect::<Vec<_>>();
+        translations.sort_by_key(|(locale, _)| locale.to_string());
+
+        let mut all_keys = Vec::new();
+        for key in translations
+            .iter()
+            .flat_map(|(_, keys)| keys)
+        {
+            if !all_keys.contains(key) {
+                all_keys.push(*key);
+            }
+        }
+
+        translations
+            .iter()
+            .map(|(locale, keys)| {
+                let missing = all_keys
+                    .iter()
+                    .filter(|key| !keys.contains(key))
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                (*locale, missing)
+            })
+            .filter(|(_, missing)| !missing.is_empty())
+            .collect()
+    }
+
+    /// Merges another translation object into this one.
+    ///
+    /// The translations already present in this
+    /// object are kept for the locales both define,
+    /// along with their comments.
+    ///
+    /// **Arguments**
+    /// * `other` - The translation object to merge.
+    pub fn merge(&mut self, other: TranslationObject) {
+        let TranslationObject(translations, mut comments) = other;
+
+        for (locale, translation) in translations {
+            if let Entry::Vacant(entry) = self
+                .0
+                .entry(locale.clone())
+            {
+                entry.insert(translation);
+
+                if let Some(comments) = comments.remove(&locale) {
+                    self.1
+                        .insert(locale, comments);
+                }
+            }
+        }
+    }
+
+    /// Plural translations check.
+    ///
+    /// **Returns**
+    /// Whether any of the translations has plural variants.
+    pub fn is_plural(&self) -> bool {
+        self.0
+            .values()
+            .any(|translation| matches!(translation, Translation::Plural(_)))
+    }
+}
+
+impl Translation {
+    /// Creates a replaced translation.
+    ///
+    /// Singular translations are replaced as is, while for
+    /// plural translations the variant is selected first with
+    /// the [`PluralTranslation::SELECTOR`] value.
+    ///
+    /// **Parameters**
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// A copy of the selected template with it's templates replaced.
+    pub fn replace_with(&self, values: &HashMap<String, String>) -> String {
+        let mut output = String::new();
+
+        // writing to a string can't fail, and plural
+        // translations always have an `other` variant.
+        let _ = self.write_to(&mut output, values);
+
+        output
+    }
+
+    /// Writes the rendered translation.
+    ///
+    /// Renders the translation the same way [`replace_with`]
+    /// does, but straight to the output, read
+    /// [`FormatString::write_to`].
+    ///
+    /// Plural translations select their variant as
+    /// [`PluralTranslation::select`] does, falling back to
+    /// the `other` variant.
+    ///
+    /// **Parameters**
+    /// * `output` - The writer to write the rendered translation to.
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// The result of writing to the output.
+    ///
+    /// [`replace_with`]: Translation::replace_with
+    pub fn write_to(
+        &self,
+        output: &mut impl FmtWrite,
+        values: &(impl TemplateArguments + ?Sized),
+    ) -> FmtResult {
+        match self {
+            Self::Singular(format_string) => format_string.write_to(output, values),
+            Self::Plural(plural) => plural
+                .select(
+                    values
+                        .argument_text(PluralTranslation::SELECTOR)
+                        .as_deref(),
+                )
+                .write_to(output, values),
+        }
+    }
+
+    /// Argument keys getter.
+    ///
+    /// Plural translations always use the [`PluralTranslation::SELECTOR`]
+    /// key, followed by the keys of every variant in category order.
+    ///
+    /// **Returns**
+    /// Every argument key the translation uses, without duplicates.
+    pub fn keys(&self) -> Vec<&str> {
+        let plural = match self {
+            Self::Singular(format_string) => return format_string.keys(),
+            Self::Plural(plural) => plural,
+        };
+
+        let mut variants = plural
+            .variants()
+            .collect::<Vec<_>>();
+        variants.sort_by_key(|(category, _)| **category);
+
+        let mut keys = vec![PluralTranslation::SELECTOR];
+        for key in variants
+            .into_iter()
+            .flat_map(|(_, format_string)| format_string.keys())
+        {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        keys
+    }
+
+    /// Placeholder format types getter.
+    ///
+    /// **Returns**
+    /// The key and format type of every placeholder in every
+    /// variant, read [`FormatString::formats`].
+    pub fn formats(&self) -> Vec<(&str, FormatType)> {
+        match self {
+            Self::Singular(format_string) => format_string.formats(),
+            Self::Plural(plural) => plural
+                .variants()
+                .flat_map(|(_, format_string)| format_string.formats())
+                .collect(),
+        }
+    }
+
+    /// Arguments validation.
+    ///
+    /// Compares the provided argument keys with the
+    /// ones the translation uses, read [`keys`].
+    ///
+    /// **Parameters**
+    /// * `arguments` - The provided argument keys.
+    ///
+    /// **Returns**
+    /// The unknown arguments in the provided order followed by
+    /// the missing placeholders in order of appearance, empty if
+    /// the arguments match.
+    ///
+    /// [`keys`]: Translation::keys
+    pub fn check_arguments(&self, arguments: &[&str]) -> Vec<ArgumentError> {
+        let keys = self.keys();
+
+        let unknown = arguments
+            .iter()
+            .filter(|argument| !keys.contains(argument))
+            .map(|argument| ArgumentError::Unknown(argument.to_string()));
+
+        let missing = keys
+            .iter()
+            .filter(|key| !arguments.contains(key))
+            .map(|key| ArgumentError::Missing(key.to_string()));
+
+        unknown
+            .chain(missing)
+            .collect()
+    }
+
+    /// Format specifiers validation.
+    ///
+    /// Checks that the provided arguments implement the
+    /// formatting traits of the placeholders they replace,
+    /// which is only known at runtime for dynamic paths.
+    ///
+    /// **Parameters**
+    /// * `values` - The arguments the translation will be written with.
+    ///
+    /// **Returns**
+    /// The first placeholder, in order of appearance, whose
+    /// argument doesn't implement its specifier type, if any.
+    pub fn check_formats(
+        &self,
+        values: &(impl TemplateArguments + ?Sized),
+    ) -> Option<ArgumentError> {
+        self.formats()
+            .into_iter()
+            .find(|(key, kind)| !values.supports(key, *kind))
+            .map(|(key, kind)| ArgumentError::UnsupportedFormat(key.to_string(), kind))
+    }
+}
+
+impl Translation {
+    /// Template-free text getter.
+    ///
+    /// **Returns**
+    /// The text of a singular translation without
+    /// templates, read [`FormatString::literal`],
+    /// plural translations always return `None`.
+    pub fn literal(&self) -> Option<&str> {
+        match self {
+            Self::Singular(format_string) => format_string.literal(),
+            Self::Plural(_) => None,
+        }
+    }
+
+    /// Compile-time rendering code generation.
+    ///
+    /// Generates an expression that evaluates to the same
+    /// [`String`] as [`replace_with`] would, read
+    /// [`FormatString::render_tokens`] for more information.
+    ///
+    /// Plural translations generate a match on the category
+    /// of the [`PluralTranslation::SELECTOR`] value, if it's
+    /// not provided the `other` variant is rendered.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to the rendered [`String`].
+    ///
+    /// [`replace_with`]: Translation::replace_with
+    pub fn render_tokens(&self, values: &HashMap<String, TokenStream2>) -> TokenStream2 {
+        self.target_tokens(values, RenderTarget::Output)
+    }
+
+    /// Compile-time writing code generation.
+    ///
+    /// Generates an expression that writes the same as
+    /// [`write_to`] would to a `formatter` binding, read
+    /// [`FormatString::write_tokens`] for more information.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to a [`FmtResult`].
+    ///
+    /// [`write_to`]: Translation::write_to
+    pub fn write_tokens(&self, values: &HashMap<String, TokenStream2>) -> TokenStream2 {
+        self.target_tokens(values, RenderTarget::Formatter)
+    }
+
+    /// Compile-time rendering code generation.
+    ///
+    /// **Parameters**
+    /// * `values` - Expressions evaluating to the value of each key.
+    /// * `target` - What the generated code renders to.
+    ///
+    /// **Returns**
+    /// A block expression evaluating to the rendered [`String`]
+    /// or to the [`FmtResult`] of writing it, depending on the
+    /// target.
+    fn target_tokens(
+        &self,
+        values: &HashMap<String, TokenStream2>,
+        target: RenderTarget,
+    ) -> TokenStream2 {
+        let plural = match self {
+            Self::Singular(format_string) => return format_string.target_tokens(values, target),
+            Self::Plural(plural) => plural,
+        };
+
+        let other = plural
+            .select(None)
+            .target_tokens(values, target);
+
+        let Some(count) = values.get(PluralTranslation::SELECTOR) else {
+            return other;
+        };
+
+        let rule = plural.rule();
+        let arms = plural
+            .variants()
+            .filter(|(category, _)| **category != PluralCategory::Other)
+            .map(|(category, format_string)| {
+                let rendered = format_string.target_tokens(values, target);
+                quote! { std::option::Option::Some(#category) => #rendered, }
+            });
+
+        let count = target.value_text(count);
+
+        quote! {
+            match #count
+                .parse::<translatable::shared::misc::plural::PluralOperands>()
+                .ok()
+                .map(|operands| #rule.select(&operands))
+            {
+                #(#arms)*
+                _ => #other,
+            }
+        }
+    }
+}
+
+/// Lazily rendered translation.
+///
+/// Wraps the code rendering a translation, which runs
+/// each time the value is displayed, so it can be written
+/// to a formatter, a log line or an HTTP body without
+/// allocating the rendered string first.
+///
+/// This is what the `translation_display!()` macro expands into.
+pub struct LazyTranslation<F>(F);
+
+impl<F> LazyTranslation<F>
+where
+    F: Fn(&mut Formatter<'_>) -> FmtResult,
+{
+    /// Compile-time to runtime constructor.
+    ///
+    /// **Parameters**
+    /// * `render` - The function writing the translation to a formatter.
+    ///
+    /// **Returns**
+    /// The function wrapped in a [`LazyTranslation`].
+    pub const fn new(render: F) -> Self {
+        Self(render)
+    }
+}
+
+/// Lazy rendering implementation.
+///
+/// Displaying the value renders the translation
+/// straight to the formatter.
+impl<F> Display for LazyTranslation<F>
+where
+    F: Fn(&mut Formatter<'_>) -> FmtResult,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        (self.0)(f)
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the respective
+/// [`Translation`] variant wrapping the runtime
+/// conversion of its content.
+impl ToTokens for Translation {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Self::Singular(format_string) => tokens.append_all(quote! {
+                translatable::shared::translations::node::Translation::Singular(#format_string)
+            }),
+
+            Self::Plural(plural) => tokens.append_all(quote! {
+                translatable::shared::translations::node::Translation::Plural(#plural)
+            }),
+        }
+    }
+}
+
+/// Hashmap wrapper implementation.
+///
+/// Abstraction to easily collect a [`HashMap<Locale, Translation>`] and
+/// wrap it in a [`TranslationObject`].
+impl FromIterator<(Locale, Translation)> for TranslationObject {
+    fn from_iter<T: IntoIterator<Item = (Locale, Translation)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .collect(),
+            HashMap::new(),
+        )
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the call to [`new`] on
+/// [`TranslationObject`] with the data from the current
+/// instance.
+///
+/// [`new`]: TranslationObject::new
+impl ToTokens for TranslationObject {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let map = map_to_tokens(&self.0);
+
+        tokens.append_all(quote! {
+            translatable::shared::translations::node::TranslationObject::new(
+                #map
+            )
+        });
+    }
+}
+
 impl TranslationNode {
+    /// Merges another node into this one.
+    ///
+    /// Nestings are merged recursively and translation
+    /// objects are merged per locale, read [`TranslationObject::merge`].
+    ///
+    /// **Arguments**
+    /// * `other` - The node to merge.
+    ///
+    /// **Returns**
+    /// A [`TranslationNodeError::MixedValues`] if a path is a nesting in
+    /// one node and a translation object in the other.
+    pub fn merge(&mut self, other: TranslationNode) -> Result<(), TranslationNodeError> {
+        self.merge_traced(other)
+            .map_err(|(error, _)| error)
+    }
+
+    /// Traced node merging.
+    ///
+    /// Works the same way as [`merge`], but the error
+    /// comes along with the keys that lead to it, so the
+    /// conflicting path can be located in the merged files.
+    ///
+    /// **Arguments**
+    /// * `other` - The node to merge.
+    ///
+    /// **Returns**
+    /// A [`TranslationNodeError::MixedValues`] along with the path
+    /// of keys that is a nesting in one node and a translation
+    /// object in the other, empty if it's the nodes themselves.
+    ///
+    /// [`merge`]: TranslationNode::merge
+    pub fn merge_traced(
+        &mut self,
+        other: TranslationNode,
+    ) -> Result<(), (TranslationNodeError, Vec<String>)> {
+        match (self, other) {
+            (Self::Nesting(nesting), Self::Nesting(other)) => {
+                for (key, node) in other {
+                    match nesting.get_mut(&key) {
+                        Some(existing) => existing
+                            .merge_traced(node)
+                            .map_err(|(error, mut keys)| {
+                                keys.insert(0, key);
+                                (error, keys)
+                            })?,
+                        None => {
+                            nesting.insert(key, node);
+                        },
+                    }
+                }
+
+                Ok(())
+            },
+
+            (Self::Translation(object), Self::Translation(other)) => {
+                object.merge(other);
+                Ok(())
+            },
+
+            _ => Err((TranslationNodeError::MixedValues, Vec::new())),
+        }
+    }
+
     /// Resolves a translation path through the nesting hierarchy.
     ///
     /// **Arguments**
@@ -108,6 +922,8 @@ impl TranslationNode {
     ///
     /// **Returns**
     /// A reference to translations if path exists and points to leaf node.
+    // A `&Vec` keeps `collect()` calls inferable at the call site.
+    #[allow(clippy::ptr_arg)]
     pub fn find_path<I: ToString>(&self, path: &Vec<I>) -> Option<&TranslationObject> {
         let path = path
             .iter()
@@ -126,6 +942,44 @@ impl TranslationNode {
                 .then_some(translation),
         }
     }
+
+    /// Collects every translation object in the node.
+    ///
+    /// **Returns**
+    /// The translation objects reachable from this node along with
+    /// their path relative to it, displayed in `::` notation.
+    pub fn objects(&self) -> Vec<(String, &TranslationObject)> {
+        match self {
+            Self::Nesting(nested) => nested
+                .iter()
+                .flat_map(|(key, node)| {
+                    node.objects()
+                        .into_iter()
+                        .map(move |(path, object)| match path.is_empty() {
+                            true => (key.clone(), object),
+                            false => (format!("{key}::{path}"), object),
+                        })
+                })
+                .collect(),
+            Self::Translation(translation) => vec![(String::new(), translation)],
+        }
+    }
+
+    /// Collects every locale used in the node.
+    ///
+    /// **Returns**
+    /// The locales of all the translation objects reachable from this node.
+    pub fn locales(&self) -> HashSet<&Locale> {
+        match self {
+            Self::Nesting(nested) => nested
+                .values()
+                .flat_map(|node| node.locales())
+                .collect(),
+            Self::Translation(translation) => translation
+                .locales()
+                .collect(),
+        }
+    }
 }
 
 /// Compile-time to runtime conversion implementation.
@@ -152,11 +1006,9 @@ impl ToTokens for TranslationNode {
             },
 
             TranslationNode::Translation(translation) => {
-                let map = map_to_tokens(translation);
-
                 tokens.append_all(quote! {
                     translatable::shared::translations::node::TranslationNode::Translation(
-                        #map
+                        #translation
                     )
                 });
             },
@@ -173,38 +1025,286 @@ impl ToTokens for TranslationNode {
 impl TryFrom<&Table> for TranslationNode {
     type Error = TranslationNodeError;
 
-    // The top level can only contain objects is never enforced.
     fn try_from(value: &Table) -> Result<Self, Self::Error> {
-        let mut result = None;
-
-        for (key, value) in value {
-            match value {
-                Item::Value(Value::String(translation_value)) => {
-                    match result.get_or_insert_with(|| Self::Translation(HashMap::new())) {
-                        Self::Translation(translation) => {
-                            translation.insert(
-                                key.parse()?,
-                                translation_value
-                                    .value()
-                                    .parse()?,
-                            );
-                        },
-                        Self::Nesting(_) => return Err(TranslationNodeError::MixedValues),
-                    }
-                },
+        Self::try_from_traced(value).map_err(|(error, _)| error)
+    }
+}
 
-                Item::Table(nesting_value) => {
-                    match result.get_or_insert_with(|| Self::Nesting(HashMap::new())) {
-                        Self::Nesting(nesting) => {
-                            nesting.insert(key.to_string(), Self::try_from(nesting_value)?);
-                        },
-                        Self::Translation(_) => return Err(TranslationNodeError::MixedValues),
+impl TranslationNode {
+    /// Traced [`TryFrom<&Table>`] conversion.
+    ///
+    /// Works the same way as the [`TryFrom<&Table>`]
+    /// implementation, but the error comes along with the
+    /// keys that lead to it, so formats that don't keep spans
+    /// in the table can locate the error in their file.
+    ///
+    /// **Arguments**
+    /// * `table` - The table to convert.
+    ///
+    /// **Returns**
+    /// The converted node, or the error along with the path of keys
+    /// to the offending value, empty if it's the table itself.
+    ///
+    /// [`TryFrom<&Table>`]: TranslationNode#impl-TryFrom<%26Table>-for-TranslationNode
+    pub fn try_from_traced(table: &Table) -> Result<Self, (TranslationNodeError, Vec<String>)> {
+        let mut path = Vec::new();
+
+        parse_table(table, &mut path).map_err(|error| (error, path))
+    }
+
+    /// Traced single language table conversion.
+    ///
+    /// Converts a table holding the plain translations of
+    /// a single language, such as `greeting = "Hello"`, by
+    /// keying each translation with the language and then
+    /// converting it as [`try_from_traced`] does.
+    ///
+    /// Plural translations keep their form, a table whose
+    /// keys are all plural categories is a single translation.
+    ///
+    /// **Arguments**
+    /// * `table` - The table to convert.
+    /// * `locale` - The language of every translation in the table.
+    ///
+    /// **Returns**
+    /// The converted node, or the error along with the path of keys
+    /// to the offending value, which ends with the language key
+    /// for errors found in a translation.
+    ///
+    /// [`try_from_traced`]: TranslationNode::try_from_traced
+    pub fn try_from_language_traced(
+        table: &Table,
+        locale: &Locale,
+    ) -> Result<Self, (TranslationNodeError, Vec<String>)> {
+        Self::try_from_traced(&localize_table(table, &locale.to_string()))
+    }
+}
+
+/// Single language table keying.
+///
+/// Wraps every translation in the table with a
+/// table keyed by the language, so the result follows
+/// the layout of a translation file with every language.
+///
+/// **Arguments**
+/// * `table` - The single language table.
+/// * `locale` - The language key.
+///
+/// **Returns**
+/// The table with its translations keyed by language.
+fn localize_table(table: &Table, locale: &str) -> Table {
+    let keyed = |item: Item| {
+        let mut object = Table::new();
+        object.insert(locale, item);
+        Item::Table(object)
+    };
+
+    let mut result = Table::new();
+    for (key, value) in table {
+        let localized = match value {
+            Item::Table(nesting) if !is_plural_table(nesting) => {
+                Item::Table(localize_table(nesting, locale))
+            },
+
+            Item::Value(Value::InlineTable(nesting))
+                if !nesting
+                    .iter()
+                    .all(|(key, _)| {
+                        key.parse::<PluralCategory>()
+                            .is_ok()
+                    }) =>
+            {
+                Item::Table(localize_table(
+                    &nesting
+                        .clone()
+                        .into_table(),
+                    locale,
+                ))
+            },
+
+            Item::Value(_) | Item::Table(_) => keyed(value.clone()),
+
+            _ => value.clone(),
+        };
+
+        result.insert(key, localized);
+    }
+
+    result
+}
+
+/// Table conversion.
+///
+/// On error, `path` is left with the keys that
+/// lead to the offending value.
+///
+/// **Arguments**
+/// * `table` - The table to convert.
+/// * `path` - The keys that lead to the table.
+///
+/// **Returns**
+/// The converted node.
+// The top level can only contain objects is never enforced.
+fn parse_table(
+    table: &Table,
+    path: &mut Vec<String>,
+) -> Result<TranslationNode, TranslationNodeError> {
+    let mut result = None;
+
+    for (key, value) in table {
+        path.push(key.to_string());
+
+        let translation = match value {
+            Item::Value(Value::String(translation_value)) => {
+                Some(Translation::Singular(parse_template(translation_value)?))
+            },
+
+            Item::Value(Value::InlineTable(plural_value)) => {
+                Some(parse_plural(key, plural_value.iter())?)
+            },
+
+            Item::Table(plural_value) if is_plural_table(plural_value) => Some(parse_plural(
+                key,
+                plural_value
+                    .iter()
+                    .filter_map(|(category, value)| Some((category, value.as_value()?))),
+            )?),
+
+            _ => None,
+        };
+
+        if let Some(translation) = translation {
+            match result.get_or_insert_with(|| {
+                TranslationNode::Translation(TranslationObject::new(HashMap::new()))
+            }) {
+                TranslationNode::Translation(TranslationObject(translations, _)) => {
+                    let locale = key.parse::<Locale>()?;
+
+                    if translations.contains_key(&locale) {
+                        let first = table
+                            .iter()
+                            .map(|(key, _)| key)
+                            .find(|key| {
+                                key.parse::<Locale>()
+                                    .is_ok_and(|parsed| parsed == locale)
+                            })
+                            .unwrap_or(key);
+
+                        return Err(TranslationNodeError::DuplicatedLocale(
+                            locale,
+                            first.to_string(),
+                            key.to_string(),
+                        ));
                     }
+
+                    translations.insert(locale, translation);
                 },
-                _ => return Err(TranslationNodeError::InvalidNesting),
+                TranslationNode::Nesting(_) => return Err(TranslationNodeError::MixedValues),
             }
+
+            path.pop();
+            continue;
         }
 
-        result.ok_or(TranslationNodeError::EmptyTable)
+        match value {
+            Item::Table(nesting_value) => {
+                match result.get_or_insert_with(|| TranslationNode::Nesting(HashMap::new())) {
+                    TranslationNode::Nesting(nesting) => {
+                        nesting.insert(key.to_string(), parse_table(nesting_value, path)?);
+                    },
+                    TranslationNode::Translation(_) => {
+                        return Err(TranslationNodeError::MixedValues);
+                    },
+                }
+            },
+            _ => return Err(TranslationNodeError::InvalidNesting),
+        }
+
+        path.pop();
     }
+
+    result.ok_or(TranslationNodeError::EmptyTable)
+}
+
+/// Plural table detection.
+///
+/// A table whose keys are all plural categories with
+/// plain values is a plural translation instead of a nesting,
+/// such as the one declared with `[files.count.en]`
+/// followed by `one` and `other` keys.
+///
+/// **Arguments**
+/// * `table` - The table to check.
+///
+/// **Returns**
+/// Whether the table is a plural translation.
+fn is_plural_table(table: &Table) -> bool {
+    !table.is_empty()
+        && table
+            .iter()
+            .all(|(key, value)| {
+                value.is_value()
+                    && key
+                        .parse::<PluralCategory>()
+                        .is_ok()
+            })
+}
+
+/// Plural translation parsing.
+///
+/// Parses the plural variants of a translation and
+/// infers the plural rule from the translation locale.
+///
+/// **Arguments**
+/// * `locale` - The translation key, which must be a BCP 47 tag.
+/// * `variants` - The plural category keys along their values.
+///
+/// **Returns**
+/// The parsed [`Translation::Plural`] or an error if any key is not
+/// a plural category, any value is not a valid template or the `other`
+/// category is missing.
+fn parse_plural<'a>(
+    locale: &str,
+    variants: impl Iterator<Item = (&'a str, &'a Value)>,
+) -> Result<Translation, TranslationNodeError> {
+    let locale = locale.parse::<Locale>()?;
+
+    let mut variants = variants
+        .map(|(category, value)| {
+            let category = category
+                .parse::<PluralCategory>()
+                .map_err(|_| TranslationNodeError::InvalidPluralCategory(category.into()))?;
+
+            let value = match value {
+                Value::String(value) => parse_template(value)?,
+                _ => return Err(TranslationNodeError::InvalidNesting),
+            };
+
+            Ok((category, value))
+        })
+        .collect::<Result<HashMap<_, _>, TranslationNodeError>>()?;
+
+    let Some(other) = variants.remove(&PluralCategory::Other) else {
+        return Err(TranslationNodeError::MissingOtherCategory(locale));
+    };
+
+    Ok(Translation::Plural(PluralTranslation::new(PluralRule::from(&locale), other, variants)))
+}
+
+/// Template parsing.
+///
+/// Parses a TOML string as a [`FormatString`], keeping
+/// the string span in the error if the table was parsed
+/// keeping spans.
+///
+/// **Arguments**
+/// * `value` - The TOML string.
+///
+/// **Returns**
+/// The parsed template or a [`TranslationNodeError::TemplateValidation`].
+fn parse_template(value: &Formatted<String>) -> Result<FormatString, TranslationNodeError> {
+    value
+        .value()
+        .parse::<FormatString>()
+        .map_err(|error| TranslationNodeError::TemplateValidation(error, value.span()))
 }